@@ -173,11 +173,6 @@ impl HSliderStep {
                 );
             }
             Message::Int(normal) => {
-                // Integer parameters must be snapped for the widget to
-                // "step" when moved.
-                self.int_range
-                    .snap(&mut self.h_slider_int_state.normal_param.value);
-
                 self.output_text = crate::info_text_i32(
                     "HSliderInt",
                     self.int_range.unmap_to_value(normal),
@@ -228,7 +223,8 @@ impl HSliderStep {
         let h_slider_int =
             HSlider::new(&mut self.h_slider_int_state, Message::Int)
                 .tick_marks(&self.int_tick_marks)
-                .text_marks(&self.int_text_marks);
+                .text_marks(&self.int_text_marks)
+                .snap(&self.int_range);
 
         let h_slider_db =
             HSlider::new(&mut self.h_slider_db_state, Message::DB)
@@ -167,11 +167,6 @@ impl KnobStep {
                 );
             }
             Message::Int(normal) => {
-                // Integer parameters must be snapped for the widget to
-                // "step" when moved.
-                self.int_range
-                    .snap(&mut self.knob_int_state.normal_param.value);
-
                 self.output_text = crate::info_text_i32(
                     "KnobInt",
                     self.int_range.unmap_to_value(normal),
@@ -226,7 +221,8 @@ impl KnobStep {
 
         let knob_int = Knob::new(&mut self.knob_int_state, Message::Int)
             .tick_marks(&self.int_tick_marks)
-            .text_marks(&self.int_text_marks);
+            .text_marks(&self.int_text_marks)
+            .snap(&self.int_range);
 
         let knob_db = Knob::new(&mut self.knob_db_state, Message::DB)
             .tick_marks(&self.db_tick_marks)
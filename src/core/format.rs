@@ -0,0 +1,378 @@
+//! Formatting of parameter values into display text, and parsing of
+//! typed text back into values
+
+/// Engineering prefixes from largest to smallest, with their powers of ten
+static PREFIXES: [(&str, i32); 7] = [
+    ("G", 9),
+    ("M", 6),
+    ("k", 3),
+    ("", 0),
+    ("m", -3),
+    ("µ", -6),
+    ("n", -9),
+];
+
+/// The maximum length of the unit of a [`ValueFormatter`] in bytes
+///
+/// [`ValueFormatter`]: struct.ValueFormatter.html
+pub const MAX_UNIT_LEN: usize = 15;
+
+/// The number of digits a [`ValueFormatter`] displays
+///
+/// [`ValueFormatter`]: struct.ValueFormatter.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Precision {
+    /// A fixed number of digits after the decimal point
+    Decimals(u8),
+    /// A number of significant digits. Digits before the decimal point are
+    /// never removed.
+    Significant(u8),
+}
+
+/// Formats `f32` values into display text such as `"1.20 kHz"`,
+/// `"350 ms"`, or `"-inf dB"`, and parses typed text back into values.
+///
+/// Parsing is locale-independent: both `.` and `,` are accepted as the
+/// decimal separator, and the unit and prefix are optional.
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::{Precision, ValueFormatter};
+///
+/// let formatter = ValueFormatter::new("s")
+///     .precision(Precision::Significant(3))
+///     .prefixes(true);
+///
+/// assert_eq!(formatter.format(0.35), "350 ms");
+/// assert_eq!(formatter.parse("350 ms"), Some(0.35));
+/// assert_eq!(formatter.parse("0,35"), Some(0.35));
+///
+/// let db = ValueFormatter::new("dB").precision(Precision::Decimals(1));
+///
+/// assert_eq!(db.format(std::f32::NEG_INFINITY), "-inf dB");
+/// assert_eq!(db.parse("-inf"), Some(std::f32::NEG_INFINITY));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(into = "ValueFormatterDef", try_from = "ValueFormatterDef")
+)]
+pub struct ValueFormatter {
+    unit: Unit,
+    precision: Precision,
+    prefixes: bool,
+    plus_sign: bool,
+}
+
+impl ValueFormatter {
+    /// Creates a new `ValueFormatter` that displays 2 decimal places
+    ///
+    /// * `unit` - the unit displayed after the value, such as `"Hz"`.
+    ///   This can be empty.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `unit` is longer than [`MAX_UNIT_LEN`] bytes
+    ///
+    /// [`MAX_UNIT_LEN`]: constant.MAX_UNIT_LEN.html
+    pub fn new(unit: &str) -> Self {
+        Self {
+            unit: Unit::new(unit).expect("unit is too long"),
+            precision: Precision::Decimals(2),
+            prefixes: false,
+            plus_sign: false,
+        }
+    }
+
+    /// Sets the [`Precision`] of displayed values
+    ///
+    /// [`Precision`]: enum.Precision.html
+    pub fn precision(mut self, precision: Precision) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Sets whether values are scaled by engineering prefixes, such as
+    /// `"k"` in `"1.20 kHz"`
+    pub fn prefixes(mut self, prefixes: bool) -> Self {
+        self.prefixes = prefixes;
+        self
+    }
+
+    /// Sets whether positive values are displayed with a `+` sign
+    pub fn plus_sign(mut self, plus_sign: bool) -> Self {
+        self.plus_sign = plus_sign;
+        self
+    }
+
+    /// Returns the unit displayed after the value
+    pub fn unit(&self) -> &str {
+        self.unit.as_str()
+    }
+
+    /// Returns the value as display text
+    pub fn format(&self, value: f32) -> String {
+        if value.is_nan() {
+            return String::from("NaN");
+        }
+
+        let (scaled, prefix) = if self.prefixes && value.is_finite() {
+            engineering_prefix(value)
+        } else {
+            (value, "")
+        };
+
+        let mut text = if value.is_infinite() {
+            String::from(if value < 0.0 { "-inf" } else { "inf" })
+        } else {
+            let decimals = match self.precision {
+                Precision::Decimals(decimals) => usize::from(decimals),
+                Precision::Significant(digits) => {
+                    let magnitude = if scaled == 0.0 {
+                        0
+                    } else {
+                        scaled.abs().log10().floor() as i32
+                    };
+                    (i32::from(digits) - 1 - magnitude).max(0) as usize
+                }
+            };
+
+            let text = format!("{:.*}", decimals, scaled);
+
+            // Avoid displaying "-0.0".
+            if text.starts_with('-')
+                && text.trim_start_matches(&['-', '0', '.'][..]).is_empty()
+            {
+                String::from(&text[1..])
+            } else {
+                text
+            }
+        };
+
+        if self.plus_sign && value > 0.0 {
+            text.insert(0, '+');
+        }
+
+        if !prefix.is_empty() || !self.unit().is_empty() {
+            text.push(' ');
+            text.push_str(prefix);
+            text.push_str(self.unit());
+        }
+
+        text
+    }
+
+    /// Parses typed text into a value, or returns `None` if the text is
+    /// not a number.
+    ///
+    /// The unit is matched case-insensitively and may be omitted. If
+    /// prefixes are enabled, the text may end in an engineering prefix such
+    /// as `"k"`. `"inf"` and `"-inf"` are accepted.
+    pub fn parse(&self, text: &str) -> Option<f32> {
+        let mut text = text.trim();
+
+        if !self.unit().is_empty() {
+            if let Some(stripped) = strip_suffix_ignore_case(text, self.unit())
+            {
+                text = stripped.trim_end();
+            }
+        }
+
+        let mut exponent = 0;
+        if self.prefixes {
+            // Also accept "K" for kilo and "u" for micro.
+            let prefixes = PREFIXES.iter().chain([("K", 3), ("u", -6)].iter());
+            for (prefix, prefix_exponent) in prefixes {
+                if prefix.is_empty() {
+                    continue;
+                }
+                if let Some(stripped) = text.strip_suffix(prefix) {
+                    text = stripped.trim_end();
+                    exponent = *prefix_exponent;
+                    break;
+                }
+            }
+        }
+
+        let value = text.replace(',', ".").parse::<f32>().ok()?;
+
+        if value.is_nan() {
+            None
+        } else {
+            Some(scale_by_exponent(value, exponent))
+        }
+    }
+}
+
+impl Default for ValueFormatter {
+    fn default() -> Self {
+        ValueFormatter::new("")
+    }
+}
+
+/// A unit stored inline, so that a [`ValueFormatter`] is `Copy` and can
+/// still be deserialized
+///
+/// [`ValueFormatter`]: struct.ValueFormatter.html
+#[derive(Copy, Clone, PartialEq)]
+struct Unit {
+    bytes: [u8; MAX_UNIT_LEN],
+    len: u8,
+}
+
+impl Unit {
+    /// Returns `None` if `unit` is longer than [`MAX_UNIT_LEN`] bytes
+    ///
+    /// [`MAX_UNIT_LEN`]: constant.MAX_UNIT_LEN.html
+    fn new(unit: &str) -> Option<Self> {
+        if unit.len() > MAX_UNIT_LEN {
+            return None;
+        }
+
+        let mut bytes = [0; MAX_UNIT_LEN];
+        bytes[..unit.len()].copy_from_slice(unit.as_bytes());
+
+        Some(Self {
+            bytes,
+            len: unit.len() as u8,
+        })
+    }
+
+    fn as_str(&self) -> &str {
+        // The bytes were copied from a `str`.
+        std::str::from_utf8(&self.bytes[..usize::from(self.len)])
+            .unwrap_or_default()
+    }
+}
+
+impl std::fmt::Debug for Unit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// The serialized form of a [`ValueFormatter`], checked when deserializing.
+///
+/// [`ValueFormatter`]: struct.ValueFormatter.html
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename = "ValueFormatter")]
+struct ValueFormatterDef {
+    unit: String,
+    precision: Precision,
+    prefixes: bool,
+    plus_sign: bool,
+}
+
+#[cfg(feature = "serde")]
+impl From<ValueFormatter> for ValueFormatterDef {
+    fn from(formatter: ValueFormatter) -> Self {
+        Self {
+            unit: String::from(formatter.unit()),
+            precision: formatter.precision,
+            prefixes: formatter.prefixes,
+            plus_sign: formatter.plus_sign,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl std::convert::TryFrom<ValueFormatterDef> for ValueFormatter {
+    type Error = &'static str;
+
+    fn try_from(def: ValueFormatterDef) -> Result<Self, Self::Error> {
+        Ok(Self {
+            unit: Unit::new(&def.unit).ok_or("unit is too long")?,
+            precision: def.precision,
+            prefixes: def.prefixes,
+            plus_sign: def.plus_sign,
+        })
+    }
+}
+
+fn engineering_prefix(value: f32) -> (f32, &'static str) {
+    let abs = value.abs();
+    if abs == 0.0 {
+        return (value, "");
+    }
+
+    for (prefix, exponent) in PREFIXES.iter() {
+        if abs >= scale_by_exponent(1.0, *exponent) {
+            return (scale_by_exponent(value, -exponent), prefix);
+        }
+    }
+
+    let (prefix, exponent) = PREFIXES[PREFIXES.len() - 1];
+    (scale_by_exponent(value, -exponent), prefix)
+}
+
+/// Multiplies the value by `10^exponent`, dividing for negative exponents
+/// so that values such as `350 ms` are exact.
+fn scale_by_exponent(value: f32, exponent: i32) -> f32 {
+    if exponent >= 0 {
+        value * 10.0f32.powi(exponent)
+    } else {
+        value / 10.0f32.powi(-exponent)
+    }
+}
+
+fn strip_suffix_ignore_case<'a>(
+    text: &'a str,
+    suffix: &str,
+) -> Option<&'a str> {
+    if text.len() < suffix.len() {
+        return None;
+    }
+
+    let split = text.len() - suffix.len();
+    if text.is_char_boundary(split)
+        && text[split..].eq_ignore_ascii_case(suffix)
+    {
+        Some(&text[..split])
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unit_is_displayed_and_parsed() {
+        let formatter = ValueFormatter::new("Hz").prefixes(true);
+
+        assert_eq!(formatter.unit(), "Hz");
+        assert_eq!(formatter.format(1200.0), "1.20 kHz");
+        assert_eq!(formatter.parse("1.2 khz"), Some(1200.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "unit is too long")]
+    fn rejects_long_unit() {
+        let _ = ValueFormatter::new("semitones per octave");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialized_form_keeps_unit() {
+        use std::convert::TryFrom;
+
+        let formatter = ValueFormatter::new("ms")
+            .precision(Precision::Significant(3))
+            .plus_sign(true);
+
+        let def = ValueFormatterDef::from(formatter);
+        assert_eq!(def.unit, "ms");
+        assert_eq!(ValueFormatter::try_from(def), Ok(formatter));
+
+        let def = ValueFormatterDef {
+            unit: String::from("semitones per octave"),
+            ..ValueFormatterDef::from(formatter)
+        };
+        assert!(ValueFormatter::try_from(def).is_err());
+    }
+}
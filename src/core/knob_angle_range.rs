@@ -14,12 +14,18 @@ pub static DEFAULT_ANGLE_MAX: f32 = (360.0 - 30.0) * PI_OVER_180;
 /// `0.0` radians points straight down at the bottom of the knob, with the
 /// angles rotating clockwise towards `TWO_PI` (`2*PI`).
 ///
-/// Values < `0.0` and >= `TWO_PI` are not allowed.
+/// Values < `0.0` and >= `TWO_PI` are not allowed. For a knob that turns
+/// more than once, such as an endless encoder or a multi-turn fine tune
+/// knob, set the [`TurnMode`] of the knob instead.
 ///
 /// The default minimum (converted to degrees) is `30` degrees, and the default
 /// maximum is `330` degrees, giving a span of `300` degrees, and a halfway
 /// point pointing strait up.
+///
+/// [`TurnMode`]: ../../native/knob/enum.TurnMode.html
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "KnobAngleRangeDef"))]
 pub struct KnobAngleRange {
     min: f32,
     max: f32,
@@ -97,3 +103,58 @@ impl KnobAngleRange {
         self.max
     }
 }
+
+/// The serialized form of a [`KnobAngleRange`], in radians. Angles that
+/// [`from_rad`] would set to `0.0` are rejected instead.
+///
+/// [`KnobAngleRange`]: struct.KnobAngleRange.html
+/// [`from_rad`]: struct.KnobAngleRange.html#method.from_rad
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(rename = "KnobAngleRange")]
+struct KnobAngleRangeDef {
+    min: f32,
+    max: f32,
+}
+
+#[cfg(feature = "serde")]
+impl std::convert::TryFrom<KnobAngleRangeDef> for KnobAngleRange {
+    type Error = &'static str;
+
+    fn try_from(def: KnobAngleRangeDef) -> Result<Self, Self::Error> {
+        let angles = 0.0..TWO_PI;
+        if !angles.contains(&def.min) || !angles.contains(&def.max) {
+            return Err("angles must be at least 0.0 and less than TWO_PI");
+        }
+        if def.min > def.max {
+            return Err("min must not be greater than max");
+        }
+
+        Ok(KnobAngleRange::from_rad(def.min, def.max))
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serde_round_trips() {
+        let range = KnobAngleRange::from_rad(0.5, 5.5);
+        let json = serde_json::to_string(&range).unwrap();
+        let loaded: KnobAngleRange = serde_json::from_str(&json).unwrap();
+
+        assert_eq!((loaded.min(), loaded.max()), (0.5, 5.5));
+    }
+
+    #[test]
+    fn serde_rejects_invalid_angles() {
+        for json in &[
+            r#"{"min":-0.5,"max":5.5}"#,
+            r#"{"min":0.5,"max":7.0}"#,
+            r#"{"min":5.5,"max":0.5}"#,
+        ] {
+            assert!(serde_json::from_str::<KnobAngleRange>(json).is_err());
+        }
+    }
+}
@@ -0,0 +1,766 @@
+//! Mapping of MIDI controller messages to parameters, with MIDI learn
+
+use super::Normal;
+
+/// The largest 7-bit MIDI value
+const MAX_7_BIT: f32 = 127.0;
+/// The largest 14-bit MIDI value
+const MAX_14_BIT: f32 = 16383.0;
+
+/// How close a controller must come to the value of a parameter to pick it
+/// up with soft takeover
+const TAKEOVER_THRESHOLD: f32 = 0.02;
+
+/// A source of MIDI controller messages that can be bound to a parameter
+///
+/// Channels are numbered from `0` to `15`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MidiSource {
+    /// A 7-bit control change
+    Cc {
+        /// The MIDI channel
+        channel: u8,
+        /// The controller number, from `0` to `127`
+        controller: u8,
+    },
+    /// A 14-bit control change, sent as a pair of controllers. The most
+    /// significant 7 bits are sent by `controller`, and the least
+    /// significant 7 bits by `controller + 32`. Until the least significant
+    /// 7 bits are received, the most significant 7 bits alone move across
+    /// the full range.
+    Cc14 {
+        /// The MIDI channel
+        channel: u8,
+        /// The controller number of the most significant 7 bits, from `0`
+        /// to `31`
+        controller: u8,
+    },
+    /// A 14-bit non-registered parameter number (NRPN), selected with
+    /// controllers `99` and `98` and set with data entry controllers `6`
+    /// and `38`
+    Nrpn {
+        /// The MIDI channel
+        channel: u8,
+        /// The parameter number, from `0` to `16383`
+        number: u16,
+    },
+    /// The 14-bit pitch bend wheel
+    PitchBend {
+        /// The MIDI channel
+        channel: u8,
+    },
+}
+
+/// The soft takeover state of a [`MidiBinding`]
+///
+/// [`MidiBinding`]: struct.MidiBinding.html
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+struct Takeover {
+    picked_up: bool,
+    last_input: Option<Normal>,
+    last_sent: Option<Normal>,
+}
+
+/// A binding of a [`MidiSource`] to a parameter
+///
+/// [`MidiSource`]: enum.MidiSource.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MidiBinding {
+    /// The source of the MIDI messages
+    pub source: MidiSource,
+
+    /// The id of the parameter
+    pub id: usize,
+
+    /// Whether the controller moves the parameter in the opposite
+    /// direction
+    pub invert: bool,
+
+    /// Whether the parameter ignores the controller until the controller
+    /// reaches the current value of the parameter. This prevents the
+    /// parameter from jumping when the controller and the parameter are
+    /// out of sync.
+    pub soft_takeover: bool,
+
+    /// The value of the parameter when the controller is at its minimum
+    pub min: Normal,
+
+    /// The value of the parameter when the controller is at its maximum
+    pub max: Normal,
+
+    #[cfg_attr(feature = "serde", serde(skip))]
+    takeover: Takeover,
+}
+
+impl MidiBinding {
+    /// Creates a new `MidiBinding` over the full range of the parameter
+    ///
+    /// * `source` - the source of the MIDI messages
+    /// * `id` - the id of the parameter
+    pub fn new(source: MidiSource, id: usize) -> Self {
+        Self {
+            source,
+            id,
+            invert: false,
+            soft_takeover: false,
+            min: Normal::min(),
+            max: Normal::max(),
+            takeover: Takeover::default(),
+        }
+    }
+
+    /// Sets whether the controller moves the parameter in the opposite
+    /// direction
+    pub fn invert(mut self, invert: bool) -> Self {
+        self.invert = invert;
+        self
+    }
+
+    /// Sets whether the parameter ignores the controller until the
+    /// controller reaches the current value of the parameter
+    pub fn soft_takeover(mut self, soft_takeover: bool) -> Self {
+        self.soft_takeover = soft_takeover;
+        self
+    }
+
+    /// Sets the sub-range of the parameter that the controller moves
+    /// across
+    ///
+    /// * `min` - the value when the controller is at its minimum
+    /// * `max` - the value when the controller is at its maximum
+    pub fn sub_range(mut self, min: Normal, max: Normal) -> Self {
+        self.min = min;
+        self.max = max;
+        self
+    }
+
+    /// Returns the value of the parameter for the position of the
+    /// controller
+    fn scaled(&self, input: Normal) -> Normal {
+        let input = if self.invert {
+            1.0 - input.as_f32()
+        } else {
+            input.as_f32()
+        };
+
+        (self.min.as_f32() + (input * (self.max.as_f32() - self.min.as_f32())))
+            .into()
+    }
+
+    /// Returns the new value of the parameter, or `None` if the controller
+    /// has not picked up the parameter
+    fn update(&mut self, input: Normal, current: Normal) -> Option<Normal> {
+        let target = self.scaled(input);
+
+        if self.soft_takeover {
+            // Something else moved the parameter, so it must be picked up
+            // again.
+            if let Some(last_sent) = self.takeover.last_sent {
+                if (last_sent.as_f32() - current.as_f32()).abs()
+                    > TAKEOVER_THRESHOLD
+                {
+                    self.takeover.picked_up = false;
+                }
+            }
+
+            if !self.takeover.picked_up {
+                let close = (target.as_f32() - current.as_f32()).abs()
+                    <= TAKEOVER_THRESHOLD;
+                let crossed = match self.takeover.last_input {
+                    Some(last) => {
+                        (self.scaled(last) < current) != (target < current)
+                    }
+                    None => false,
+                };
+
+                self.takeover.last_input = Some(input);
+                if !(close || crossed) {
+                    return None;
+                }
+                self.takeover.picked_up = true;
+            }
+        }
+
+        self.takeover.last_input = Some(input);
+        self.takeover.last_sent = Some(target);
+        Some(target)
+    }
+}
+
+/// The state of a MIDI channel that spans several messages
+///
+/// The least significant 7 bits of a 14-bit controller are kept when only
+/// its most significant 7 bits change, so that the value does not jump
+/// back to a multiple of 128 between the two messages.
+#[derive(Debug, Copy, Clone, Default)]
+struct ChannelState {
+    cc_msb: [u8; 32],
+    cc_lsb: [Option<u8>; 32],
+    nrpn_msb: Option<u8>,
+    nrpn_lsb: Option<u8>,
+    data_msb: u8,
+    data_lsb: Option<u8>,
+}
+
+impl ChannelState {
+    /// Selects another NRPN, whose data entry starts over
+    fn select_nrpn(&mut self, msb: Option<u8>, lsb: Option<u8>) {
+        self.nrpn_msb = msb;
+        self.nrpn_lsb = lsb;
+        self.data_msb = 0;
+        self.data_lsb = None;
+    }
+
+    fn nrpn(&self) -> Option<u16> {
+        match (self.nrpn_msb, self.nrpn_lsb) {
+            (Some(msb), Some(lsb)) => {
+                Some((u16::from(msb) << 7) | u16::from(lsb))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Maps raw MIDI controller messages to parameter values, and binds new
+/// controllers with MIDI learn.
+///
+/// [`arm`] a parameter, such as when the user right-clicks a widget, and
+/// the next control change or pitch bend message received is bound to
+/// it. After that, each message received by [`process`] returns the new
+/// value of every parameter bound to it.
+///
+/// Messages are the raw bytes of a single MIDI message, so they can come
+/// from any MIDI backend or from a test.
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::{MidiLearn, MidiSource, Normal};
+///
+/// let mut midi = MidiLearn::new();
+///
+/// // Learn controller 74 on channel 1 for parameter 3.
+/// midi.arm(3);
+/// let _ = midi.process(&[0xB0, 74, 0], |_| Normal::min());
+///
+/// assert_eq!(
+///     midi.bindings()[0].source,
+///     MidiSource::Cc { channel: 0, controller: 74 }
+/// );
+///
+/// let changes = midi.process(&[0xB0, 74, 127], |_| Normal::min());
+/// assert_eq!(changes, vec![(3, Normal::max())]);
+/// ```
+///
+/// [`arm`]: #method.arm
+/// [`process`]: #method.process
+#[derive(Debug, Clone, Default)]
+pub struct MidiLearn {
+    bindings: Vec<MidiBinding>,
+    armed: Option<usize>,
+    last_learned: Option<usize>,
+    channels: [ChannelState; 16],
+}
+
+impl MidiLearn {
+    /// Creates a new `MidiLearn` without any bindings
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Arms a parameter, so that the next controller message received is
+    /// bound to it
+    ///
+    /// * `id` - the id of the parameter
+    pub fn arm(&mut self, id: usize) {
+        self.armed = Some(id);
+    }
+
+    /// Cancels MIDI learn
+    pub fn disarm(&mut self) {
+        self.armed = None;
+    }
+
+    /// Returns the id of the parameter waiting for a controller, if any
+    pub fn armed(&self) -> Option<usize> {
+        self.armed
+    }
+
+    /// Adds a binding, replacing any binding of the same parameter
+    pub fn bind(&mut self, binding: MidiBinding) {
+        self.unbind(binding.id);
+        self.bindings.push(binding);
+        self.last_learned = None;
+    }
+
+    /// Removes the binding of a parameter
+    pub fn unbind(&mut self, id: usize) {
+        self.bindings.retain(|binding| binding.id != id);
+        self.last_learned = None;
+    }
+
+    /// Returns every binding
+    pub fn bindings(&self) -> &[MidiBinding] {
+        &self.bindings
+    }
+
+    /// Returns the binding of a parameter, such as to change its options,
+    /// or `None` if the parameter is not bound.
+    pub fn binding_mut(&mut self, id: usize) -> Option<&mut MidiBinding> {
+        self.bindings.iter_mut().find(|binding| binding.id == id)
+    }
+
+    /// Processes a raw MIDI message, and returns the `(id, value)` of each
+    /// parameter it changed.
+    ///
+    /// Messages other than control changes and pitch bends are ignored.
+    ///
+    /// * `message` - the bytes of a single MIDI message, including the
+    ///   status byte
+    /// * `current` - returns the current value of the parameter with the
+    ///   supplied id, used for soft takeover
+    pub fn process<F>(
+        &mut self,
+        message: &[u8],
+        current: F,
+    ) -> Vec<(usize, Normal)>
+    where
+        F: Fn(usize) -> Normal,
+    {
+        let mut changes = Vec::new();
+
+        if message.len() < 3 || message[1] > 127 || message[2] > 127 {
+            return changes;
+        }
+
+        let channel = message[0] & 0x0F;
+        let (data_1, data_2) = (message[1], message[2]);
+
+        match message[0] & 0xF0 {
+            0xB0 => self.control_change(
+                channel,
+                data_1,
+                data_2,
+                &current,
+                &mut changes,
+            ),
+            0xE0 => {
+                let value = (u16::from(data_2) << 7) | u16::from(data_1);
+                self.send(
+                    MidiSource::PitchBend { channel },
+                    value_14_bit(value),
+                    &current,
+                    &mut changes,
+                );
+            }
+            _ => {}
+        }
+
+        changes
+    }
+
+    fn control_change<F>(
+        &mut self,
+        channel: u8,
+        controller: u8,
+        value: u8,
+        current: &F,
+        changes: &mut Vec<(usize, Normal)>,
+    ) where
+        F: Fn(usize) -> Normal,
+    {
+        let state = &mut self.channels[usize::from(channel)];
+
+        match controller {
+            99 => state.select_nrpn(Some(value), state.nrpn_lsb),
+            98 => state.select_nrpn(state.nrpn_msb, Some(value)),
+            // An RPN deselects the NRPN.
+            100 | 101 => state.select_nrpn(None, None),
+            6 | 38 if state.nrpn().is_some() => {
+                let number = state.nrpn().unwrap_or(0);
+                if controller == 6 {
+                    state.data_msb = value;
+                } else {
+                    state.data_lsb = Some(value);
+                }
+                let input = value_msb_lsb(state.data_msb, state.data_lsb);
+
+                self.send(
+                    MidiSource::Nrpn { channel, number },
+                    input,
+                    current,
+                    changes,
+                );
+            }
+            0..=31 => {
+                state.cc_msb[usize::from(controller)] = value;
+                let lsb = state.cc_lsb[usize::from(controller)];
+
+                self.send(
+                    MidiSource::Cc {
+                        channel,
+                        controller,
+                    },
+                    value_7_bit(value),
+                    current,
+                    changes,
+                );
+                self.send(
+                    MidiSource::Cc14 {
+                        channel,
+                        controller,
+                    },
+                    value_msb_lsb(value, lsb),
+                    current,
+                    changes,
+                );
+            }
+            32..=63 => {
+                let msb_controller = controller - 32;
+                let msb = state.cc_msb[usize::from(msb_controller)];
+                state.cc_lsb[usize::from(msb_controller)] = Some(value);
+
+                // A learned controller that is followed by its least
+                // significant controller is a 14-bit controller.
+                if let Some(index) = self.last_learned {
+                    let binding = &mut self.bindings[index];
+                    if binding.source
+                        == (MidiSource::Cc {
+                            channel,
+                            controller: msb_controller,
+                        })
+                    {
+                        binding.source = MidiSource::Cc14 {
+                            channel,
+                            controller: msb_controller,
+                        };
+                        self.last_learned = None;
+                    }
+                }
+
+                self.send(
+                    MidiSource::Cc {
+                        channel,
+                        controller,
+                    },
+                    value_7_bit(value),
+                    current,
+                    changes,
+                );
+                self.send(
+                    MidiSource::Cc14 {
+                        channel,
+                        controller: msb_controller,
+                    },
+                    value_msb_lsb(msb, Some(value)),
+                    current,
+                    changes,
+                );
+            }
+            _ => self.send(
+                MidiSource::Cc {
+                    channel,
+                    controller,
+                },
+                value_7_bit(value),
+                current,
+                changes,
+            ),
+        }
+    }
+
+    /// Learns the source if a parameter is armed, and returns the new value
+    /// of every parameter bound to the source
+    fn send<F>(
+        &mut self,
+        source: MidiSource,
+        input: Normal,
+        current: &F,
+        changes: &mut Vec<(usize, Normal)>,
+    ) where
+        F: Fn(usize) -> Normal,
+    {
+        // A 14-bit controller is learned from its most significant
+        // controller as a `Cc`, and upgraded when its least significant
+        // controller follows.
+        let learnable = !matches!(source, MidiSource::Cc14 { .. });
+
+        if learnable {
+            if let Some(id) = self.armed.take() {
+                self.bind(MidiBinding::new(source, id));
+                self.last_learned = Some(self.bindings.len() - 1);
+            }
+        }
+
+        for binding in self.bindings.iter_mut() {
+            if binding.source == source {
+                if let Some(normal) = binding.update(input, current(binding.id))
+                {
+                    changes.push((binding.id, normal));
+                }
+            }
+        }
+    }
+}
+
+fn value_7_bit(value: u8) -> Normal {
+    Normal::new(f32::from(value) / MAX_7_BIT)
+}
+
+fn value_14_bit(value: u16) -> Normal {
+    Normal::new(f32::from(value) / MAX_14_BIT)
+}
+
+/// Returns the value of a 14-bit controller from its most significant 7
+/// bits and the last least significant 7 bits received, if any. A
+/// controller that only sends its most significant 7 bits still moves
+/// across the full range.
+fn value_msb_lsb(msb: u8, lsb: Option<u8>) -> Normal {
+    match lsb {
+        Some(lsb) => value_14_bit((u16::from(msb) << 7) | u16::from(lsb)),
+        None => value_7_bit(msb),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cc14() -> MidiLearn {
+        let mut midi = MidiLearn::new();
+        midi.bind(MidiBinding::new(
+            MidiSource::Cc14 {
+                channel: 0,
+                controller: 7,
+            },
+            0,
+        ));
+        midi
+    }
+
+    fn nrpn() -> MidiLearn {
+        let mut midi = MidiLearn::new();
+        midi.bind(MidiBinding::new(
+            MidiSource::Nrpn {
+                channel: 0,
+                number: 300,
+            },
+            0,
+        ));
+        let _ = midi.process(&[0xB0, 99, 2], |_| Normal::min());
+        let _ = midi.process(&[0xB0, 98, 44], |_| Normal::min());
+        midi
+    }
+
+    fn cc(controller: u8) -> MidiSource {
+        MidiSource::Cc {
+            channel: 0,
+            controller,
+        }
+    }
+
+    #[test]
+    fn learns_the_next_controller() {
+        let mut midi = MidiLearn::new();
+        midi.arm(2);
+        let _ = midi.process(&[0x90, 60, 100], |_| Normal::min());
+        assert_eq!(midi.armed(), Some(2));
+
+        let changes = midi.process(&[0xB3, 74, 127], |_| Normal::min());
+
+        assert_eq!(midi.armed(), None);
+        assert_eq!(changes, vec![(2, Normal::max())]);
+        assert_eq!(
+            midi.bindings()[0].source,
+            MidiSource::Cc {
+                channel: 3,
+                controller: 74,
+            }
+        );
+    }
+
+    #[test]
+    fn learned_controller_followed_by_its_lsb_is_cc14() {
+        let mut midi = MidiLearn::new();
+        midi.arm(0);
+        let _ = midi.process(&[0xB0, 7, 64], |_| Normal::min());
+        let changes = midi.process(&[0xB0, 39, 0], |_| Normal::min());
+
+        assert_eq!(
+            midi.bindings()[0].source,
+            MidiSource::Cc14 {
+                channel: 0,
+                controller: 7,
+            }
+        );
+        assert_eq!(changes, vec![(0, value_14_bit(64 << 7))]);
+    }
+
+    #[test]
+    fn invert_and_sub_range_scale_the_controller() {
+        let mut midi = MidiLearn::new();
+        midi.bind(
+            MidiBinding::new(cc(1), 0)
+                .invert(true)
+                .sub_range(Normal::new(0.25), Normal::new(0.75)),
+        );
+
+        let min = midi.process(&[0xB0, 1, 127], |_| Normal::min());
+        let max = midi.process(&[0xB0, 1, 0], |_| Normal::min());
+
+        assert_eq!(min, vec![(0, Normal::new(0.25))]);
+        assert_eq!(max, vec![(0, Normal::new(0.75))]);
+    }
+
+    #[test]
+    fn pitch_bend_is_14_bit() {
+        let mut midi = MidiLearn::new();
+        midi.bind(MidiBinding::new(MidiSource::PitchBend { channel: 1 }, 0));
+
+        let changes = midi.process(&[0xE1, 0x7F, 0x7F], |_| Normal::min());
+
+        assert_eq!(changes, vec![(0, Normal::max())]);
+    }
+
+    #[test]
+    fn soft_takeover_waits_for_the_controller_to_reach_the_param() {
+        let mut midi = MidiLearn::new();
+        midi.bind(MidiBinding::new(cc(1), 0).soft_takeover(true));
+        let current = |_| Normal::new(0.5);
+
+        assert_eq!(midi.process(&[0xB0, 1, 10], current), vec![]);
+        assert_eq!(midi.process(&[0xB0, 1, 40], current), vec![]);
+        assert_eq!(
+            midi.process(&[0xB0, 1, 64], current),
+            vec![(0, value_7_bit(64))]
+        );
+        assert_eq!(
+            midi.process(&[0xB0, 1, 100], |_| value_7_bit(64)),
+            vec![(0, value_7_bit(100))]
+        );
+    }
+
+    #[test]
+    fn soft_takeover_picks_up_when_the_controller_crosses_the_param() {
+        let mut midi = MidiLearn::new();
+        midi.bind(MidiBinding::new(cc(1), 0).soft_takeover(true));
+        let current = |_| Normal::new(0.5);
+
+        assert_eq!(midi.process(&[0xB0, 1, 20], current), vec![]);
+        assert_eq!(
+            midi.process(&[0xB0, 1, 100], current),
+            vec![(0, value_7_bit(100))]
+        );
+    }
+
+    #[test]
+    fn soft_takeover_drops_after_another_change() {
+        let mut midi = MidiLearn::new();
+        midi.bind(MidiBinding::new(cc(1), 0).soft_takeover(true));
+        let _ = midi.process(&[0xB0, 1, 64], |_| value_7_bit(64));
+
+        // The GUI moved the parameter to its maximum.
+        let changes = midi.process(&[0xB0, 1, 70], |_| Normal::max());
+
+        assert_eq!(changes, vec![]);
+    }
+
+    #[test]
+    fn nrpn_needs_both_number_controllers() {
+        let mut midi = MidiLearn::new();
+        midi.bind(MidiBinding::new(
+            MidiSource::Nrpn {
+                channel: 0,
+                number: 300,
+            },
+            0,
+        ));
+        midi.bind(MidiBinding::new(cc(6), 1));
+
+        let _ = midi.process(&[0xB0, 99, 2], |_| Normal::min());
+        let changes = midi.process(&[0xB0, 6, 127], |_| Normal::min());
+
+        assert_eq!(changes, vec![(1, Normal::max())]);
+    }
+
+    #[test]
+    fn nrpn_is_learned_from_data_entry() {
+        let mut midi = MidiLearn::new();
+        let _ = midi.process(&[0xB5, 99, 2], |_| Normal::min());
+        let _ = midi.process(&[0xB5, 98, 44], |_| Normal::min());
+        midi.arm(4);
+
+        let _ = midi.process(&[0xB5, 6, 64], |_| Normal::min());
+
+        assert_eq!(
+            midi.bindings()[0].source,
+            MidiSource::Nrpn {
+                channel: 5,
+                number: 300,
+            }
+        );
+    }
+
+    #[test]
+    fn rpn_deselects_the_nrpn() {
+        let mut midi = nrpn();
+        let _ = midi.process(&[0xB0, 101, 0], |_| Normal::min());
+
+        let changes = midi.process(&[0xB0, 6, 127], |_| Normal::min());
+
+        assert_eq!(changes, vec![]);
+    }
+
+    #[test]
+    fn new_nrpn_forgets_the_last_lsb() {
+        let mut midi = nrpn();
+        let _ = midi.process(&[0xB0, 38, 5], |_| Normal::min());
+        let _ = midi.process(&[0xB0, 98, 44], |_| Normal::min());
+
+        let changes = midi.process(&[0xB0, 6, 127], |_| Normal::min());
+
+        assert_eq!(changes, vec![(0, Normal::max())]);
+    }
+
+    #[test]
+    fn cc14_without_lsb_reaches_max() {
+        let mut midi = cc14();
+
+        let changes = midi.process(&[0xB0, 7, 127], |_| Normal::min());
+
+        assert_eq!(changes, vec![(0, Normal::max())]);
+    }
+
+    #[test]
+    fn cc14_msb_keeps_last_lsb() {
+        let mut midi = cc14();
+        let _ = midi.process(&[0xB0, 7, 64], |_| Normal::min());
+        let _ = midi.process(&[0xB0, 39, 100], |_| Normal::min());
+
+        let changes = midi.process(&[0xB0, 7, 65], |_| Normal::min());
+
+        assert_eq!(changes, vec![(0, value_14_bit((65 << 7) | 100))]);
+    }
+
+    #[test]
+    fn nrpn_data_entry_msb_only_reaches_max() {
+        let mut midi = nrpn();
+
+        let changes = midi.process(&[0xB0, 6, 127], |_| Normal::min());
+
+        assert_eq!(changes, vec![(0, Normal::max())]);
+    }
+
+    #[test]
+    fn nrpn_data_entry_msb_keeps_last_lsb() {
+        let mut midi = nrpn();
+        let _ = midi.process(&[0xB0, 6, 10], |_| Normal::min());
+        let _ = midi.process(&[0xB0, 38, 5], |_| Normal::min());
+
+        let changes = midi.process(&[0xB0, 6, 11], |_| Normal::min());
+
+        assert_eq!(changes, vec![(0, value_14_bit((11 << 7) | 5))]);
+    }
+}
@@ -3,17 +3,35 @@
 //! This module holds basic types that can be reused and re-exported in
 //! different runtime implementations.
 
+pub mod format;
 pub mod knob_angle_range;
 pub mod math;
+pub mod midi_learn;
+pub mod modulation;
 pub mod modulation_range;
 pub mod normal;
 pub mod normal_param;
 pub mod offset;
+pub mod param_constraint;
+pub mod param_link;
+pub mod param_registry;
+pub mod param_store;
+pub mod preset;
 pub mod range;
+pub mod undo;
 
+pub use format::{Precision, ValueFormatter, MAX_UNIT_LEN};
 pub use knob_angle_range::*;
+pub use midi_learn::{MidiBinding, MidiLearn, MidiSource};
+pub use modulation::{ModPolarity, ModSlot, ModulatedParam};
 pub use modulation_range::ModulationRange;
 pub use normal::Normal;
 pub use normal_param::NormalParam;
 pub use offset::Offset;
+pub use param_constraint::{ParamConstraint, ParamConstraints, Resolve};
+pub use param_link::{LinkMode, ParamLink, ParamLinks};
+pub use param_registry::{ParamInfo, ParamRange, ParamRegistry};
+pub use param_store::ParamStore;
+pub use preset::{Preset, PresetError, PresetMigrations};
 pub use range::*;
+pub use undo::UndoHistory;
@@ -0,0 +1,295 @@
+//! A model of parameters modulated by any number of sources
+
+use super::{ModulationRange, Normal};
+
+/// The range of output values of a modulation source in a [`ModSlot`]
+///
+/// [`ModSlot`]: struct.ModSlot.html
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ModPolarity {
+    /// The source outputs values from `0.0` to `1.0`, such as an envelope,
+    /// and moves the parameter from its base value in the direction of the
+    /// amount.
+    Unipolar,
+    /// The source outputs values from `-1.0` to `1.0`, such as an LFO, and
+    /// moves the parameter to both sides of its base value.
+    Bipolar,
+}
+
+/// A connection from a modulation source to a [`ModulatedParam`]
+///
+/// [`ModulatedParam`]: struct.ModulatedParam.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ModSlot {
+    /// The id of the modulation source, such as the index of an LFO
+    pub source: usize,
+
+    /// How far the source moves the parameter, from `-1.0` to `1.0`. A
+    /// negative amount moves the parameter in the opposite direction.
+    pub amount: f32,
+
+    /// The range of output values of the source
+    pub polarity: ModPolarity,
+}
+
+impl ModSlot {
+    /// Creates a new `ModSlot`
+    ///
+    /// * `source` - the id of the modulation source
+    /// * `amount` - how far the source moves the parameter, from `-1.0` to
+    ///   `1.0`. This is constrained to that range.
+    /// * `polarity` - the range of output values of the source
+    pub fn new(source: usize, amount: f32, polarity: ModPolarity) -> Self {
+        Self {
+            source,
+            amount: amount.clamp(-1.0, 1.0),
+            polarity,
+        }
+    }
+
+    /// Returns the lowest and highest offsets this slot can add to the
+    /// base value of a parameter
+    fn extent(&self) -> (f32, f32) {
+        match self.polarity {
+            ModPolarity::Unipolar => {
+                (self.amount.min(0.0), self.amount.max(0.0))
+            }
+            ModPolarity::Bipolar => (-self.amount.abs(), self.amount.abs()),
+        }
+    }
+
+    /// Returns the offset this slot adds to the base value of a parameter
+    /// for the current output of its source
+    fn offset(&self, output: f32) -> f32 {
+        let output = match self.polarity {
+            ModPolarity::Unipolar => output.clamp(0.0, 1.0),
+            ModPolarity::Bipolar => output.clamp(-1.0, 1.0),
+        };
+
+        output * self.amount
+    }
+}
+
+/// A parameter with a base value set by the user, and any number of
+/// [`ModSlot`]s that move it away from the base value.
+///
+/// This is the single source of truth for both the effective value of the
+/// parameter, and the [`ModulationRange`]s displayed by widgets.
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::{ModPolarity, ModSlot, ModulatedParam, Normal};
+///
+/// let mut cutoff = ModulatedParam::new(Normal::new(0.5));
+///
+/// // LFO 0 moves the cutoff by up to 0.25 to either side.
+/// let _ = cutoff.add_slot(ModSlot::new(0, 0.25, ModPolarity::Bipolar));
+///
+/// let range = cutoff.range();
+/// assert_eq!(range.start, Normal::new(0.25));
+/// assert_eq!(range.end, Normal::new(0.75));
+///
+/// // The LFO is at its peak.
+/// assert_eq!(cutoff.value(|_| 1.0), Normal::new(0.75));
+/// ```
+///
+/// [`ModSlot`]: struct.ModSlot.html
+/// [`ModulationRange`]: ../modulation_range/struct.ModulationRange.html
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ModulatedParam {
+    /// The value of the parameter before modulation
+    pub base: Normal,
+
+    slots: Vec<ModSlot>,
+}
+
+impl ModulatedParam {
+    /// Creates a new `ModulatedParam` without any modulation
+    ///
+    /// * `base` - the value of the parameter before modulation
+    pub fn new(base: Normal) -> Self {
+        Self {
+            base,
+            slots: Vec::new(),
+        }
+    }
+
+    /// Adds a [`ModSlot`] and returns its index
+    ///
+    /// [`ModSlot`]: struct.ModSlot.html
+    pub fn add_slot(&mut self, slot: ModSlot) -> usize {
+        self.slots.push(slot);
+        self.slots.len() - 1
+    }
+
+    /// Removes the [`ModSlot`] at `index` and returns it
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `index` is out of bounds
+    ///
+    /// [`ModSlot`]: struct.ModSlot.html
+    pub fn remove_slot(&mut self, index: usize) -> ModSlot {
+        self.slots.remove(index)
+    }
+
+    /// Removes every [`ModSlot`] of a modulation source
+    ///
+    /// [`ModSlot`]: struct.ModSlot.html
+    pub fn disconnect(&mut self, source: usize) {
+        self.slots.retain(|slot| slot.source != source);
+    }
+
+    /// Returns every [`ModSlot`]
+    ///
+    /// [`ModSlot`]: struct.ModSlot.html
+    pub fn slots(&self) -> &[ModSlot] {
+        &self.slots
+    }
+
+    /// Returns the [`ModSlot`] at `index`, such as to change its amount
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `index` is out of bounds
+    ///
+    /// [`ModSlot`]: struct.ModSlot.html
+    pub fn slot_mut(&mut self, index: usize) -> &mut ModSlot {
+        &mut self.slots[index]
+    }
+
+    /// Returns the effective value of the parameter after modulation
+    ///
+    /// * `output` - returns the current output of the modulation source
+    ///   with the supplied id
+    pub fn value<F>(&self, output: F) -> Normal
+    where
+        F: Fn(usize) -> f32,
+    {
+        let offset: f32 = self
+            .slots
+            .iter()
+            .map(|slot| slot.offset(output(slot.source)))
+            .sum();
+
+        (self.base.as_f32() + offset).into()
+    }
+
+    /// Returns the [`ModulationRange`] of every value the parameter can
+    /// reach with all of its modulation, for display around a widget.
+    ///
+    /// [`ModulationRange`]: ../modulation_range/struct.ModulationRange.html
+    pub fn range(&self) -> ModulationRange {
+        let (low, high) = self.slots.iter().fold((0.0, 0.0), |acc, slot| {
+            let (low, high) = slot.extent();
+            (acc.0 + low, acc.1 + high)
+        });
+
+        self.offset_range(low, high)
+    }
+
+    /// Returns the [`ModulationRange`] of every value a single [`ModSlot`]
+    /// can move the parameter to, such as for a row of a mod matrix.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `index` is out of bounds
+    ///
+    /// [`ModulationRange`]: ../modulation_range/struct.ModulationRange.html
+    /// [`ModSlot`]: struct.ModSlot.html
+    pub fn slot_range(&self, index: usize) -> ModulationRange {
+        let (low, high) = self.slots[index].extent();
+
+        self.offset_range(low, high)
+    }
+
+    /// Returns the [`ModulationRange`] from the base value to the current
+    /// effective value, such as to animate the modulation.
+    ///
+    /// * `output` - returns the current output of the modulation source
+    ///   with the supplied id
+    ///
+    /// [`ModulationRange`]: ../modulation_range/struct.ModulationRange.html
+    pub fn live_range<F>(&self, output: F) -> ModulationRange
+    where
+        F: Fn(usize) -> f32,
+    {
+        ModulationRange::new(self.base, self.value(output))
+    }
+
+    fn offset_range(&self, low: f32, high: f32) -> ModulationRange {
+        ModulationRange::new(
+            (self.base.as_f32() + low).into(),
+            (self.base.as_f32() + high).into(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn n(value: f32) -> Normal {
+        Normal::new(value)
+    }
+
+    fn ends(range: ModulationRange) -> (Normal, Normal) {
+        (range.start, range.end)
+    }
+
+    #[test]
+    fn unipolar_slot_moves_in_the_direction_of_its_amount() {
+        let mut param = ModulatedParam::new(n(0.5));
+        let _ = param.add_slot(ModSlot::new(0, -0.25, ModPolarity::Unipolar));
+
+        assert_eq!(ends(param.range()), (n(0.25), n(0.5)));
+        assert_eq!(param.value(|_| 1.0), n(0.25));
+        // A unipolar source below zero does not move the parameter.
+        assert_eq!(param.value(|_| -1.0), n(0.5));
+    }
+
+    #[test]
+    fn slots_add_up_and_stay_in_range() {
+        let mut param = ModulatedParam::new(n(0.75));
+        let _ = param.add_slot(ModSlot::new(0, 0.25, ModPolarity::Bipolar));
+        let _ = param.add_slot(ModSlot::new(1, 2.0, ModPolarity::Unipolar));
+
+        assert_eq!(param.slots()[1].amount, 1.0);
+        assert_eq!(ends(param.range()), (n(0.5), n(1.0)));
+        assert_eq!(ends(param.slot_range(0)), (n(0.5), n(1.0)));
+        assert_eq!(
+            param.value(|source| if source == 0 { -1.0 } else { 0.0 }),
+            n(0.5)
+        );
+        assert_eq!(param.value(|_| 1.0), n(1.0));
+    }
+
+    #[test]
+    fn live_range_follows_the_sources() {
+        let mut param = ModulatedParam::new(n(0.5));
+        let _ = param.add_slot(ModSlot::new(3, 0.5, ModPolarity::Bipolar));
+
+        assert_eq!(ends(param.live_range(|_| -0.5)), (n(0.5), n(0.25)));
+    }
+
+    #[test]
+    fn disconnect_removes_every_slot_of_a_source() {
+        let mut param = ModulatedParam::new(n(0.5));
+        let _ = param.add_slot(ModSlot::new(0, 0.25, ModPolarity::Bipolar));
+        let _ = param.add_slot(ModSlot::new(1, 0.25, ModPolarity::Bipolar));
+        let _ = param.add_slot(ModSlot::new(0, 0.5, ModPolarity::Unipolar));
+
+        param.disconnect(0);
+
+        assert_eq!(
+            param.slots(),
+            &[ModSlot::new(1, 0.25, ModPolarity::Bipolar)]
+        );
+        assert_eq!(param.remove_slot(0).source, 1);
+        assert_eq!(ends(param.range()), (n(0.5), n(0.5)));
+    }
+}
@@ -4,6 +4,7 @@ use super::normal::Normal;
 
 /// The state of a modulation range
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ModulationRange {
     /// Where the modulation range starts.
     /// `0.0.into()` is all the way minimum, and `1.0.into()` is all the way maximum.
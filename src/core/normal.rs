@@ -21,6 +21,8 @@
 /// assert_eq!(normal.as_f32(), 0.5);
 /// ```
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "NormalDef", into = "f32"))]
 pub struct Normal {
     value: f32,
 }
@@ -133,3 +135,57 @@ impl From<Normal> for f32 {
         normal.value
     }
 }
+
+/// The serialized form of a [`Normal`], a plain `f32`. Values outside of
+/// `0.0` to `1.0` are constrained as with [`Normal::new`], but `NaN` is
+/// rejected.
+///
+/// [`Normal`]: struct.Normal.html
+/// [`Normal::new`]: struct.Normal.html#method.new
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(transparent)]
+struct NormalDef(f32);
+
+#[cfg(feature = "serde")]
+impl std::convert::TryFrom<NormalDef> for Normal {
+    type Error = &'static str;
+
+    fn try_from(def: NormalDef) -> Result<Self, Self::Error> {
+        if def.0.is_nan() {
+            return Err("a Normal must not be NaN");
+        }
+
+        Ok(Normal::new(def.0))
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+    use serde::de::{value, Deserialize, IntoDeserializer};
+
+    #[test]
+    fn serde_round_trips_as_f32() {
+        let normal = Normal::new(0.25);
+        let json = serde_json::to_string(&normal).unwrap();
+
+        assert_eq!(json, "0.25");
+        assert_eq!(serde_json::from_str::<Normal>(&json).unwrap(), normal);
+        assert_eq!(
+            serde_json::from_str::<Normal>("2.5").unwrap(),
+            Normal::max()
+        );
+    }
+
+    #[test]
+    fn serde_rejects_nan() {
+        let nan: value::F32Deserializer<value::Error> =
+            f32::NAN.into_deserializer();
+        let infinity: value::F32Deserializer<value::Error> =
+            f32::INFINITY.into_deserializer();
+
+        assert!(Normal::deserialize(nan).is_err());
+        assert_eq!(Normal::deserialize(infinity), Ok(Normal::max()));
+    }
+}
@@ -0,0 +1,472 @@
+//! Rules that tie the values of several parameters together
+
+use super::Normal;
+
+/// How an [`Ordered`] constraint is kept when an edit would break it
+///
+/// [`Ordered`]: enum.ParamConstraint.html#variant.Ordered
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Resolve {
+    /// The other parameter is pushed along with the edited parameter
+    Push,
+    /// The edited parameter stops at the other parameter
+    Clamp,
+}
+
+/// A rule that ties the values of several parameters together
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ParamConstraint {
+    /// The value of `low` stays at least `min_gap` below the value of
+    /// `high`, such as a low cut below a high cut
+    Ordered {
+        /// The id of the lower parameter
+        low: usize,
+        /// The id of the higher parameter
+        high: usize,
+        /// The smallest distance between the two values, as a [`Normal`]
+        /// distance
+        ///
+        /// [`Normal`]: ../struct.Normal.html
+        min_gap: f32,
+        /// How the constraint is kept
+        resolve: Resolve,
+    },
+    /// The values of the parameters add up to `1.0`, such as the levels
+    /// of a mixer. When one parameter is edited, the others are scaled to
+    /// share the rest. Parameters that were pushed by an [`Ordered`]
+    /// constraint keep their value, and only the rest are scaled.
+    ///
+    /// [`Ordered`]: #variant.Ordered
+    SumToOne(Vec<usize>),
+}
+
+impl ParamConstraint {
+    /// Creates a new [`Ordered`] constraint without a gap, that pushes the
+    /// other parameter
+    ///
+    /// * `low` - the id of the lower parameter
+    /// * `high` - the id of the higher parameter
+    ///
+    /// [`Ordered`]: #variant.Ordered
+    pub fn ordered(low: usize, high: usize) -> Self {
+        ParamConstraint::Ordered {
+            low,
+            high,
+            min_gap: 0.0,
+            resolve: Resolve::Push,
+        }
+    }
+
+    /// Creates a new [`Ordered`] constraint with a minimum gap, that
+    /// pushes the other parameter
+    ///
+    /// * `low` - the id of the lower parameter
+    /// * `high` - the id of the higher parameter
+    /// * `min_gap` - the smallest distance between the two values
+    ///
+    /// [`Ordered`]: #variant.Ordered
+    pub fn min_gap(low: usize, high: usize, min_gap: f32) -> Self {
+        ParamConstraint::Ordered {
+            low,
+            high,
+            min_gap,
+            resolve: Resolve::Push,
+        }
+    }
+
+    /// Creates a new [`SumToOne`] constraint
+    ///
+    /// * `ids` - the ids of the parameters
+    ///
+    /// [`SumToOne`]: #variant.SumToOne
+    pub fn sum_to_one(ids: &[usize]) -> Self {
+        ParamConstraint::SumToOne(ids.to_vec())
+    }
+
+    /// Returns `true` if the constraint involves a parameter
+    pub fn contains(&self, id: usize) -> bool {
+        match self {
+            ParamConstraint::Ordered { low, high, .. } => {
+                *low == id || *high == id
+            }
+            ParamConstraint::SumToOne(ids) => ids.contains(&id),
+        }
+    }
+}
+
+/// The values of the parameters being changed by an edit
+struct Edit<'a, F> {
+    id: usize,
+    values: Vec<(usize, Normal)>,
+    /// The edited parameter, and the parameters pushed by an `Ordered`
+    /// constraint, which a `SumToOne` constraint does not scale
+    pinned: Vec<usize>,
+    current: &'a F,
+}
+
+impl<'a, F: Fn(usize) -> Normal> Edit<'a, F> {
+    fn get(&self, id: usize) -> Normal {
+        self.values
+            .iter()
+            .find(|(changed, _)| *changed == id)
+            .map(|(_, normal)| *normal)
+            .unwrap_or_else(|| (self.current)(id))
+    }
+
+    fn was_moved(&self, id: usize) -> bool {
+        self.values.iter().any(|(changed, _)| *changed == id)
+    }
+
+    fn is_pinned(&self, id: usize) -> bool {
+        self.pinned.contains(&id)
+    }
+
+    /// Sets a value that a `SumToOne` constraint must not scale, and
+    /// returns `true` if it changed
+    fn pin(&mut self, id: usize, normal: Normal) -> bool {
+        if !self.is_pinned(id) {
+            self.pinned.push(id);
+        }
+        self.set(id, normal)
+    }
+
+    /// Sets a value, and returns `true` if it changed
+    fn set(&mut self, id: usize, normal: Normal) -> bool {
+        if self.get(id) == normal {
+            return false;
+        }
+
+        match self.values.iter_mut().find(|(changed, _)| *changed == id) {
+            Some((_, value)) => *value = normal,
+            None => self.values.push((id, normal)),
+        }
+        true
+    }
+}
+
+/// A set of [`ParamConstraint`]s between parameters.
+///
+/// When the user edits a parameter, [`apply`] returns the edit with every
+/// constraint kept: the value of the edited parameter, and the new value
+/// of every other parameter that was pushed or scaled. Constraints may
+/// share parameters, such as the bands of a three-band crossover.
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::{Normal, ParamConstraint, ParamConstraints};
+///
+/// let (low_cut, high_cut) = (0, 1);
+///
+/// let mut constraints = ParamConstraints::new();
+/// constraints.add(ParamConstraint::min_gap(low_cut, high_cut, 0.1));
+///
+/// // The high cut is at 0.5, and the user drags the low cut to 0.45.
+/// let changes = constraints.apply(low_cut, Normal::new(0.45), |_| {
+///     Normal::new(0.5)
+/// });
+///
+/// assert_eq!(changes[0], (low_cut, Normal::new(0.45)));
+/// assert_eq!(changes[1].0, high_cut);
+/// assert!((changes[1].1.as_f32() - 0.55).abs() < 0.0001);
+/// ```
+///
+/// [`ParamConstraint`]: enum.ParamConstraint.html
+/// [`apply`]: #method.apply
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ParamConstraints {
+    constraints: Vec<ParamConstraint>,
+}
+
+impl ParamConstraints {
+    /// Creates a new `ParamConstraints` without any constraints
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a [`ParamConstraint`]
+    ///
+    /// [`ParamConstraint`]: enum.ParamConstraint.html
+    pub fn add(&mut self, constraint: ParamConstraint) {
+        self.constraints.push(constraint);
+    }
+
+    /// Removes every constraint that involves a parameter
+    pub fn remove_all(&mut self, id: usize) {
+        self.constraints
+            .retain(|constraint| !constraint.contains(id));
+    }
+
+    /// Returns every [`ParamConstraint`]
+    ///
+    /// [`ParamConstraint`]: enum.ParamConstraint.html
+    pub fn constraints(&self) -> &[ParamConstraint] {
+        &self.constraints
+    }
+
+    /// Returns the `(id, value)` of every parameter changed by an edit. The
+    /// edited parameter is always first, and its value may be clamped by a
+    /// constraint.
+    ///
+    /// * `id` - the id of the edited parameter
+    /// * `normal` - the value the user set
+    /// * `current` - returns the current value of the parameter with the
+    ///   supplied id
+    pub fn apply<F>(
+        &self,
+        id: usize,
+        normal: Normal,
+        current: F,
+    ) -> Vec<(usize, Normal)>
+    where
+        F: Fn(usize) -> Normal,
+    {
+        let mut edit = Edit {
+            id,
+            values: vec![(id, normal)],
+            pinned: vec![id],
+            current: &current,
+        };
+
+        // Keeping one constraint can break another one that shares a
+        // parameter, so repeat until every constraint is kept.
+        for _ in 0..=self.constraints.len() {
+            let mut changed = false;
+            for constraint in self.constraints.iter() {
+                changed |= keep(constraint, &mut edit);
+            }
+            if !changed {
+                break;
+            }
+        }
+
+        edit.values
+    }
+}
+
+/// Keeps a constraint, and returns `true` if a value changed
+fn keep<F>(constraint: &ParamConstraint, edit: &mut Edit<'_, F>) -> bool
+where
+    F: Fn(usize) -> Normal,
+{
+    match constraint {
+        ParamConstraint::Ordered {
+            low,
+            high,
+            min_gap,
+            resolve,
+        } => {
+            let (low, high, min_gap) = (*low, *high, min_gap.max(0.0));
+            let low_value = edit.get(low).as_f32();
+            let high_value = edit.get(high).as_f32();
+
+            if high_value - low_value >= min_gap {
+                return false;
+            }
+
+            // The parameter that moved stays where it is, unless the other
+            // parameter cannot move far enough.
+            let low_moved =
+                edit.id == low || (edit.id != high && edit.was_moved(low));
+            let clamp = *resolve == Resolve::Clamp
+                && (edit.id == low || edit.id == high);
+
+            let (new_low, new_high) = if low_moved != clamp {
+                if low_value + min_gap <= 1.0 {
+                    (low_value, low_value + min_gap)
+                } else {
+                    (1.0 - min_gap, 1.0)
+                }
+            } else if high_value - min_gap >= 0.0 {
+                (high_value - min_gap, high_value)
+            } else {
+                (0.0, min_gap)
+            };
+
+            let low_changed = edit.pin(low, new_low.into());
+            let high_changed = edit.pin(high, new_high.into());
+            low_changed || high_changed
+        }
+        ParamConstraint::SumToOne(ids) => {
+            if !ids.iter().any(|id| edit.is_pinned(*id)) {
+                return false;
+            }
+
+            let (pinned, free): (Vec<usize>, Vec<usize>) =
+                ids.iter().copied().partition(|id| edit.is_pinned(*id));
+            if free.is_empty() {
+                // A single parameter always has the whole sum.
+                return ids.len() == 1 && edit.set(ids[0], Normal::max());
+            }
+
+            let rest = 1.0
+                - pinned.iter().map(|id| edit.get(*id).as_f32()).sum::<f32>();
+            let sum: f32 = free.iter().map(|id| edit.get(*id).as_f32()).sum();
+
+            if (sum - rest).abs() < f32::EPSILON {
+                return false;
+            }
+
+            let mut changed = false;
+            for other in free.iter() {
+                let value = if sum > 0.0 {
+                    edit.get(*other).as_f32() * rest / sum
+                } else {
+                    rest / free.len() as f32
+                };
+                changed |= edit.set(*other, value.into());
+            }
+            changed
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn n(value: f32) -> Normal {
+        Normal::new(value)
+    }
+
+    /// Returns the value of every parameter after applying the changes
+    fn values(current: &[f32], changes: &[(usize, Normal)]) -> Vec<f32> {
+        let mut values = current.to_vec();
+        for (id, normal) in changes.iter() {
+            values[*id] = normal.as_f32();
+        }
+        values
+    }
+
+    fn assert_close(values: &[f32], expected: &[f32]) {
+        assert_eq!(values.len(), expected.len());
+        for (value, expected) in values.iter().zip(expected.iter()) {
+            assert!(
+                (value - expected).abs() < 0.0001,
+                "{:?} != {:?}",
+                values,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn ordered_pushes_the_other_param() {
+        let mut constraints = ParamConstraints::new();
+        constraints.add(ParamConstraint::ordered(0, 1));
+
+        let changes = constraints.apply(1, n(0.25), |_| n(0.5));
+
+        assert_eq!(changes, vec![(1, n(0.25)), (0, n(0.25))]);
+    }
+
+    #[test]
+    fn ordered_gap_stops_at_the_end_of_the_range() {
+        let mut constraints = ParamConstraints::new();
+        constraints.add(ParamConstraint::min_gap(0, 1, 0.25));
+
+        let changes = constraints.apply(0, n(0.875), |_| n(0.5));
+
+        assert_eq!(changes, vec![(0, n(0.75)), (1, n(1.0))]);
+    }
+
+    #[test]
+    fn clamp_stops_the_edited_param() {
+        let mut constraints = ParamConstraints::new();
+        constraints.add(ParamConstraint::Ordered {
+            low: 0,
+            high: 1,
+            min_gap: 0.25,
+            resolve: Resolve::Clamp,
+        });
+
+        let low = constraints.apply(0, n(0.75), |_| n(0.5));
+        let high = constraints.apply(1, n(0.125), |_| n(0.5));
+        let kept = constraints.apply(0, n(0.125), |_| n(0.5));
+
+        assert_eq!(low, vec![(0, n(0.25))]);
+        assert_eq!(high, vec![(1, n(0.75))]);
+        assert_eq!(kept, vec![(0, n(0.125))]);
+    }
+
+    #[test]
+    fn shared_ordered_params_push_in_a_chain() {
+        let mut constraints = ParamConstraints::new();
+        constraints.add(ParamConstraint::ordered(0, 1));
+        constraints.add(ParamConstraint::ordered(1, 2));
+        let current = [0.25, 0.5, 0.75];
+
+        let changes = constraints.apply(0, n(0.875), |id| n(current[id]));
+
+        assert_eq!(values(&current, &changes), [0.875, 0.875, 0.875]);
+    }
+
+    #[test]
+    fn sum_to_one_scales_the_others() {
+        let mut constraints = ParamConstraints::new();
+        constraints.add(ParamConstraint::sum_to_one(&[0, 1, 2]));
+        let current = [0.5, 0.25, 0.25];
+
+        let changes = constraints.apply(0, n(0.75), |id| n(current[id]));
+        assert_close(&values(&current, &changes), &[0.75, 0.125, 0.125]);
+
+        let silent = constraints.apply(0, n(0.5), |_| n(0.0));
+        assert_close(&values(&[0.0; 3], &silent), &[0.5, 0.25, 0.25]);
+    }
+
+    #[test]
+    fn sum_to_one_keeps_params_pushed_by_ordered() {
+        let mut constraints = ParamConstraints::new();
+        constraints.add(ParamConstraint::sum_to_one(&[0, 1, 2]));
+        constraints.add(ParamConstraint::ordered(1, 2));
+        let current = [0.4, 0.1, 0.5];
+
+        let changes = constraints.apply(1, n(0.4), |id| n(current[id]));
+
+        assert_eq!(changes[0], (1, n(0.4)));
+        assert_close(&values(&current, &changes), &[0.2, 0.4, 0.4]);
+    }
+
+    #[test]
+    fn sum_to_one_follows_a_param_pushed_by_ordered() {
+        let mut constraints = ParamConstraints::new();
+        constraints.add(ParamConstraint::sum_to_one(&[1, 2]));
+        constraints.add(ParamConstraint::ordered(0, 1));
+        let current = [0.25, 0.5, 0.5];
+
+        let changes = constraints.apply(0, n(0.75), |id| n(current[id]));
+
+        assert_close(&values(&current, &changes), &[0.75, 0.75, 0.25]);
+    }
+
+    #[test]
+    fn remove_all_removes_constraints_of_a_param() {
+        let mut constraints = ParamConstraints::new();
+        constraints.add(ParamConstraint::ordered(0, 1));
+        constraints.add(ParamConstraint::sum_to_one(&[1, 2]));
+        constraints.add(ParamConstraint::ordered(2, 3));
+
+        constraints.remove_all(1);
+
+        assert_eq!(
+            constraints.constraints(),
+            &[ParamConstraint::ordered(2, 3)]
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trips() {
+        let mut constraints = ParamConstraints::new();
+        constraints.add(ParamConstraint::ordered(0, 1));
+        constraints.add(ParamConstraint::SumToOne(vec![1, 2, 3]));
+
+        let json = serde_json::to_string(&constraints).unwrap();
+        let loaded: ParamConstraints = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(loaded.constraints(), constraints.constraints());
+    }
+}
@@ -0,0 +1,342 @@
+//! Linking of parameters, so that moving one parameter moves others
+
+use std::collections::VecDeque;
+
+use super::Normal;
+
+/// How a [`ParamLink`] moves its target parameter
+///
+/// [`ParamLink`]: struct.ParamLink.html
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LinkMode {
+    /// The target is set to the value of the source, such as for the left
+    /// and right gain of a stereo pair
+    Absolute,
+    /// The target is moved by the same amount as the source, keeping the
+    /// offset between them, such as for the bands of a crossover or a
+    /// macro control. The target is constrained to its range.
+    Relative,
+}
+
+/// A link from a source parameter to a target parameter
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ParamLink {
+    /// The id of the target parameter
+    pub target: usize,
+
+    /// How the target is moved
+    pub mode: LinkMode,
+
+    /// How far the target moves for each step the source moves. The
+    /// default is `1.0`.
+    pub scale: f32,
+
+    /// Whether the target moves in the opposite direction to the source
+    pub invert: bool,
+}
+
+impl ParamLink {
+    /// Creates a new [`LinkMode::Absolute`] link
+    ///
+    /// * `target` - the id of the target parameter
+    ///
+    /// [`LinkMode::Absolute`]: enum.LinkMode.html#variant.Absolute
+    pub fn absolute(target: usize) -> Self {
+        Self {
+            target,
+            mode: LinkMode::Absolute,
+            scale: 1.0,
+            invert: false,
+        }
+    }
+
+    /// Creates a new [`LinkMode::Relative`] link
+    ///
+    /// * `target` - the id of the target parameter
+    ///
+    /// [`LinkMode::Relative`]: enum.LinkMode.html#variant.Relative
+    pub fn relative(target: usize) -> Self {
+        Self {
+            target,
+            mode: LinkMode::Relative,
+            scale: 1.0,
+            invert: false,
+        }
+    }
+
+    /// Sets how far the target moves for each step the source moves
+    pub fn scale(mut self, scale: f32) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Sets whether the target moves in the opposite direction to the
+    /// source
+    pub fn invert(mut self, invert: bool) -> Self {
+        self.invert = invert;
+        self
+    }
+
+    /// Returns the new value of the target when the source moves
+    fn apply(&self, old: Normal, new: Normal, target: Normal) -> Normal {
+        match self.mode {
+            LinkMode::Absolute => {
+                let value = if self.invert {
+                    1.0 - new.as_f32()
+                } else {
+                    new.as_f32()
+                };
+
+                (value * self.scale).into()
+            }
+            LinkMode::Relative => {
+                let delta = (new.as_f32() - old.as_f32()) * self.scale;
+                let delta = if self.invert { -delta } else { delta };
+
+                (target.as_f32() + delta).into()
+            }
+        }
+    }
+}
+
+/// A set of [`ParamLink`]s between parameters.
+///
+/// When the user moves a parameter, [`propagate`] returns the new value of
+/// every parameter linked to it, including through other linked
+/// parameters. Links may form cycles, such as a stereo pair linked in both
+/// directions, and each parameter is moved at most once.
+///
+/// Set the returned values on the `State` of each widget in the same
+/// `update` that handles the change, so that every linked widget moves in
+/// the same frame.
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::{Normal, ParamLink, ParamLinks};
+///
+/// let (left, right, low_band, high_band) = (0, 1, 2, 3);
+///
+/// let mut links = ParamLinks::new();
+/// links.link_pair(left, right);
+/// links.link(low_band, ParamLink::relative(high_band));
+///
+/// let changes =
+///     links.propagate(left, Normal::new(0.5), Normal::new(0.8), |_| {
+///         Normal::new(0.5)
+///     });
+/// assert_eq!(changes, vec![(right, Normal::new(0.8))]);
+///
+/// let changes =
+///     links.propagate(low_band, Normal::new(0.2), Normal::new(0.3), |_| {
+///         Normal::new(0.95)
+///     });
+/// assert_eq!(changes, vec![(high_band, Normal::max())]);
+/// ```
+///
+/// [`ParamLink`]: struct.ParamLink.html
+/// [`propagate`]: #method.propagate
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ParamLinks {
+    links: Vec<(usize, ParamLink)>,
+}
+
+impl ParamLinks {
+    /// Creates a new `ParamLinks` without any links
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Links a source parameter to a target parameter, replacing any link
+    /// between them
+    ///
+    /// * `source` - the id of the source parameter
+    /// * `link` - the [`ParamLink`] to the target
+    ///
+    /// [`ParamLink`]: struct.ParamLink.html
+    pub fn link(&mut self, source: usize, link: ParamLink) {
+        self.unlink(source, link.target);
+        self.links.push((source, link));
+    }
+
+    /// Links two parameters absolutely in both directions, such as a
+    /// stereo pair
+    pub fn link_pair(&mut self, a: usize, b: usize) {
+        self.link(a, ParamLink::absolute(b));
+        self.link(b, ParamLink::absolute(a));
+    }
+
+    /// Removes the link from a source parameter to a target parameter
+    pub fn unlink(&mut self, source: usize, target: usize) {
+        self.links
+            .retain(|(s, link)| !(*s == source && link.target == target));
+    }
+
+    /// Removes every link from or to a parameter
+    pub fn unlink_all(&mut self, id: usize) {
+        self.links
+            .retain(|(source, link)| *source != id && link.target != id);
+    }
+
+    /// Returns an iterator over the [`ParamLink`]s from a source parameter
+    ///
+    /// [`ParamLink`]: struct.ParamLink.html
+    pub fn links_from(
+        &self,
+        source: usize,
+    ) -> impl Iterator<Item = &ParamLink> + '_ {
+        self.links
+            .iter()
+            .filter(move |(s, _)| *s == source)
+            .map(|(_, link)| link)
+    }
+
+    /// Returns the `(id, value)` of every parameter moved by a change to a
+    /// source parameter. The source itself is not returned.
+    ///
+    /// * `source` - the id of the parameter the user moved
+    /// * `old` - the value of the source before the change
+    /// * `new` - the value of the source after the change
+    /// * `current` - returns the current value of the parameter with the
+    ///   supplied id
+    pub fn propagate<F>(
+        &self,
+        source: usize,
+        old: Normal,
+        new: Normal,
+        current: F,
+    ) -> Vec<(usize, Normal)>
+    where
+        F: Fn(usize) -> Normal,
+    {
+        let mut changes: Vec<(usize, Normal)> = Vec::new();
+        let mut queue = VecDeque::new();
+        queue.push_back((source, old, new));
+
+        while let Some((id, old, new)) = queue.pop_front() {
+            for link in self.links_from(id) {
+                if link.target == source
+                    || changes
+                        .iter()
+                        .any(|(changed, _)| *changed == link.target)
+                {
+                    continue;
+                }
+
+                let target_old = current(link.target);
+                let target_new = link.apply(old, new, target_old);
+
+                changes.push((link.target, target_new));
+                queue.push_back((link.target, target_old, target_new));
+            }
+        }
+
+        changes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn n(value: f32) -> Normal {
+        Normal::new(value)
+    }
+
+    #[test]
+    fn cycle_moves_each_param_once() {
+        let mut links = ParamLinks::new();
+        links.link(0, ParamLink::relative(1));
+        links.link(1, ParamLink::relative(2));
+        links.link(2, ParamLink::relative(0));
+
+        let changes = links.propagate(0, n(0.25), n(0.5), |_| n(0.25));
+
+        assert_eq!(changes, vec![(1, n(0.5)), (2, n(0.5))]);
+    }
+
+    #[test]
+    fn param_reached_twice_moves_once() {
+        let mut links = ParamLinks::new();
+        links.link(0, ParamLink::relative(1));
+        links.link(0, ParamLink::relative(2));
+        links.link(1, ParamLink::relative(2).scale(2.0));
+
+        let changes = links.propagate(0, n(0.25), n(0.5), |_| n(0.25));
+
+        assert_eq!(changes, vec![(1, n(0.5)), (2, n(0.5))]);
+    }
+
+    #[test]
+    fn stereo_pair_does_not_move_its_source() {
+        let mut links = ParamLinks::new();
+        links.link_pair(0, 1);
+
+        let changes = links.propagate(1, n(0.5), n(0.75), |_| n(0.5));
+
+        assert_eq!(changes, vec![(0, n(0.75))]);
+    }
+
+    #[test]
+    fn links_chain_through_other_params() {
+        let mut links = ParamLinks::new();
+        links.link(0, ParamLink::absolute(1).invert(true));
+        links.link(1, ParamLink::relative(2).scale(0.5));
+
+        let changes = links.propagate(0, n(0.5), n(0.75), |_| n(0.5));
+
+        assert_eq!(changes, vec![(1, n(0.25)), (2, n(0.375))]);
+    }
+
+    #[test]
+    fn relative_target_is_constrained() {
+        let mut links = ParamLinks::new();
+        links.link(0, ParamLink::relative(1).invert(true));
+
+        let changes = links.propagate(0, n(0.25), n(0.75), |_| n(0.25));
+
+        assert_eq!(changes, vec![(1, Normal::min())]);
+    }
+
+    #[test]
+    fn unlink_all_removes_links_from_and_to_a_param() {
+        let mut links = ParamLinks::new();
+        links.link_pair(0, 1);
+        links.link(1, ParamLink::relative(2));
+        links.link(2, ParamLink::absolute(3));
+        // Linking again replaces the link.
+        links.link(2, ParamLink::relative(3));
+
+        links.unlink_all(1);
+
+        assert_eq!(links.links_from(0).count(), 0);
+        assert_eq!(links.links_from(1).count(), 0);
+        assert_eq!(
+            links.links_from(2).collect::<Vec<_>>(),
+            vec![&ParamLink::relative(3)]
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trips() {
+        let mut links = ParamLinks::new();
+        links.link_pair(0, 1);
+        links.link(0, ParamLink::relative(2).scale(0.5));
+
+        let json = serde_json::to_string(&links).unwrap();
+        let loaded: ParamLinks = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(
+            loaded.links_from(0).collect::<Vec<_>>(),
+            links.links_from(0).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            loaded.propagate(0, n(0.25), n(0.5), |_| n(0.25)),
+            links.propagate(0, n(0.25), n(0.5), |_| n(0.25))
+        );
+    }
+}
@@ -0,0 +1,598 @@
+//! A registry that describes every parameter of an application once
+
+use std::any::Any;
+use std::fmt::Debug;
+use std::str::FromStr;
+
+use super::{Normal, NormalParam, ParamStore, Preset, Range};
+
+/// The object-safe part of [`Range`] used by [`ParamRange`]
+///
+/// [`Range`]: ../range/trait.Range.html
+/// [`ParamRange`]: struct.ParamRange.html
+trait ErasedRange: Debug {
+    fn format(&self, normal: Normal) -> String;
+    fn parse(&self, text: &str) -> Option<Normal>;
+    fn snapped(&self, normal: Normal) -> Normal;
+    fn snapped_fine(&self, normal: Normal) -> Normal;
+    fn step(&self, normal: Normal, steps: i32) -> Normal;
+    fn discrete_len(&self) -> Option<usize>;
+    fn is_quantized(&self) -> bool;
+    fn default_normal(&self) -> Normal;
+    fn value_text(&self, normal: Normal) -> String;
+    fn parse_value_text(&self, text: &str) -> Result<Normal, ValueTextError>;
+    fn as_any(&self) -> &dyn Any;
+}
+
+/// Why a [`ParamRange`] could not parse value text
+///
+/// [`ParamRange`]: struct.ParamRange.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) enum ValueTextError {
+    Invalid,
+    OutOfRange,
+}
+
+impl<R> ErasedRange for R
+where
+    R: Range + Debug + 'static,
+    R::Value: FromStr,
+{
+    fn format(&self, normal: Normal) -> String {
+        Range::format(self, normal)
+    }
+
+    fn parse(&self, text: &str) -> Option<Normal> {
+        Range::parse(self, text)
+    }
+
+    fn snapped(&self, normal: Normal) -> Normal {
+        Range::snapped(self, normal)
+    }
+
+    fn snapped_fine(&self, normal: Normal) -> Normal {
+        Range::snapped_fine(self, normal)
+    }
+
+    fn step(&self, normal: Normal, steps: i32) -> Normal {
+        Range::step(self, normal, steps)
+    }
+
+    fn discrete_len(&self) -> Option<usize> {
+        Range::discrete_len(self)
+    }
+
+    fn is_quantized(&self) -> bool {
+        Range::is_quantized(self)
+    }
+
+    fn default_normal(&self) -> Normal {
+        self.map_to_normal(self.default_value())
+    }
+
+    fn value_text(&self, normal: Normal) -> String {
+        self.preset_text(self.unmap_to_value(normal))
+    }
+
+    fn parse_value_text(&self, text: &str) -> Result<Normal, ValueTextError> {
+        let text = text.trim();
+        let value: R::Value = match self.parse_preset_text(text) {
+            Some(value) => value,
+            None => text.parse().map_err(|_| ValueTextError::Invalid)?,
+        };
+
+        if value.partial_cmp(&value).is_none() {
+            Err(ValueTextError::Invalid)
+        } else if value < self.min() || value > self.max() {
+            Err(ValueTextError::OutOfRange)
+        } else {
+            Ok(self.map_to_normal(value))
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Any [`Range`], with its value type erased so that parameters with
+/// different kinds of ranges can be stored together.
+///
+/// Use [`downcast_ref`] to get back the original range and its typed
+/// values.
+///
+/// [`Range`]: ../range/trait.Range.html
+/// [`downcast_ref`]: #method.downcast_ref
+#[derive(Debug)]
+pub struct ParamRange {
+    range: Box<dyn ErasedRange>,
+}
+
+impl ParamRange {
+    /// Creates a new `ParamRange` from any [`Range`] whose values can be
+    /// parsed with `FromStr`
+    ///
+    /// [`Range`]: ../range/trait.Range.html
+    pub fn new<R>(range: R) -> Self
+    where
+        R: Range + Debug + 'static,
+        R::Value: FromStr,
+    {
+        Self {
+            range: Box::new(range),
+        }
+    }
+
+    /// Returns the value of the supplied [`Normal`] as display text.
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn format(&self, normal: Normal) -> String {
+        self.range.format(normal)
+    }
+
+    /// Parses typed text into the corresponding [`Normal`], or returns
+    /// `None` if the text is not a valid value.
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn parse(&self, text: &str) -> Option<Normal> {
+        self.range.parse(text)
+    }
+
+    /// Returns the [`Normal`] of the closest value in the range.
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn snapped(&self, normal: Normal) -> Normal {
+        self.range.snapped(normal)
+    }
+
+    /// Returns the [`Normal`] of the closest value in the range for a fine
+    /// drag of a widget.
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn snapped_fine(&self, normal: Normal) -> Normal {
+        self.range.snapped_fine(normal)
+    }
+
+    /// Returns the [`Normal`] that is `steps` steps away from `normal`.
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn step(&self, normal: Normal, steps: i32) -> Normal {
+        self.range.step(normal, steps)
+    }
+
+    /// Returns the number of discrete values in the range, or `None` if
+    /// the range is continuous.
+    pub fn discrete_len(&self) -> Option<usize> {
+        self.range.discrete_len()
+    }
+
+    /// Returns `true` if the values of the range are quantized, so that a
+    /// widget steps between them.
+    pub fn is_quantized(&self) -> bool {
+        self.range.is_quantized()
+    }
+
+    /// Returns the exact value of the supplied [`Normal`] as text, such as
+    /// `"1250.5"` rather than the display text `"1.25 kHz"`, or the label
+    /// of a [`ChoiceRange`].
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    /// [`ChoiceRange`]: ../range/struct.ChoiceRange.html
+    pub fn value_text(&self, normal: Normal) -> String {
+        self.range.value_text(normal)
+    }
+
+    pub(crate) fn parse_value_text(
+        &self,
+        text: &str,
+    ) -> Result<Normal, ValueTextError> {
+        self.range.parse_value_text(text)
+    }
+
+    /// Returns the original range, or `None` if it is not of type `R`.
+    pub fn downcast_ref<R: Range + 'static>(&self) -> Option<&R> {
+        self.range.as_any().downcast_ref()
+    }
+}
+
+/// The description of a single parameter in a [`ParamRegistry`]
+///
+/// The `key` identifies the parameter in presets and automation, so it
+/// should never change once a plugin is released, even if the parameter
+/// is renamed or moved.
+///
+/// [`ParamRegistry`]: struct.ParamRegistry.html
+#[derive(Debug)]
+pub struct ParamInfo {
+    key: &'static str,
+    name: String,
+    group: Option<String>,
+    unit: &'static str,
+    range: ParamRange,
+    default: Normal,
+    automatable: bool,
+    hidden: bool,
+}
+
+impl ParamInfo {
+    /// Creates a new `ParamInfo`
+    ///
+    /// The parameter is automatable and visible, and its default is the
+    /// default value of the range.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - the stable identifier of the parameter, such as
+    ///   `"filter_cutoff"`
+    /// * `name` - the name displayed to the user, such as `"Cutoff"`
+    /// * `range` - the [`Range`] of values of the parameter
+    ///
+    /// [`Range`]: ../range/trait.Range.html
+    pub fn new<R>(key: &'static str, name: &str, range: R) -> Self
+    where
+        R: Range + Debug + 'static,
+        R::Value: FromStr,
+    {
+        let range = ParamRange::new(range);
+
+        Self {
+            key,
+            name: String::from(name),
+            group: None,
+            unit: "",
+            default: range.range.default_normal(),
+            range,
+            automatable: true,
+            hidden: false,
+        }
+    }
+
+    /// Sets the name of the group the parameter is displayed in, such as
+    /// `"Filter"`
+    pub fn group(mut self, group: &str) -> Self {
+        self.group = Some(String::from(group));
+        self
+    }
+
+    /// Sets the unit reported to the host, such as `"Hz"`
+    pub fn unit(mut self, unit: &'static str) -> Self {
+        self.unit = unit;
+        self
+    }
+
+    /// Sets the default value of the parameter
+    pub fn default(mut self, default: Normal) -> Self {
+        self.default = default;
+        self
+    }
+
+    /// Sets whether the host may automate the parameter. The default is
+    /// `true`.
+    pub fn automatable(mut self, automatable: bool) -> Self {
+        self.automatable = automatable;
+        self
+    }
+
+    /// Sets whether the parameter is hidden from generated interfaces and
+    /// the host. The default is `false`.
+    pub fn hidden(mut self, hidden: bool) -> Self {
+        self.hidden = hidden;
+        self
+    }
+
+    /// Returns the stable identifier of the parameter
+    pub fn key(&self) -> &'static str {
+        self.key
+    }
+
+    /// Returns the name displayed to the user
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the name of the group the parameter is displayed in, if any
+    pub fn group_name(&self) -> Option<&str> {
+        self.group.as_deref()
+    }
+
+    /// Returns the unit reported to the host
+    pub fn unit_name(&self) -> &'static str {
+        self.unit
+    }
+
+    /// Returns the [`ParamRange`] of values of the parameter
+    ///
+    /// [`ParamRange`]: struct.ParamRange.html
+    pub fn range(&self) -> &ParamRange {
+        &self.range
+    }
+
+    /// Returns the default value of the parameter
+    pub fn default_normal(&self) -> Normal {
+        self.default
+    }
+
+    /// Returns whether the host may automate the parameter
+    pub fn is_automatable(&self) -> bool {
+        self.automatable
+    }
+
+    /// Returns whether the parameter is hidden
+    pub fn is_hidden(&self) -> bool {
+        self.hidden
+    }
+}
+
+/// A registry that describes every parameter of an application once.
+///
+/// Each parameter gets an `id`, which is its index in the registry and in
+/// a [`ParamStore`] created from it, and a `key`, which is stable across
+/// versions and is used to look up the `id`.
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::{FloatRange, FreqRange, Normal, ParamInfo, ParamRegistry};
+///
+/// let mut registry = ParamRegistry::new();
+///
+/// let cutoff = registry.push(
+///     ParamInfo::new("cutoff", "Cutoff", FreqRange::default())
+///         .group("Filter")
+///         .unit("Hz"),
+/// );
+/// let gain = registry.push(
+///     ParamInfo::new("gain", "Gain", FloatRange::new(0.0, 2.0))
+///         .default(Normal::new(0.5)),
+/// );
+///
+/// assert_eq!(registry.id_of("gain"), Some(gain));
+/// assert_eq!(registry.get(gain).range().format(Normal::new(0.5)), "1.00");
+///
+/// let normal_param = registry.normal_param(gain);
+/// assert_eq!(normal_param.default, Normal::new(0.5));
+///
+/// let filter: Vec<usize> = registry.in_group("Filter").collect();
+/// assert_eq!(filter, vec![cutoff]);
+/// ```
+///
+/// [`ParamStore`]: ../param_store/struct.ParamStore.html
+#[derive(Debug, Default)]
+pub struct ParamRegistry {
+    params: Vec<ParamInfo>,
+}
+
+impl ParamRegistry {
+    /// Creates a new empty `ParamRegistry`
+    pub fn new() -> Self {
+        Self { params: Vec::new() }
+    }
+
+    /// Adds a parameter and returns its `id`
+    ///
+    /// # Panics
+    ///
+    /// This will panic if a parameter with the same key was already added,
+    /// or if the key is not a valid [`Preset`] key
+    ///
+    /// [`Preset`]: ../preset/struct.Preset.html
+    pub fn push(&mut self, info: ParamInfo) -> usize {
+        assert!(
+            Preset::is_valid_key(info.key),
+            "\"{}\" is not a valid preset key",
+            info.key
+        );
+        assert!(
+            self.id_of(info.key).is_none(),
+            "a parameter with the key \"{}\" was already added",
+            info.key
+        );
+
+        self.params.push(info);
+        self.params.len() - 1
+    }
+
+    /// Returns the number of parameters in the registry
+    pub fn len(&self) -> usize {
+        self.params.len()
+    }
+
+    /// Returns `true` if the registry has no parameters
+    pub fn is_empty(&self) -> bool {
+        self.params.is_empty()
+    }
+
+    /// Returns the `id` of the parameter with the supplied key, or `None`
+    /// if there is no such parameter.
+    pub fn id_of(&self, key: &str) -> Option<usize> {
+        self.params.iter().position(|info| info.key == key)
+    }
+
+    /// Returns the description of a parameter
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `id` is out of bounds
+    pub fn get(&self, id: usize) -> &ParamInfo {
+        &self.params[id]
+    }
+
+    /// Returns an iterator over the `(id, info)` of every parameter
+    pub fn iter(&self) -> impl Iterator<Item = (usize, &ParamInfo)> {
+        self.params.iter().enumerate()
+    }
+
+    /// Returns the names of the groups of the parameters, in the order
+    /// they first appear
+    pub fn groups(&self) -> Vec<&str> {
+        let mut groups: Vec<&str> = Vec::new();
+        for info in self.params.iter() {
+            if let Some(group) = info.group_name() {
+                if !groups.contains(&group) {
+                    groups.push(group);
+                }
+            }
+        }
+        groups
+    }
+
+    /// Returns an iterator over the `id` of every parameter in a group
+    pub fn in_group<'a>(
+        &'a self,
+        group: &'a str,
+    ) -> impl Iterator<Item = usize> + 'a {
+        self.iter()
+            .filter(move |(_, info)| info.group_name() == Some(group))
+            .map(|(id, _)| id)
+    }
+
+    /// Returns a [`NormalParam`] for a parameter set to its default value
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `id` is out of bounds
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    pub fn normal_param(&self, id: usize) -> NormalParam {
+        NormalParam {
+            value: self.params[id].default,
+            default: self.params[id].default,
+        }
+    }
+
+    /// Returns a widget `State` for a parameter set to its default value,
+    /// such as an [`h_slider::State`].
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `id` is out of bounds
+    ///
+    /// [`h_slider::State`]: ../../native/h_slider/struct.State.html
+    pub fn state<S: From<NormalParam>>(&self, id: usize) -> S {
+        S::from(self.normal_param(id))
+    }
+
+    /// Returns the default value of every parameter, indexed by its `id`
+    pub fn defaults(&self) -> Vec<Normal> {
+        self.params.iter().map(|info| info.default).collect()
+    }
+
+    /// Creates a [`ParamStore`] with every parameter at its default value
+    ///
+    /// [`ParamStore`]: ../param_store/struct.ParamStore.html
+    pub fn param_store(&self) -> ParamStore {
+        ParamStore::new(&self.defaults())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{ChoiceRange, FloatRange, IntRange};
+
+    fn registry() -> ParamRegistry {
+        let mut registry = ParamRegistry::new();
+        let _ = registry.push(
+            ParamInfo::new("gain", "Gain", FloatRange::new(0.0, 2.0))
+                .group("Output"),
+        );
+        let _ = registry.push(
+            ParamInfo::new("voices", "Voices", IntRange::new(1, 8))
+                .group("Voice"),
+        );
+        let _ = registry.push(
+            ParamInfo::new("pan", "Pan", FloatRange::new(-1.0, 1.0))
+                .group("Output"),
+        );
+        registry
+    }
+
+    #[test]
+    fn groups_keep_the_order_of_their_params() {
+        let registry = registry();
+
+        assert_eq!(registry.groups(), vec!["Output", "Voice"]);
+        assert_eq!(registry.in_group("Output").collect::<Vec<_>>(), [0, 2]);
+        assert_eq!(registry.id_of("pan"), Some(2));
+        assert_eq!(registry.id_of("cutoff"), None);
+    }
+
+    #[test]
+    fn value_text_round_trips() {
+        let registry = registry();
+        let range = registry.get(1).range();
+
+        let text = range.value_text(Normal::max());
+
+        assert_eq!(text, "8");
+        assert_eq!(range.parse_value_text(&text), Ok(Normal::max()));
+    }
+
+    #[test]
+    fn choice_value_text_is_the_label() {
+        let range = ParamRange::new(ChoiceRange::new(&["Low", "Band", "3"]));
+
+        let text = range.value_text(Normal::new(0.5));
+
+        assert_eq!(text, "Band");
+        assert_eq!(range.parse_value_text(&text), Ok(Normal::new(0.5)));
+        assert_eq!(range.value_text(Normal::max()), "3");
+        assert_eq!(range.parse_value_text("3"), Ok(Normal::max()));
+
+        // An index is accepted if it is not also a label.
+        assert_eq!(range.parse_value_text("0"), Ok(Normal::min()));
+        assert_eq!(
+            range.parse_value_text("High"),
+            Err(ValueTextError::Invalid)
+        );
+    }
+
+    #[test]
+    fn invalid_value_text_is_rejected() {
+        let registry = registry();
+        let range = registry.get(0).range();
+
+        assert_eq!(range.parse_value_text(" 1 "), Ok(Normal::new(0.5)));
+        assert_eq!(
+            range.parse_value_text("loud"),
+            Err(ValueTextError::Invalid)
+        );
+        assert_eq!(range.parse_value_text("NaN"), Err(ValueTextError::Invalid));
+        assert_eq!(
+            range.parse_value_text("2.5"),
+            Err(ValueTextError::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn range_downcasts_to_its_type() {
+        let registry = registry();
+
+        assert!(registry.get(1).range().downcast_ref::<IntRange>().is_some());
+        assert!(registry
+            .get(1)
+            .range()
+            .downcast_ref::<FloatRange>()
+            .is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "was already added")]
+    fn duplicate_key_panics() {
+        let _ = registry().push(ParamInfo::new(
+            "gain",
+            "Gain",
+            FloatRange::new(0.0, 1.0),
+        ));
+    }
+
+    #[test]
+    #[should_panic(expected = "is not a valid preset key")]
+    fn invalid_key_panics() {
+        let _ = registry().push(ParamInfo::new(
+            "gain = 1",
+            "Gain",
+            FloatRange::new(0.0, 1.0),
+        ));
+    }
+}
@@ -0,0 +1,335 @@
+//! A lock-free store of parameter values shared between the GUI and a
+//! realtime audio thread
+
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+
+use super::{Normal, NormalParam};
+
+#[derive(Debug)]
+struct AtomicParam {
+    value: AtomicU32,
+    default: Normal,
+    changed_for_audio: AtomicBool,
+    changed_for_gui: AtomicBool,
+}
+
+/// Which side of a [`ParamStore`] a [`Changes`] iterator reports changes to
+///
+/// [`ParamStore`]: struct.ParamStore.html
+/// [`Changes`]: struct.Changes.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum Side {
+    Audio,
+    Gui,
+}
+
+/// A store of [`Normal`] parameter values indexed by parameter id, that can
+/// be shared between the GUI and a realtime audio thread with an `Arc`.
+///
+/// All operations are lock-free, and reads are wait-free. Each parameter
+/// has two change flags:
+///
+/// * [`set`] is used by the GUI when a widget is moved. The change is
+///   reported to the audio thread by [`audio_changes`].
+/// * [`set_from_host`] is used when the host, automation, or the audio
+///   thread changes a value. The change is reported to the GUI by
+///   [`gui_changes`], so it can update the widget with `State::set`.
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::{Normal, ParamStore};
+/// use std::sync::Arc;
+///
+/// let store = Arc::new(ParamStore::new(&[Normal::new(0.5), Normal::min()]));
+///
+/// // The GUI moves parameter 1.
+/// store.set(1, Normal::new(0.25));
+///
+/// // The audio thread picks up the change.
+/// let audio_store = Arc::clone(&store);
+/// std::thread::spawn(move || {
+///     let changes: Vec<(usize, Normal)> =
+///         audio_store.audio_changes().collect();
+///     assert_eq!(changes, vec![(1, Normal::new(0.25))]);
+///
+///     // Host automation moves parameter 0.
+///     audio_store.set_from_host(0, Normal::max());
+/// })
+/// .join()
+/// .unwrap();
+///
+/// // The GUI picks up the automation.
+/// for (id, normal) in store.gui_changes() {
+///     assert_eq!((id, normal), (0, Normal::max()));
+/// }
+/// ```
+///
+/// [`Normal`]: ../struct.Normal.html
+/// [`set`]: #method.set
+/// [`set_from_host`]: #method.set_from_host
+/// [`audio_changes`]: #method.audio_changes
+/// [`gui_changes`]: #method.gui_changes
+#[derive(Debug)]
+pub struct ParamStore {
+    params: Box<[AtomicParam]>,
+}
+
+impl ParamStore {
+    /// Creates a new `ParamStore`
+    ///
+    /// * `defaults` - the default value of each parameter, indexed by its
+    ///   id. Each parameter starts at its default value.
+    pub fn new(defaults: &[Normal]) -> Self {
+        let params = defaults
+            .iter()
+            .map(|default| AtomicParam {
+                value: AtomicU32::new(default.as_f32().to_bits()),
+                default: *default,
+                changed_for_audio: AtomicBool::new(false),
+                changed_for_gui: AtomicBool::new(false),
+            })
+            .collect();
+
+        Self { params }
+    }
+
+    /// Returns the number of parameters in the store
+    pub fn len(&self) -> usize {
+        self.params.len()
+    }
+
+    /// Returns `true` if the store has no parameters
+    pub fn is_empty(&self) -> bool {
+        self.params.is_empty()
+    }
+
+    /// Returns the current value of a parameter. This is wait-free.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `id` is out of bounds
+    pub fn get(&self, id: usize) -> Normal {
+        Normal::new(f32::from_bits(
+            self.params[id].value.load(Ordering::Relaxed),
+        ))
+    }
+
+    /// Returns the default value of a parameter
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `id` is out of bounds
+    pub fn default(&self, id: usize) -> Normal {
+        self.params[id].default
+    }
+
+    /// Returns the current and default value of a parameter as a
+    /// [`NormalParam`], such as for creating the `State` of a widget.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `id` is out of bounds
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    pub fn normal_param(&self, id: usize) -> NormalParam {
+        NormalParam {
+            value: self.get(id),
+            default: self.default(id),
+        }
+    }
+
+    /// Sets the value of a parameter from the GUI, such as when a widget
+    /// is moved. The change will be reported by [`audio_changes`].
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `id` is out of bounds
+    ///
+    /// [`audio_changes`]: #method.audio_changes
+    pub fn set(&self, id: usize, normal: Normal) {
+        let param = &self.params[id];
+        param
+            .value
+            .store(normal.as_f32().to_bits(), Ordering::Relaxed);
+        param.changed_for_audio.store(true, Ordering::Release);
+    }
+
+    /// Sets the value of a parameter from the host, automation, or the
+    /// audio thread. The change will be reported by [`gui_changes`].
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `id` is out of bounds
+    ///
+    /// [`gui_changes`]: #method.gui_changes
+    pub fn set_from_host(&self, id: usize, normal: Normal) {
+        let param = &self.params[id];
+        param
+            .value
+            .store(normal.as_f32().to_bits(), Ordering::Relaxed);
+        param.changed_for_gui.store(true, Ordering::Release);
+    }
+
+    /// Returns the new value of a parameter if it was changed by [`set`]
+    /// since the last time it was reported to the audio thread, clearing
+    /// its change flag. This is wait-free.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `id` is out of bounds
+    ///
+    /// [`set`]: #method.set
+    pub fn take_audio_change(&self, id: usize) -> Option<Normal> {
+        if self.params[id]
+            .changed_for_audio
+            .swap(false, Ordering::Acquire)
+        {
+            Some(self.get(id))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the new value of a parameter if it was changed by
+    /// [`set_from_host`] since the last time it was reported to the GUI,
+    /// clearing its change flag.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `id` is out of bounds
+    ///
+    /// [`set_from_host`]: #method.set_from_host
+    pub fn take_gui_change(&self, id: usize) -> Option<Normal> {
+        if self.params[id]
+            .changed_for_gui
+            .swap(false, Ordering::Acquire)
+        {
+            Some(self.get(id))
+        } else {
+            None
+        }
+    }
+
+    /// Returns an iterator over the `(id, value)` of every parameter changed
+    /// by [`set`] since the last time it was reported to the audio thread.
+    ///
+    /// The iterator is wait-free and does not allocate, so it is safe to use
+    /// on a realtime thread.
+    ///
+    /// [`set`]: #method.set
+    pub fn audio_changes(&self) -> Changes<'_> {
+        Changes {
+            store: self,
+            side: Side::Audio,
+            next_id: 0,
+        }
+    }
+
+    /// Returns an iterator over the `(id, value)` of every parameter changed
+    /// by [`set_from_host`] since the last time it was reported to the GUI.
+    ///
+    /// [`set_from_host`]: #method.set_from_host
+    pub fn gui_changes(&self) -> Changes<'_> {
+        Changes {
+            store: self,
+            side: Side::Gui,
+            next_id: 0,
+        }
+    }
+}
+
+/// An iterator over the changed parameters of a [`ParamStore`]
+///
+/// Each change flag is cleared as it is iterated over.
+///
+/// [`ParamStore`]: struct.ParamStore.html
+#[derive(Debug)]
+pub struct Changes<'a> {
+    store: &'a ParamStore,
+    side: Side,
+    next_id: usize,
+}
+
+impl<'a> Iterator for Changes<'a> {
+    type Item = (usize, Normal);
+
+    fn next(&mut self) -> Option<(usize, Normal)> {
+        while self.next_id < self.store.len() {
+            let id = self.next_id;
+            self.next_id += 1;
+
+            let change = match self.side {
+                Side::Audio => self.store.take_audio_change(id),
+                Side::Gui => self.store.take_gui_change(id),
+            };
+
+            if let Some(normal) = change {
+                return Some((id, normal));
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn store() -> ParamStore {
+        ParamStore::new(&[Normal::new(0.5), Normal::min(), Normal::max()])
+    }
+
+    #[test]
+    fn params_start_at_their_defaults() {
+        let store = store();
+
+        assert_eq!(store.len(), 3);
+        assert_eq!(store.get(0), Normal::new(0.5));
+        assert_eq!(
+            store.normal_param(2),
+            NormalParam {
+                value: Normal::max(),
+                default: Normal::max(),
+            }
+        );
+        assert_eq!(store.audio_changes().count(), 0);
+        assert_eq!(store.gui_changes().count(), 0);
+    }
+
+    #[test]
+    fn gui_change_is_reported_to_audio_once() {
+        let store = store();
+        store.set(1, Normal::new(0.25));
+        store.set(1, Normal::new(0.75));
+
+        assert_eq!(store.gui_changes().count(), 0);
+        assert_eq!(
+            store.audio_changes().collect::<Vec<_>>(),
+            vec![(1, Normal::new(0.75))]
+        );
+        assert_eq!(store.take_audio_change(1), None);
+        assert_eq!(store.default(1), Normal::min());
+    }
+
+    #[test]
+    fn host_change_is_reported_to_gui_once() {
+        let store = store();
+        store.set_from_host(2, Normal::min());
+        store.set_from_host(0, Normal::max());
+
+        assert_eq!(store.audio_changes().count(), 0);
+        assert_eq!(
+            store.gui_changes().collect::<Vec<_>>(),
+            vec![(0, Normal::max()), (2, Normal::min())]
+        );
+        assert_eq!(store.take_gui_change(0), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn out_of_bounds_id_panics() {
+        let _ = store().get(3);
+    }
+}
@@ -0,0 +1,789 @@
+//! Presets that save and recall the values of a set of parameters as
+//! human-readable text
+
+use std::fmt;
+use std::str::FromStr;
+
+use super::param_registry::ValueTextError;
+use super::{Normal, ParamRegistry};
+
+/// An error that occurred while parsing or restoring a [`Preset`]
+///
+/// [`Preset`]: struct.Preset.html
+#[derive(Debug, Clone, PartialEq)]
+pub enum PresetError {
+    /// A line of the text could not be parsed
+    Syntax {
+        /// The line number, starting at `1`
+        line: usize,
+        /// What was wrong with the line
+        message: &'static str,
+    },
+    /// The preset was saved with a newer version than the
+    /// [`PresetMigrations`] support
+    ///
+    /// [`PresetMigrations`]: struct.PresetMigrations.html
+    UnsupportedVersion {
+        /// The version of the preset
+        version: u32,
+        /// The latest supported version
+        latest: u32,
+    },
+    /// The preset has a parameter that is not in the [`ParamRegistry`]
+    ///
+    /// [`ParamRegistry`]: ../param_registry/struct.ParamRegistry.html
+    UnknownParam(String),
+    /// The value of a parameter could not be parsed
+    InvalidValue {
+        /// The key of the parameter
+        key: String,
+        /// The text of the value
+        value: String,
+    },
+    /// The value of a parameter is outside of its range
+    OutOfRange {
+        /// The key of the parameter
+        key: String,
+        /// The text of the value
+        value: String,
+    },
+}
+
+impl fmt::Display for PresetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PresetError::Syntax { line, message } => {
+                write!(f, "line {}: {}", line, message)
+            }
+            PresetError::UnsupportedVersion { version, latest } => write!(
+                f,
+                "preset version {} is newer than the latest version {}",
+                version, latest
+            ),
+            PresetError::UnknownParam(key) => {
+                write!(f, "unknown parameter \"{}\"", key)
+            }
+            PresetError::InvalidValue { key, value } => write!(
+                f,
+                "\"{}\" is not a valid value for parameter \"{}\"",
+                value, key
+            ),
+            PresetError::OutOfRange { key, value } => write!(
+                f,
+                "{} is out of the range of parameter \"{}\"",
+                value, key
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PresetError {}
+
+/// The values of a set of parameters, keyed by the stable key of each
+/// parameter in a [`ParamRegistry`].
+///
+/// Values are stored as the exact value of each parameter (through
+/// `unmap_to_value`), not as a [`Normal`], so presets still load correctly
+/// if the range of a parameter is changed. The choices of a
+/// [`ChoiceRange`] are stored as their labels, so presets also survive a
+/// reordering of the choices.
+///
+/// A preset is written as text with `to_string()`, and read back with
+/// `parse()`:
+///
+/// ```text
+/// [preset]
+/// name = Warm Pad
+/// version = 2
+///
+/// [params]
+/// cutoff = 1250.5
+/// gain = 1
+/// ```
+///
+/// Blank lines and lines starting with `#` are ignored.
+///
+/// The name and the values are escaped so that each stays on one line and
+/// keeps any whitespace at either end. A backslash is written as `\\`, a
+/// line break as `\n` or `\r`, a tab as `\t`, and any other control
+/// character or whitespace at either end as `\u{..}` with its hexadecimal
+/// code point. Keys are not escaped, so they are limited to the keys
+/// accepted by [`is_valid_key`].
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::{FloatRange, Normal, ParamInfo, ParamRegistry, Preset};
+///
+/// let mut registry = ParamRegistry::new();
+/// let gain = registry.push(ParamInfo::new(
+///     "gain",
+///     "Gain",
+///     FloatRange::new(0.0, 2.0),
+/// ));
+///
+/// let preset = Preset::snapshot("Loud", 1, &registry, |_| Normal::max());
+/// assert_eq!(preset.get("gain"), Some("2"));
+///
+/// let text = preset.to_string();
+/// let loaded: Preset = text.parse().unwrap();
+///
+/// let values = loaded.restore(&registry).unwrap();
+/// assert_eq!(values, vec![(gain, Normal::max())]);
+/// ```
+///
+/// [`ParamRegistry`]: ../param_registry/struct.ParamRegistry.html
+/// [`Normal`]: ../struct.Normal.html
+/// [`ChoiceRange`]: ../range/struct.ChoiceRange.html
+/// [`is_valid_key`]: #method.is_valid_key
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Preset {
+    name: String,
+    version: u32,
+    values: Vec<(String, String)>,
+}
+
+impl Preset {
+    /// Creates a new empty `Preset`
+    ///
+    /// * `name` - the name displayed to the user
+    /// * `version` - the version of the parameter layout, used by
+    ///   [`PresetMigrations`]
+    ///
+    /// [`PresetMigrations`]: struct.PresetMigrations.html
+    pub fn new(name: &str, version: u32) -> Self {
+        Self {
+            name: String::from(name),
+            version,
+            values: Vec::new(),
+        }
+    }
+
+    /// Creates a new `Preset` with the current value of every parameter in
+    /// a [`ParamRegistry`]
+    ///
+    /// * `name` - the name displayed to the user
+    /// * `version` - the version of the parameter layout
+    /// * `registry` - the [`ParamRegistry`] describing the parameters
+    /// * `value` - returns the current value of the parameter with the
+    ///   supplied `id`, such as with [`ParamStore::get`]
+    ///
+    /// [`ParamRegistry`]: ../param_registry/struct.ParamRegistry.html
+    /// [`ParamStore::get`]: ../param_store/struct.ParamStore.html#method.get
+    pub fn snapshot<F>(
+        name: &str,
+        version: u32,
+        registry: &ParamRegistry,
+        value: F,
+    ) -> Self
+    where
+        F: Fn(usize) -> Normal,
+    {
+        let mut preset = Preset::new(name, version);
+
+        for (id, info) in registry.iter() {
+            preset.set(info.key(), &info.range().value_text(value(id)));
+        }
+
+        preset
+    }
+
+    /// Returns the name displayed to the user
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the version of the parameter layout
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// Returns the number of parameters in the preset
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns `true` if the preset has no parameters
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Returns the value text of a parameter, or `None` if the preset
+    /// does not have the parameter.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.values
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Returns `true` if `key` can be saved in the text of a preset.
+    ///
+    /// A key must not be empty, start or end with whitespace, start with
+    /// `#` or `[`, or contain `=` or a line break.
+    pub fn is_valid_key(key: &str) -> bool {
+        !key.is_empty()
+            && key.trim() == key
+            && !key.starts_with('#')
+            && !key.starts_with('[')
+            && !key.contains(['=', '\n', '\r'])
+    }
+
+    /// Sets the value text of a parameter, adding the parameter if the
+    /// preset does not have it.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `key` is not a valid key, as described in
+    /// [`is_valid_key`]
+    ///
+    /// [`is_valid_key`]: #method.is_valid_key
+    pub fn set(&mut self, key: &str, value: &str) {
+        assert!(
+            Preset::is_valid_key(key),
+            "\"{}\" is not a valid preset key",
+            key
+        );
+
+        match self.values.iter_mut().find(|(k, _)| k == key) {
+            Some((_, v)) => *v = String::from(value),
+            None => self.values.push((String::from(key), String::from(value))),
+        }
+    }
+
+    /// Removes a parameter, and returns its value text if the preset had
+    /// the parameter.
+    pub fn remove(&mut self, key: &str) -> Option<String> {
+        let index = self.values.iter().position(|(k, _)| k == key)?;
+        Some(self.values.remove(index).1)
+    }
+
+    /// Renames the key of a parameter, such as when migrating a preset.
+    /// This does nothing if the preset does not have the parameter.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `new_key` is not a valid key, as described in
+    /// [`is_valid_key`]
+    ///
+    /// [`is_valid_key`]: #method.is_valid_key
+    pub fn rename(&mut self, key: &str, new_key: &str) {
+        if let Some(value) = self.remove(key) {
+            self.set(new_key, &value);
+        }
+    }
+
+    /// Returns an iterator over the `(key, value)` text of every parameter
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.values
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+    }
+
+    /// Returns the `(id, value)` of every parameter in the preset, to be
+    /// set on a [`ParamStore`] or on widget states.
+    ///
+    /// Parameters that are not in the preset are not returned, so that an
+    /// older preset or a partial preset only restores the parameters it
+    /// has.
+    ///
+    /// Nothing is returned if any parameter is not in the registry, or if
+    /// any value is invalid or out of range. Use [`PresetMigrations`] to
+    /// update presets when parameters are added, removed or renamed.
+    ///
+    /// [`ParamStore`]: ../param_store/struct.ParamStore.html
+    /// [`PresetMigrations`]: struct.PresetMigrations.html
+    pub fn restore(
+        &self,
+        registry: &ParamRegistry,
+    ) -> Result<Vec<(usize, Normal)>, PresetError> {
+        self.values
+            .iter()
+            .map(|(key, value)| {
+                let id = registry
+                    .id_of(key)
+                    .ok_or_else(|| PresetError::UnknownParam(key.clone()))?;
+
+                let normal = registry
+                    .get(id)
+                    .range()
+                    .parse_value_text(value)
+                    .map_err(|error| match error {
+                    ValueTextError::Invalid => PresetError::InvalidValue {
+                        key: key.clone(),
+                        value: value.clone(),
+                    },
+                    ValueTextError::OutOfRange => PresetError::OutOfRange {
+                        key: key.clone(),
+                        value: value.clone(),
+                    },
+                })?;
+
+                Ok((id, normal))
+            })
+            .collect()
+    }
+}
+
+impl fmt::Display for Preset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "[preset]")?;
+        writeln!(f, "name = {}", escape(&self.name))?;
+        writeln!(f, "version = {}", self.version)?;
+        writeln!(f)?;
+        writeln!(f, "[params]")?;
+
+        for (key, value) in self.values.iter() {
+            writeln!(f, "{} = {}", key, escape(value))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Escapes text so that it stays on one line, and keeps any whitespace at
+/// either end when the line is trimmed
+fn escape(text: &str) -> String {
+    let last = text.chars().count().saturating_sub(1);
+    let mut escaped = String::with_capacity(text.len());
+
+    for (i, c) in text.chars().enumerate() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control()
+                || (c.is_whitespace() && (i == 0 || i == last)) =>
+            {
+                escaped.push_str(&format!("\\u{{{:x}}}", u32::from(c)))
+            }
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+/// Reverses [`escape`], or returns `None` if the text has an invalid
+/// escape sequence
+///
+/// [`escape`]: fn.escape.html
+fn unescape(text: &str) -> Option<String> {
+    let mut chars = text.chars();
+    let mut unescaped = String::with_capacity(text.len());
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+
+        let c = match chars.next()? {
+            '\\' => '\\',
+            'n' => '\n',
+            'r' => '\r',
+            't' => '\t',
+            'u' => {
+                if chars.next()? != '{' {
+                    return None;
+                }
+
+                let mut code = 0u32;
+                loop {
+                    match chars.next()? {
+                        '}' => break,
+                        digit => {
+                            code = code
+                                .checked_mul(16)?
+                                .checked_add(digit.to_digit(16)?)?
+                        }
+                    }
+                }
+
+                std::char::from_u32(code)?
+            }
+            _ => return None,
+        };
+
+        unescaped.push(c);
+    }
+
+    Some(unescaped)
+}
+
+/// The section of the preset text being parsed
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum Section {
+    None,
+    Preset,
+    Params,
+}
+
+impl FromStr for Preset {
+    type Err = PresetError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut preset = Preset::default();
+        let mut section = Section::None;
+
+        for (i, line) in text.lines().enumerate() {
+            let line = line.trim();
+            let syntax = |message| PresetError::Syntax {
+                line: i + 1,
+                message,
+            };
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if line.starts_with('[') {
+                section = match line {
+                    "[preset]" => Section::Preset,
+                    "[params]" => Section::Params,
+                    _ => return Err(syntax("unknown section")),
+                };
+                continue;
+            }
+
+            let mut parts = line.splitn(2, '=');
+            let key = parts.next().unwrap_or("").trim();
+            let value = parts
+                .next()
+                .ok_or_else(|| syntax("expected `key = value`"))?
+                .trim();
+
+            if key.is_empty() {
+                return Err(syntax("expected a key before `=`"));
+            }
+
+            let value = unescape(value)
+                .ok_or_else(|| syntax("invalid escape sequence"))?;
+
+            match section {
+                Section::None => {
+                    return Err(syntax("expected a section such as [params]"))
+                }
+                Section::Preset => match key {
+                    "name" => preset.name = value,
+                    "version" => {
+                        preset.version = value
+                            .parse()
+                            .map_err(|_| syntax("expected a version number"))?
+                    }
+                    _ => return Err(syntax("unknown preset property")),
+                },
+                Section::Params => {
+                    if preset.get(key).is_some() {
+                        return Err(syntax("duplicate parameter"));
+                    }
+                    preset.set(key, &value);
+                }
+            }
+        }
+
+        Ok(preset)
+    }
+}
+
+type MigrationStep = Box<dyn Fn(&mut Preset)>;
+
+/// Updates presets saved with an older version of a parameter layout, such
+/// as when parameters are added, removed or renamed.
+///
+/// Each step updates a preset from one version to the next. Versions
+/// without a step are updated without changes.
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::{Preset, PresetMigrations};
+///
+/// let migrations = PresetMigrations::new(2)
+///     // Version 1 renamed "cutoff" to "filter_cutoff".
+///     .step(0, |preset| preset.rename("cutoff", "filter_cutoff"))
+///     // Version 2 removed "legacy_mode".
+///     .step(1, |preset| {
+///         let _ = preset.remove("legacy_mode");
+///     });
+///
+/// let mut preset = Preset::new("Old", 0);
+/// preset.set("cutoff", "1000");
+/// preset.set("legacy_mode", "1");
+///
+/// migrations.migrate(&mut preset).unwrap();
+///
+/// assert_eq!(preset.version(), 2);
+/// assert_eq!(preset.get("filter_cutoff"), Some("1000"));
+/// assert_eq!(preset.get("legacy_mode"), None);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct PresetMigrations {
+    latest: u32,
+    steps: Vec<(u32, MigrationStep)>,
+}
+
+impl PresetMigrations {
+    /// Creates a new `PresetMigrations` without any steps
+    ///
+    /// * `latest` - the current version of the parameter layout
+    pub fn new(latest: u32) -> Self {
+        Self {
+            latest,
+            steps: Vec::new(),
+        }
+    }
+
+    /// Adds a step that updates a preset from version `from` to version
+    /// `from + 1`
+    pub fn step<F>(mut self, from: u32, step: F) -> Self
+    where
+        F: 'static + Fn(&mut Preset),
+    {
+        self.steps.push((from, Box::new(step)));
+        self
+    }
+
+    /// Returns the current version of the parameter layout
+    pub fn latest(&self) -> u32 {
+        self.latest
+    }
+
+    /// Updates a preset to the latest version, running every step from
+    /// its version onwards in order.
+    ///
+    /// This returns an error if the preset has a newer version than the
+    /// latest version.
+    pub fn migrate(&self, preset: &mut Preset) -> Result<(), PresetError> {
+        if preset.version > self.latest {
+            return Err(PresetError::UnsupportedVersion {
+                version: preset.version,
+                latest: self.latest,
+            });
+        }
+
+        while preset.version < self.latest {
+            for (from, step) in self.steps.iter() {
+                if *from == preset.version {
+                    step(preset);
+                }
+            }
+            preset.version += 1;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{ChoiceRange, FloatRange, IntRange, ParamInfo};
+
+    fn registry() -> ParamRegistry {
+        let mut registry = ParamRegistry::new();
+        let _ = registry.push(ParamInfo::new(
+            "gain",
+            "Gain",
+            FloatRange::new(0.0, 2.0),
+        ));
+        let _ = registry.push(ParamInfo::new(
+            "voices",
+            "Voices",
+            IntRange::new(1, 8),
+        ));
+        registry
+    }
+
+    fn syntax_error(text: &str) -> (usize, &'static str) {
+        match text.parse::<Preset>() {
+            Err(PresetError::Syntax { line, message }) => (line, message),
+            other => panic!("expected a syntax error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn parse_ignores_comments_and_blank_lines() {
+        let preset: Preset = "# saved by hand\n\n[preset]\nname = Init\n\
+                              version = 3\n[params]\n  gain =  1.5  \n"
+            .parse()
+            .unwrap();
+
+        assert_eq!(preset.name(), "Init");
+        assert_eq!(preset.version(), 3);
+        assert_eq!(preset.iter().collect::<Vec<_>>(), [("gain", "1.5")]);
+    }
+
+    #[test]
+    fn parse_errors_report_their_line() {
+        assert_eq!(
+            syntax_error("gain = 1"),
+            (1, "expected a section such as [params]")
+        );
+        assert_eq!(syntax_error("[values]"), (1, "unknown section"));
+        assert_eq!(
+            syntax_error("[params]\ngain"),
+            (2, "expected `key = value`")
+        );
+        assert_eq!(
+            syntax_error("[params]\n= 1"),
+            (2, "expected a key before `=`")
+        );
+        assert_eq!(
+            syntax_error("[params]\ngain = 1\ngain = 2"),
+            (3, "duplicate parameter")
+        );
+        assert_eq!(
+            syntax_error("[preset]\nversion = two"),
+            (2, "expected a version number")
+        );
+        assert_eq!(
+            syntax_error("[preset]\nauthor = me"),
+            (2, "unknown preset property")
+        );
+    }
+
+    #[test]
+    fn restore_is_partial_and_checks_values() {
+        let registry = registry();
+        let mut preset = Preset::new("Init", 0);
+        preset.set("voices", "8");
+
+        assert_eq!(preset.restore(&registry), Ok(vec![(1, Normal::max())]));
+
+        preset.set("gain", "loud");
+        assert_eq!(
+            preset.restore(&registry),
+            Err(PresetError::InvalidValue {
+                key: String::from("gain"),
+                value: String::from("loud"),
+            })
+        );
+
+        preset.set("gain", "3");
+        assert_eq!(
+            preset.restore(&registry),
+            Err(PresetError::OutOfRange {
+                key: String::from("gain"),
+                value: String::from("3"),
+            })
+        );
+
+        let _ = preset.remove("gain");
+        preset.set("cutoff", "1000");
+        assert_eq!(
+            preset.restore(&registry),
+            Err(PresetError::UnknownParam(String::from("cutoff")))
+        );
+    }
+
+    #[test]
+    fn choice_is_stored_as_its_label() {
+        let mut registry = ParamRegistry::new();
+        let _ = registry.push(ParamInfo::new(
+            "mode",
+            "Mode",
+            ChoiceRange::new(&["Low", "Band", "High"]),
+        ));
+
+        let preset =
+            Preset::snapshot("Init", 0, &registry, |_| Normal::new(0.5));
+        assert_eq!(preset.get("mode"), Some("Band"));
+
+        let loaded: Preset = preset.to_string().parse().unwrap();
+        assert_eq!(loaded.restore(&registry), Ok(vec![(0, Normal::new(0.5))]));
+    }
+
+    #[test]
+    fn migrations_run_each_step_in_order() {
+        let migrations = PresetMigrations::new(3)
+            .step(2, |preset| preset.rename("level", "gain"))
+            .step(0, |preset| preset.set("level", "1"))
+            .step(1, |preset| {
+                let _ = preset.remove("legacy");
+            });
+
+        let mut preset = Preset::new("Old", 0);
+        preset.set("legacy", "1");
+        migrations.migrate(&mut preset).unwrap();
+
+        assert_eq!(preset.version(), 3);
+        assert_eq!(preset.iter().collect::<Vec<_>>(), [("gain", "1")]);
+        assert_eq!(
+            preset.restore(&registry()),
+            Ok(vec![(0, Normal::new(0.5))])
+        );
+    }
+
+    #[test]
+    fn migrations_start_at_the_preset_version() {
+        let migrations = PresetMigrations::new(2)
+            .step(0, |preset| preset.set("voices", "1"))
+            .step(1, |preset| preset.rename("level", "gain"));
+
+        let mut preset = Preset::new("Newer", 1);
+        preset.set("level", "2");
+        migrations.migrate(&mut preset).unwrap();
+
+        assert_eq!(preset.iter().collect::<Vec<_>>(), [("gain", "2")]);
+    }
+
+    #[test]
+    fn newer_preset_is_unsupported() {
+        let mut preset = Preset::new("Future", 4);
+
+        assert_eq!(
+            PresetMigrations::new(3).migrate(&mut preset),
+            Err(PresetError::UnsupportedVersion {
+                version: 4,
+                latest: 3,
+            })
+        );
+        assert_eq!(preset.version(), 4);
+    }
+
+    #[test]
+    fn name_and_values_are_escaped() {
+        let mut preset = Preset::new("Warm\nPad \\ 2 ", 1);
+        preset.set("mode", " Low\tBand\r");
+
+        let text = preset.to_string();
+        assert_eq!(text.lines().count(), 6);
+        assert!(text.contains("name = Warm\\nPad \\\\ 2\\u{20}\n"));
+
+        let loaded: Preset = text.parse().unwrap();
+        assert_eq!(loaded, preset);
+    }
+
+    #[test]
+    fn invalid_escape_is_a_syntax_error() {
+        for value in &["\\x", "\\", "\\u{110000}", "\\u20", "\\u{zz}"] {
+            let text = format!("[params]\ngain = {}\n", value);
+
+            assert_eq!(
+                text.parse::<Preset>(),
+                Err(PresetError::Syntax {
+                    line: 2,
+                    message: "invalid escape sequence",
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn keys_that_break_the_text_are_invalid() {
+        assert!(Preset::is_valid_key("filter_cutoff"));
+        assert!(Preset::is_valid_key("a#b[c"));
+
+        for key in &["", " gain", "gain ", "a=b", "a\nb", "#gain", "[gain]"] {
+            assert!(!Preset::is_valid_key(key), "{:?}", key);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "is not a valid preset key")]
+    fn set_rejects_invalid_key() {
+        Preset::new("Init", 0).set("gain = 1", "2");
+    }
+}
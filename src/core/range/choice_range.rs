@@ -0,0 +1,294 @@
+use super::Range;
+use crate::core::Normal;
+use crate::native::{text_marks, tick_marks};
+
+/// A range that defines a discrete list of named choices, such as a filter
+/// type or an oscillator waveform.
+///
+/// The value of a `ChoiceRange` is the index of the selected choice.
+/// [`unmap_to_value`] always rounds to the closest choice, so the selection
+/// can be read from any [`Normal`] a widget emits. Pass the range to the
+/// `snap` method of a widget, such as [`Knob::snap`], to make the widget
+/// "step" between choices.
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::{ChoiceRange, Normal, Range};
+///
+/// let range = ChoiceRange::new(&["Sine", "Triangle", "Saw", "Square"]);
+///
+/// let normal = Normal::new(0.6);
+/// assert_eq!(range.unmap_to_value(normal), 2);
+/// assert_eq!(range.label(normal), "Saw");
+/// assert_eq!(range.format(normal), "Saw");
+/// ```
+///
+/// [`Normal`]: ../struct.Normal.html
+/// [`unmap_to_value`]: #method.unmap_to_value
+/// [`Knob::snap`]: ../../native/knob/struct.Knob.html#method.snap
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(into = "ChoiceRangeDef", try_from = "ChoiceRangeDef")
+)]
+pub struct ChoiceRange {
+    labels: Vec<String>,
+    span: f32,
+    span_recip: f32,
+}
+
+impl ChoiceRange {
+    /// Creates a new `ChoiceRange`
+    ///
+    /// # Arguments
+    ///
+    /// * `labels` - the name of each choice, in order from the minimum
+    ///   to the maximum of the range
+    ///
+    /// # Panics
+    ///
+    /// This will panic if there are less than two labels
+    pub fn new(labels: &[&str]) -> Self {
+        Self::from_strings(labels.iter().map(|label| String::from(*label)))
+    }
+
+    /// Creates a new `ChoiceRange` from owned labels
+    ///
+    /// # Panics
+    ///
+    /// This will panic if there are less than two labels
+    pub fn from_strings<I>(labels: I) -> Self
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let labels: Vec<String> = labels.into_iter().collect();
+
+        assert!(labels.len() >= 2, "there must be at least two choices");
+
+        let span = (labels.len() - 1) as f32;
+
+        Self {
+            labels,
+            span,
+            span_recip: span.recip(),
+        }
+    }
+
+    /// Returns the labels of all the choices
+    pub fn labels(&self) -> &[String] {
+        &self.labels
+    }
+
+    /// Returns the label of the choice closest to the supplied [`Normal`]
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn label(&self, normal: Normal) -> &str {
+        &self.labels[self.unmap_to_value(normal)]
+    }
+
+    /// Returns the index of the choice with the given label, if any
+    pub fn index_of(&self, label: &str) -> Option<usize> {
+        self.labels.iter().position(|l| l == label)
+    }
+
+    /// Returns a [`tick_marks::Group`] with a tick mark at every choice
+    ///
+    /// * `tier` - the [`Tier`] of the tick marks
+    ///
+    /// [`tick_marks::Group`]: ../../native/tick_marks/struct.Group.html
+    /// [`Tier`]: ../../native/tick_marks/enum.Tier.html
+    pub fn tick_marks(&self, tier: tick_marks::Tier) -> tick_marks::Group {
+        tick_marks::Group::evenly_spaced(self.labels.len(), tier)
+    }
+
+    /// Returns a [`text_marks::Group`] with the label of every choice
+    /// at its position
+    ///
+    /// [`text_marks::Group`]: ../../native/text_marks/struct.Group.html
+    pub fn text_marks(&self) -> text_marks::Group {
+        self.labels
+            .iter()
+            .enumerate()
+            .map(|(i, label)| (self.map_to_normal(i), label.clone()))
+            .collect::<Vec<(Normal, String)>>()
+            .into()
+    }
+}
+
+impl Range for ChoiceRange {
+    type Value = usize;
+
+    fn min(&self) -> usize {
+        0
+    }
+
+    fn max(&self) -> usize {
+        self.labels.len() - 1
+    }
+
+    /// Returns the first choice
+    fn default_value(&self) -> usize {
+        0
+    }
+
+    fn discrete_len(&self) -> Option<usize> {
+        Some(self.labels.len())
+    }
+
+    fn map_to_normal(&self, value: usize) -> Normal {
+        let value = self.clamp(value);
+        (value as f32 * self.span_recip).into()
+    }
+
+    fn unmap_to_value(&self, normal: Normal) -> usize {
+        (normal.as_f32() * self.span).round() as usize
+    }
+
+    /// Returns the label of the choice
+    fn format_value(&self, value: usize) -> String {
+        self.labels[self.clamp(value)].clone()
+    }
+
+    /// Parses the label of a choice, ignoring case
+    fn parse_value(&self, text: &str) -> Option<usize> {
+        let text = text.trim();
+        self.labels
+            .iter()
+            .position(|label| label.eq_ignore_ascii_case(text))
+    }
+
+    /// Returns the label of the choice, so that presets still load after
+    /// the choices are reordered
+    fn preset_text(&self, value: usize) -> String {
+        self.format_value(value)
+    }
+
+    /// Parses the label of a choice. The index of a choice is also
+    /// accepted, as the value itself.
+    fn parse_preset_text(&self, text: &str) -> Option<usize> {
+        self.index_of(text)
+    }
+}
+
+/// The serialized form of a [`ChoiceRange`], which stores only the labels.
+/// The span between choices is recomputed from the number of labels.
+///
+/// [`ChoiceRange`]: struct.ChoiceRange.html
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename = "ChoiceRange")]
+struct ChoiceRangeDef {
+    labels: Vec<String>,
+}
+
+#[cfg(feature = "serde")]
+impl From<ChoiceRange> for ChoiceRangeDef {
+    fn from(range: ChoiceRange) -> Self {
+        Self {
+            labels: range.labels,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl std::convert::TryFrom<ChoiceRangeDef> for ChoiceRange {
+    type Error = &'static str;
+
+    fn try_from(def: ChoiceRangeDef) -> Result<Self, Self::Error> {
+        if def.labels.len() < 2 {
+            return Err("there must be at least two choices");
+        }
+
+        Ok(ChoiceRange::from_strings(def.labels))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn filter() -> ChoiceRange {
+        ChoiceRange::new(&["Low", "Band", "High", "Notch"])
+    }
+
+    #[test]
+    fn unmap_rounds_to_closest_choice() {
+        let range = filter();
+
+        assert_eq!(range.unmap_to_value(Normal::new(0.1)), 0);
+        assert_eq!(range.unmap_to_value(Normal::new(0.2)), 1);
+        assert_eq!(range.unmap_to_value(Normal::new(0.9)), 3);
+        assert_eq!(range.label(Normal::new(0.6)), "High");
+    }
+
+    #[test]
+    fn choices_round_trip() {
+        let range = filter();
+
+        for index in 0..4 {
+            assert_eq!(range.unmap_to_value(range.map_to_normal(index)), index);
+        }
+        assert_eq!(range.map_to_normal(9), Normal::max());
+    }
+
+    #[test]
+    fn snaps_and_steps_between_choices() {
+        let range = filter();
+
+        assert_eq!(range.discrete_len(), Some(4));
+        assert_eq!(range.snapped(Normal::new(0.4)), range.map_to_normal(1));
+        assert_eq!(
+            range.step(range.map_to_normal(1), 1),
+            range.map_to_normal(2)
+        );
+        assert_eq!(range.step(Normal::max(), 1), Normal::max());
+    }
+
+    #[test]
+    fn formats_and_parses_labels() {
+        let range = filter();
+
+        assert_eq!(range.format_value(2), "High");
+        assert_eq!(range.parse_value(" notch "), Some(3));
+        assert_eq!(range.parse_value("Peak"), None);
+        assert_eq!(range.index_of("Band"), Some(1));
+        assert_eq!(range.index_of("band"), None);
+    }
+
+    #[test]
+    fn marks_are_at_each_choice() {
+        let range = filter();
+        let text_marks = range.text_marks();
+
+        assert_eq!(text_marks.group.len(), 4);
+        assert_eq!(
+            text_marks.group[1],
+            (range.map_to_normal(1), String::from("Band"))
+        );
+        assert_eq!(range.tick_marks(tick_marks::Tier::One).len(), 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "there must be at least two choices")]
+    fn new_rejects_a_single_choice() {
+        let _ = ChoiceRange::new(&["Only"]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_stores_only_the_labels() {
+        let range = filter();
+        let json = serde_json::to_string(&range).unwrap();
+
+        assert_eq!(json, r#"{"labels":["Low","Band","High","Notch"]}"#);
+
+        let loaded: ChoiceRange = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded.labels(), range.labels());
+        assert_eq!(loaded.map_to_normal(2), range.map_to_normal(2));
+
+        let single = r#"{"labels":["Only"]}"#;
+        assert!(serde_json::from_str::<ChoiceRange>(single).is_err());
+    }
+}
@@ -0,0 +1,523 @@
+use super::Range;
+use crate::core::{Normal, ValueFormatter};
+
+/// The number of decibels per natural log unit of amplitude
+const DB_PER_LN: f64 = 20.0 / std::f64::consts::LN_10;
+
+/// How a [`CurveRange`] interpolates between its breakpoints
+///
+/// [`CurveRange`]: struct.CurveRange.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CurveInterpolation {
+    /// Straight lines between breakpoints
+    Linear,
+    /// A smooth curve through the breakpoints that never overshoots them
+    /// (Fritsch-Carlson monotone cubic interpolation)
+    MonotoneCubic,
+}
+
+/// A range that maps a [`Normal`] to `f32` values along a curve defined by
+/// a table of `(normal, value)` breakpoints.
+///
+/// This is useful for parameter tapers that match no closed formula, such
+/// as a measured potentiometer taper.
+///
+/// The values must be strictly increasing or strictly decreasing, so every
+/// value maps back to exactly one [`Normal`]. The first and last values may
+/// be infinite. A segment that ends at an infinite value treats its values
+/// as decibels, and is interpolated linearly in amplitude, so that a gain
+/// can fall to `-∞ dB` and a value such as a compressor ratio can rise to
+/// `∞:1`.
+///
+/// Values are displayed with 2 decimal places by default.
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::{CurveInterpolation, CurveRange, Normal, Range};
+///
+/// // A compressor ratio from 1:1 to ∞:1.
+/// let range = CurveRange::new(
+///     &[
+///         (Normal::new(0.0), 1.0),
+///         (Normal::new(0.25), 2.0),
+///         (Normal::new(0.5), 4.0),
+///         (Normal::new(0.75), 10.0),
+///         (Normal::new(1.0), std::f32::INFINITY),
+///     ],
+///     CurveInterpolation::MonotoneCubic,
+/// );
+///
+/// assert_eq!(range.unmap_to_value(Normal::new(0.5)), 4.0);
+/// assert_eq!(range.unmap_to_value(Normal::max()), std::f32::INFINITY);
+/// assert_eq!(range.map_to_normal(10.0), Normal::new(0.75));
+///
+/// let normal = range.map_to_normal(3.0);
+/// assert!((range.unmap_to_value(normal) - 3.0).abs() < 0.0001);
+/// ```
+///
+/// [`Normal`]: ../struct.Normal.html
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(into = "CurveRangeDef", try_from = "CurveRangeDef")
+)]
+pub struct CurveRange {
+    points: Vec<(Normal, f32)>,
+    tangents: Vec<f64>,
+    interpolation: CurveInterpolation,
+    increasing: bool,
+    formatter: ValueFormatter,
+}
+
+impl CurveRange {
+    /// Creates a new `CurveRange`
+    ///
+    /// # Arguments
+    ///
+    /// * `points` - the `(normal, value)` breakpoints of the curve, sorted
+    ///   by their normals
+    /// * `interpolation` - how to interpolate between the breakpoints
+    ///
+    /// # Panics
+    ///
+    /// This will panic if
+    /// * there are less than two breakpoints
+    /// * the first normal is not `0.0` or the last normal is not `1.0`
+    /// * the normals are not strictly increasing
+    /// * the values are not strictly increasing or strictly decreasing
+    /// * a value is `NaN`, or a value other than the first or last is
+    ///   infinite
+    /// * there are only two breakpoints and both values are infinite
+    pub fn new(
+        points: &[(Normal, f32)],
+        interpolation: CurveInterpolation,
+    ) -> Self {
+        let increasing = validate(points).unwrap_or_else(|e| panic!("{}", e));
+
+        let tangents = match interpolation {
+            CurveInterpolation::Linear => Vec::new(),
+            CurveInterpolation::MonotoneCubic => monotone_tangents(points),
+        };
+
+        Self {
+            points: points.to_vec(),
+            tangents,
+            interpolation,
+            increasing,
+            formatter: ValueFormatter::default(),
+        }
+    }
+
+    /// Sets the [`ValueFormatter`] used to display and parse values
+    ///
+    /// [`ValueFormatter`]: ../format/struct.ValueFormatter.html
+    pub fn formatter(mut self, formatter: ValueFormatter) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Returns the `(normal, value)` breakpoints of the curve
+    pub fn points(&self) -> &[(Normal, f32)] {
+        &self.points
+    }
+
+    /// Returns how the curve interpolates between its breakpoints
+    pub fn interpolation(&self) -> CurveInterpolation {
+        self.interpolation
+    }
+
+    fn segment_value(&self, i: usize, t: f64) -> f64 {
+        let (normal_1, value_1) = self.points[i];
+        let (normal_2, value_2) = self.points[i + 1];
+        let value_1 = f64::from(value_1);
+        let value_2 = f64::from(value_2);
+
+        // The amplitude is `0.0` at the infinite end of the segment and
+        // `1.0` at the finite end.
+        if value_1.is_infinite() {
+            return value_2 - (value_1.signum() * DB_PER_LN * t.ln());
+        }
+        if value_2.is_infinite() {
+            return value_1 - (value_2.signum() * DB_PER_LN * (1.0 - t).ln());
+        }
+
+        match self.interpolation {
+            CurveInterpolation::Linear => value_1 + (t * (value_2 - value_1)),
+            CurveInterpolation::MonotoneCubic => {
+                let h = f64::from(normal_2.as_f32() - normal_1.as_f32());
+                let t2 = t * t;
+                let t3 = t2 * t;
+
+                ((2.0 * t3) - (3.0 * t2) + 1.0) * value_1
+                    + (t3 - (2.0 * t2) + t) * h * self.tangents[i]
+                    + ((-2.0 * t3) + (3.0 * t2)) * value_2
+                    + (t3 - t2) * h * self.tangents[i + 1]
+            }
+        }
+    }
+
+    fn segment_t(&self, i: usize, value: f64) -> f64 {
+        let value_1 = f64::from(self.points[i].1);
+        let value_2 = f64::from(self.points[i + 1].1);
+
+        if value_1.is_infinite() {
+            return (-(value - value_2).abs() / DB_PER_LN).exp();
+        }
+        if value_2.is_infinite() {
+            return 1.0 - (-(value - value_1).abs() / DB_PER_LN).exp();
+        }
+
+        match self.interpolation {
+            CurveInterpolation::Linear => {
+                (value - value_1) / (value_2 - value_1)
+            }
+            CurveInterpolation::MonotoneCubic => {
+                // The segment is monotonic, so bisect it until it converges.
+                let mut low = 0.0;
+                let mut high = 1.0;
+                for _ in 0..64 {
+                    let mid = (low + high) * 0.5;
+                    let below = self.segment_value(i, mid) < value;
+                    if below == self.increasing {
+                        low = mid;
+                    } else {
+                        high = mid;
+                    }
+                }
+                (low + high) * 0.5
+            }
+        }
+    }
+}
+
+impl Range for CurveRange {
+    type Value = f32;
+
+    fn min(&self) -> f32 {
+        if self.increasing {
+            self.points[0].1
+        } else {
+            self.points[self.points.len() - 1].1
+        }
+    }
+
+    fn max(&self) -> f32 {
+        if self.increasing {
+            self.points[self.points.len() - 1].1
+        } else {
+            self.points[0].1
+        }
+    }
+
+    /// Returns the value of the first breakpoint
+    fn default_value(&self) -> f32 {
+        self.points[0].1
+    }
+
+    /// Returns the corresponding [`Normal`] from the supplied value
+    ///
+    /// This is the exact inverse of [`unmap_to_value`]. The value of each
+    /// breakpoint maps exactly to its normal.
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    /// [`unmap_to_value`]: #method.unmap_to_value
+    fn map_to_normal(&self, value: f32) -> Normal {
+        let value = self.clamp(value);
+
+        for (i, window) in self.points.windows(2).enumerate() {
+            let (normal_1, value_1) = window[0];
+            let (normal_2, value_2) = window[1];
+
+            if value == value_1 {
+                return normal_1;
+            }
+            if value == value_2 {
+                return normal_2;
+            }
+
+            let in_segment = if self.increasing {
+                value < value_2
+            } else {
+                value > value_2
+            };
+
+            if in_segment {
+                let t = self.segment_t(i, f64::from(value)) as f32;
+                return (normal_1.as_f32()
+                    + (t * (normal_2.as_f32() - normal_1.as_f32())))
+                .into();
+            }
+        }
+
+        self.points[self.points.len() - 1].0
+    }
+
+    fn unmap_to_value(&self, normal: Normal) -> f32 {
+        for (i, window) in self.points.windows(2).enumerate() {
+            let (normal_1, value_1) = window[0];
+            let (normal_2, value_2) = window[1];
+
+            if normal == normal_1 {
+                return value_1;
+            }
+            if normal == normal_2 {
+                return value_2;
+            }
+
+            if normal < normal_2 {
+                let t = (normal.as_f32() - normal_1.as_f32())
+                    / (normal_2.as_f32() - normal_1.as_f32());
+                return self.clamp(self.segment_value(i, f64::from(t)) as f32);
+            }
+        }
+
+        self.points[self.points.len() - 1].1
+    }
+
+    fn format_value(&self, value: f32) -> String {
+        self.formatter.format(value)
+    }
+
+    fn parse_value(&self, text: &str) -> Option<f32> {
+        self.formatter.parse(text)
+    }
+}
+
+/// Checks the breakpoints of a [`CurveRange`], and returns whether the
+/// values are increasing.
+///
+/// [`CurveRange`]: struct.CurveRange.html
+fn validate(points: &[(Normal, f32)]) -> Result<bool, &'static str> {
+    if points.len() < 2 {
+        return Err("there must be at least two breakpoints");
+    }
+    if points[0].0 != Normal::min() {
+        return Err("the first breakpoint must be at a normal of 0.0");
+    }
+    if points[points.len() - 1].0 != Normal::max() {
+        return Err("the last breakpoint must be at a normal of 1.0");
+    }
+
+    let increasing = points[1].1 > points[0].1;
+
+    for (i, window) in points.windows(2).enumerate() {
+        let (normal_1, value_1) = window[0];
+        let (normal_2, value_2) = window[1];
+
+        if normal_2 <= normal_1 {
+            return Err("breakpoint normals must be strictly increasing");
+        }
+        let monotonic = if increasing {
+            value_2 > value_1
+        } else {
+            value_2 < value_1
+        };
+        if !monotonic {
+            return Err(
+                "breakpoint values must be strictly increasing or decreasing",
+            );
+        }
+        if i > 0 && !value_1.is_finite() {
+            return Err("only the first and last values may be infinite");
+        }
+        if value_1.is_infinite() && value_2.is_infinite() {
+            return Err("a segment may not have two infinite values");
+        }
+    }
+
+    Ok(increasing)
+}
+
+/// Returns the tangent (slope) at each breakpoint for monotone cubic
+/// interpolation, using the Fritsch-Carlson method.
+fn monotone_tangents(points: &[(Normal, f32)]) -> Vec<f64> {
+    let secants: Vec<f64> = points
+        .windows(2)
+        .map(|window| {
+            let (normal_1, value_1) = window[0];
+            let (normal_2, value_2) = window[1];
+            (f64::from(value_2) - f64::from(value_1))
+                / f64::from(normal_2.as_f32() - normal_1.as_f32())
+        })
+        .collect();
+
+    let last = secants.len() - 1;
+    let mut tangents: Vec<f64> = (0..points.len())
+        .map(|i| {
+            if i == 0 {
+                secants[0]
+            } else if i > last {
+                secants[last]
+            } else if secants[i - 1].is_infinite() {
+                secants[i]
+            } else if secants[i].is_infinite() {
+                secants[i - 1]
+            } else {
+                (secants[i - 1] + secants[i]) * 0.5
+            }
+        })
+        .collect();
+
+    for (i, secant) in secants.iter().enumerate() {
+        if secant.is_infinite() {
+            continue;
+        }
+
+        let alpha = tangents[i] / secant;
+        let beta = tangents[i + 1] / secant;
+        let magnitude = (alpha * alpha) + (beta * beta);
+
+        if magnitude > 9.0 {
+            let tau = 3.0 / magnitude.sqrt();
+            tangents[i] = tau * alpha * secant;
+            tangents[i + 1] = tau * beta * secant;
+        }
+    }
+
+    tangents
+}
+
+/// The serialized form of a [`CurveRange`]. The points are checked as in
+/// [`CurveRange::new`], and the tangents and direction of the curve are
+/// recomputed from them.
+///
+/// [`CurveRange`]: struct.CurveRange.html
+/// [`CurveRange::new`]: struct.CurveRange.html#method.new
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename = "CurveRange")]
+struct CurveRangeDef {
+    points: Vec<(Normal, f32)>,
+    interpolation: CurveInterpolation,
+    formatter: ValueFormatter,
+}
+
+#[cfg(feature = "serde")]
+impl From<CurveRange> for CurveRangeDef {
+    fn from(range: CurveRange) -> Self {
+        Self {
+            points: range.points,
+            interpolation: range.interpolation,
+            formatter: range.formatter,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl std::convert::TryFrom<CurveRangeDef> for CurveRange {
+    type Error = &'static str;
+
+    fn try_from(def: CurveRangeDef) -> Result<Self, Self::Error> {
+        let _ = validate(&def.points)?;
+
+        Ok(CurveRange::new(&def.points, def.interpolation)
+            .formatter(def.formatter))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const INFINITY: f32 = f32::INFINITY;
+
+    fn range(points: &[(f32, f32)]) -> CurveRange {
+        let points: Vec<(Normal, f32)> = points
+            .iter()
+            .map(|(normal, value)| (Normal::new(*normal), *value))
+            .collect();
+
+        CurveRange::new(&points, CurveInterpolation::Linear)
+    }
+
+    fn assert_monotonic(range: &CurveRange) {
+        let values: Vec<f32> = (0..=100)
+            .map(|i| range.unmap_to_value(Normal::new(i as f32 / 100.0)))
+            .collect();
+
+        for window in values.windows(2) {
+            if range.increasing {
+                assert!(window[1] > window[0], "{:?}", window);
+            } else {
+                assert!(window[1] < window[0], "{:?}", window);
+            }
+        }
+    }
+
+    fn assert_round_trip(range: &CurveRange, value: f32) {
+        let normal = range.map_to_normal(value);
+        let unmapped = range.unmap_to_value(normal);
+
+        assert!(
+            (unmapped - value).abs() < 0.001,
+            "{} != {}",
+            unmapped,
+            value
+        );
+    }
+
+    #[test]
+    fn negative_infinity_to_zero() {
+        let range = range(&[(0.0, -INFINITY), (1.0, 0.0)]);
+
+        assert_eq!(range.unmap_to_value(Normal::min()), -INFINITY);
+        assert_eq!(range.unmap_to_value(Normal::max()), 0.0);
+        assert!(
+            (range.unmap_to_value(Normal::new(0.5)) + 6.0206).abs() < 0.001
+        );
+        assert_eq!(range.map_to_normal(-INFINITY), Normal::min());
+        assert_round_trip(&range, -12.0);
+        assert_round_trip(&range, -60.0);
+        assert_monotonic(&range);
+    }
+
+    #[test]
+    fn negative_infinity_to_positive() {
+        let range = range(&[(0.0, -INFINITY), (1.0, 6.0)]);
+
+        let half = range.unmap_to_value(Normal::new(0.5));
+        assert!((half + 0.0206).abs() < 0.001);
+        assert_round_trip(&range, 3.0);
+        assert_round_trip(&range, -24.0);
+        assert_monotonic(&range);
+    }
+
+    #[test]
+    fn finite_to_positive_infinity() {
+        let range = range(&[(0.0, -3.0), (0.5, 1.0), (1.0, INFINITY)]);
+
+        assert_eq!(range.unmap_to_value(Normal::max()), INFINITY);
+        assert_eq!(range.map_to_normal(INFINITY), Normal::max());
+        assert!(range.unmap_to_value(Normal::new(0.99)).is_finite());
+        assert_round_trip(&range, 20.0);
+        assert_monotonic(&range);
+    }
+
+    #[test]
+    fn decreasing_from_positive_infinity() {
+        let range = range(&[(0.0, INFINITY), (1.0, 0.0)]);
+
+        assert!(
+            (range.unmap_to_value(Normal::new(0.5)) - 6.0206).abs() < 0.001
+        );
+        assert_round_trip(&range, 40.0);
+        assert_monotonic(&range);
+    }
+
+    #[test]
+    fn decreasing_to_negative_infinity() {
+        let range = range(&[(0.0, 10.0), (1.0, -INFINITY)]);
+
+        assert_eq!(range.unmap_to_value(Normal::max()), -INFINITY);
+        assert_round_trip(&range, -5.0);
+        assert_monotonic(&range);
+    }
+
+    #[test]
+    #[should_panic(expected = "two infinite values")]
+    fn rejects_segment_between_infinities() {
+        let _ = range(&[(0.0, -INFINITY), (1.0, INFINITY)]);
+    }
+}
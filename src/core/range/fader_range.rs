@@ -0,0 +1,422 @@
+use super::Range;
+use crate::core::math::{amplitude_to_db_f32, db_to_amplitdue_f32};
+use crate::core::{Normal, Precision, ValueFormatter};
+
+/// Breakpoints of the IEC 60268-18 scale as `(dB, position)` pairs, where
+/// the position is the proportion of travel between the bottom and 0 dB.
+static IEC_60268_18_TAPER: [(f32, f32); 7] = [
+    (-70.0, 0.0),
+    (-60.0, 0.025),
+    (-50.0, 0.075),
+    (-40.0, 0.15),
+    (-30.0, 0.3),
+    (-20.0, 0.5),
+    (0.0, 1.0),
+];
+
+/// Breakpoints of an SSL-like console fader scale as `(dB, position)` pairs,
+/// where the position is the proportion of travel between the bottom and
+/// 0 dB.
+static SSL_TAPER: [(f32, f32); 10] = [
+    (-70.0, 0.0),
+    (-60.0, 0.03),
+    (-50.0, 0.08),
+    (-40.0, 0.16),
+    (-30.0, 0.28),
+    (-20.0, 0.45),
+    (-15.0, 0.57),
+    (-10.0, 0.7),
+    (-5.0, 0.85),
+    (0.0, 1.0),
+];
+
+/// The [`Normal`] of the lowest point of every [`FaderTaper`], such as
+/// -70 dB. This is just above the very bottom of the fader, so that the
+/// lowest point stays distinct from -inf dB.
+///
+/// [`Normal`]: ../struct.Normal.html
+/// [`FaderTaper`]: enum.FaderTaper.html
+static FLOOR_NORMAL: f32 = f32::MIN_POSITIVE;
+
+/// The taper (fader law) of a [`FaderRange`] below 0 dB
+///
+/// [`FaderRange`]: struct.FaderRange.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FaderTaper {
+    /// A scale modeled after SSL-like mixing console faders
+    Ssl,
+    /// The IEC 60268-18 peak meter scale
+    Iec60268_18,
+}
+
+impl FaderTaper {
+    fn breakpoints(&self) -> &'static [(f32, f32)] {
+        match self {
+            FaderTaper::Ssl => &SSL_TAPER,
+            FaderTaper::Iec60268_18 => &IEC_60268_18_TAPER,
+        }
+    }
+}
+
+/// A range that defines a mixer fader of `dB` values, where the very bottom
+/// of the fader (a [`Normal`] of `0.0`) is `-inf dB` (silence).
+///
+/// Below 0 dB the values follow a [`FaderTaper`]. Above 0 dB the values are
+/// spaced linearly up to the maximum. Values are displayed with 1 decimal
+/// place by default, such as `"-6.0 dB"` and `"-inf dB"`.
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::{FaderRange, FaderTaper, Normal, Range};
+///
+/// let range = FaderRange::new(6.0, Normal::new(0.75), FaderTaper::Ssl);
+///
+/// assert_eq!(range.unmap_to_value(Normal::min()), f32::NEG_INFINITY);
+/// assert_eq!(range.unmap_to_amplitude(Normal::min()), 0.0);
+/// assert_eq!(range.unmap_to_value(Normal::new(0.75)), 0.0);
+/// assert_eq!(range.unmap_to_value(Normal::max()), 6.0);
+/// ```
+///
+/// [`Normal`]: ../struct.Normal.html
+/// [`FaderTaper`]: enum.FaderTaper.html
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(into = "FaderRangeDef", try_from = "FaderRangeDef")
+)]
+pub struct FaderRange {
+    max: f32,
+    unity_position: Normal,
+    taper: FaderTaper,
+    formatter: ValueFormatter,
+}
+
+impl FaderRange {
+    /// Creates a new `FaderRange`
+    ///
+    /// # Arguments
+    ///
+    /// * `max` - the maximum of the range in dB (inclusive), must be >= 0.0
+    /// * `unity_position` - a normal that defines where on the fader 0 dB
+    ///   should be. This must be `1.0` if `max` is `0.0`.
+    /// * `taper` - the [`FaderTaper`] to use below 0 dB
+    ///
+    /// # Panics
+    ///
+    /// This will panic if
+    /// * `max` < `0.0`
+    /// * `unity_position` is `0.0`
+    /// * `max` > `0.0` and `unity_position` is `1.0`
+    /// * `max` is `0.0` and `unity_position` is not `1.0`
+    ///
+    /// [`FaderTaper`]: enum.FaderTaper.html
+    pub fn new(max: f32, unity_position: Normal, taper: FaderTaper) -> Self {
+        assert!(max >= 0.0, "max must be 0.0 or positive");
+        assert!(
+            unity_position > Normal::min(),
+            "unity_position must be greater than 0.0"
+        );
+        assert!(
+            max == 0.0 || unity_position < Normal::max(),
+            "unity_position must be less than 1.0 when max is positive"
+        );
+        assert!(
+            max > 0.0 || unity_position == Normal::max(),
+            "unity_position must be 1.0 when max is 0.0"
+        );
+
+        Self {
+            max,
+            unity_position,
+            taper,
+            formatter: ValueFormatter::new("dB")
+                .precision(Precision::Decimals(1)),
+        }
+    }
+
+    /// Returns the [`FaderTaper`] of this range
+    ///
+    /// [`FaderTaper`]: enum.FaderTaper.html
+    pub fn taper(&self) -> FaderTaper {
+        self.taper
+    }
+
+    /// Sets the [`ValueFormatter`] used to display and parse values
+    ///
+    /// [`ValueFormatter`]: ../format/struct.ValueFormatter.html
+    pub fn formatter(mut self, formatter: ValueFormatter) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Returns the linear amplitude (gain) from the supplied [`Normal`].
+    ///
+    /// A [`Normal`] of `0.0` returns an amplitude of `0.0`.
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn unmap_to_amplitude(&self, normal: Normal) -> f32 {
+        let db = self.unmap_to_value(normal);
+        if db == f32::NEG_INFINITY {
+            0.0
+        } else {
+            db_to_amplitdue_f32(db)
+        }
+    }
+
+    /// Returns the corresponding [`Normal`] from the supplied linear
+    /// amplitude (gain).
+    ///
+    /// An amplitude of `0.0` or less returns a [`Normal`] of `0.0`.
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn map_amplitude_to_normal(&self, amplitude: f32) -> Normal {
+        if amplitude <= 0.0 {
+            Normal::min()
+        } else {
+            self.map_to_normal(amplitude_to_db_f32(amplitude))
+        }
+    }
+}
+
+impl Range for FaderRange {
+    type Value = f32;
+
+    /// Returns `-inf` dB
+    fn min(&self) -> f32 {
+        f32::NEG_INFINITY
+    }
+
+    fn max(&self) -> f32 {
+        self.max
+    }
+
+    /// Returns `0.0` dB
+    fn default_value(&self) -> f32 {
+        0.0
+    }
+
+    /// Returns the corresponding [`Normal`] from the supplied `value`
+    ///
+    /// Only `-inf` dB maps to the very bottom of the fader. Values at or
+    /// below the lowest point of the [`FaderTaper`] map to that point, just
+    /// above the bottom, so that the lowest point round-trips through
+    /// [`unmap_to_value`].
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    /// [`FaderTaper`]: enum.FaderTaper.html
+    /// [`unmap_to_value`]: #method.unmap_to_value
+    fn map_to_normal(&self, value: f32) -> Normal {
+        if value == f32::NEG_INFINITY {
+            return Normal::min();
+        }
+
+        let value = self.clamp(value);
+        let unity = self.unity_position.as_f32();
+
+        if value >= 0.0 {
+            if self.max == 0.0 {
+                return self.unity_position;
+            }
+            return (unity + ((value / self.max) * (1.0 - unity))).into();
+        }
+
+        let breakpoints = self.taper.breakpoints();
+
+        if value <= breakpoints[0].0 {
+            return FLOOR_NORMAL.into();
+        }
+
+        for window in breakpoints.windows(2) {
+            let (db_1, pos_1) = window[0];
+            let (db_2, pos_2) = window[1];
+
+            if value <= db_2 {
+                let amount = (value - db_1) / (db_2 - db_1);
+                return ((pos_1 + (amount * (pos_2 - pos_1))) * unity).into();
+            }
+        }
+
+        self.unity_position
+    }
+
+    fn unmap_to_value(&self, normal: Normal) -> f32 {
+        if normal == Normal::min() {
+            return f32::NEG_INFINITY;
+        }
+
+        let unity = self.unity_position.as_f32();
+
+        if normal >= self.unity_position {
+            if unity >= 1.0 {
+                return 0.0;
+            }
+            return ((normal.as_f32() - unity) / (1.0 - unity)) * self.max;
+        }
+
+        let pos = normal.as_f32() / unity;
+        let breakpoints = self.taper.breakpoints();
+
+        for window in breakpoints.windows(2) {
+            let (db_1, pos_1) = window[0];
+            let (db_2, pos_2) = window[1];
+
+            if pos <= pos_2 {
+                let amount = (pos - pos_1) / (pos_2 - pos_1);
+                return db_1 + (amount * (db_2 - db_1));
+            }
+        }
+
+        0.0
+    }
+
+    fn format_value(&self, value: f32) -> String {
+        self.formatter.format(value)
+    }
+
+    fn parse_value(&self, text: &str) -> Option<f32> {
+        self.formatter.parse(text)
+    }
+}
+
+impl Default for FaderRange {
+    fn default() -> Self {
+        FaderRange::new(6.0, Normal::new(0.75), FaderTaper::Ssl)
+    }
+}
+
+/// The serialized form of a [`FaderRange`], checked with the same rules as
+/// [`FaderRange::new`] so that deserializing never panics.
+///
+/// [`FaderRange`]: struct.FaderRange.html
+/// [`FaderRange::new`]: struct.FaderRange.html#method.new
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename = "FaderRange")]
+struct FaderRangeDef {
+    max: f32,
+    unity_position: Normal,
+    taper: FaderTaper,
+    formatter: ValueFormatter,
+}
+
+#[cfg(feature = "serde")]
+impl From<FaderRange> for FaderRangeDef {
+    fn from(range: FaderRange) -> Self {
+        Self {
+            max: range.max,
+            unity_position: range.unity_position,
+            taper: range.taper,
+            formatter: range.formatter,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl std::convert::TryFrom<FaderRangeDef> for FaderRange {
+    type Error = &'static str;
+
+    fn try_from(def: FaderRangeDef) -> Result<Self, Self::Error> {
+        if !def.max.is_finite() || def.max < 0.0 {
+            return Err("max must be finite and 0.0 or positive");
+        }
+        if def.unity_position <= Normal::min() {
+            return Err("unity_position must be greater than 0.0");
+        }
+        if def.max != 0.0 && def.unity_position >= Normal::max() {
+            return Err(
+                "unity_position must be less than 1.0 when max is positive",
+            );
+        }
+        if def.max == 0.0 && def.unity_position != Normal::max() {
+            return Err("unity_position must be 1.0 when max is 0.0");
+        }
+
+        Ok(FaderRange::new(def.max, def.unity_position, def.taper)
+            .formatter(def.formatter))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fader() -> FaderRange {
+        FaderRange::new(6.0, Normal::new(0.75), FaderTaper::Ssl)
+    }
+
+    #[test]
+    fn bottom_is_silence() {
+        let range = fader();
+
+        assert_eq!(range.unmap_to_value(Normal::min()), f32::NEG_INFINITY);
+        assert_eq!(range.map_to_normal(f32::NEG_INFINITY), Normal::min());
+        assert_eq!(range.unmap_to_amplitude(Normal::min()), 0.0);
+        assert_eq!(range.map_amplitude_to_normal(0.0), Normal::min());
+    }
+
+    #[test]
+    fn floor_of_the_taper_round_trips() {
+        for taper in &[FaderTaper::Ssl, FaderTaper::Iec60268_18] {
+            let range = FaderRange::new(6.0, Normal::new(0.75), *taper);
+            let floor = range.map_to_normal(-70.0);
+
+            assert!(floor > Normal::min());
+            assert_eq!(range.unmap_to_value(floor), -70.0);
+            assert_eq!(range.map_to_normal(-90.0), floor);
+        }
+    }
+
+    #[test]
+    fn breakpoints_and_unity_round_trip() {
+        let range = fader();
+
+        assert_eq!(range.map_to_normal(0.0), Normal::new(0.75));
+        assert_eq!(range.unmap_to_value(Normal::new(0.75)), 0.0);
+        assert_eq!(range.map_to_normal(6.0), Normal::max());
+        assert_eq!(range.map_to_normal(12.0), Normal::max());
+
+        for (db, _) in SSL_TAPER.iter() {
+            let round_trip = range.unmap_to_value(range.map_to_normal(*db));
+            assert!((round_trip - db).abs() < 1.0e-4, "{} dB", db);
+        }
+        let round_trip = range.unmap_to_value(range.map_to_normal(3.0));
+        assert!((round_trip - 3.0).abs() < 1.0e-4);
+    }
+
+    #[test]
+    fn zero_max_puts_unity_at_the_top() {
+        let range = FaderRange::new(0.0, Normal::max(), FaderTaper::Ssl);
+
+        assert_eq!(range.map_to_normal(0.0), Normal::max());
+        assert_eq!(range.unmap_to_value(Normal::max()), 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "unity_position must be 1.0 when max is 0.0")]
+    fn zero_max_rejects_unity_below_the_top() {
+        let _ = FaderRange::new(0.0, Normal::new(0.75), FaderTaper::Ssl);
+    }
+
+    #[test]
+    #[should_panic(expected = "must be less than 1.0 when max is positive")]
+    fn positive_max_rejects_unity_at_the_top() {
+        let _ = FaderRange::new(6.0, Normal::max(), FaderTaper::Ssl);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trips_and_checks_unity() {
+        let range = fader();
+        let json = serde_json::to_string(&range).unwrap();
+        let loaded: FaderRange = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(loaded.taper(), FaderTaper::Ssl);
+        assert_eq!(loaded.map_to_normal(-20.0), range.map_to_normal(-20.0));
+
+        let invalid = json.replace("\"max\":6.0", "\"max\":0.0");
+        assert!(serde_json::from_str::<FaderRange>(&invalid).is_err());
+    }
+}
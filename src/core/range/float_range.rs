@@ -0,0 +1,177 @@
+use super::Range;
+use crate::core::{Normal, NormalParam, ValueFormatter};
+
+/// A range that maps a continuous linear range of `f32` values
+/// to a [`Normal`]
+///
+/// Values are displayed with 2 decimal places by default.
+///
+/// [`Normal`]: ../struct.Normal.html
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(into = "FloatRangeDef", try_from = "FloatRangeDef")
+)]
+pub struct FloatRange {
+    min: f32,
+    max: f32,
+    span: f32,
+    span_recip: f32,
+    formatter: ValueFormatter,
+}
+
+impl FloatRange {
+    /// Creates a new `FloatRange`
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - the minimum of the range (inclusive)
+    /// * `max` - the maximum of the range (inclusive)
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `max` <= `min`
+    pub fn new(min: f32, max: f32) -> Self {
+        assert!(max > min);
+
+        let span = max - min;
+        let span_recip = span.recip();
+
+        Self {
+            min,
+            max,
+            span,
+            span_recip,
+            formatter: ValueFormatter::default(),
+        }
+    }
+
+    /// Sets the [`ValueFormatter`] used to display and parse values
+    ///
+    /// [`ValueFormatter`]: ../format/struct.ValueFormatter.html
+    pub fn formatter(mut self, formatter: ValueFormatter) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// A `FloatRange` with the range
+    ///
+    /// * `min` = -1.0
+    /// * `max` = 1.0
+    pub fn default_bipolar() -> Self {
+        FloatRange::new(-1.0, 1.0)
+    }
+
+    /// Creates a new [`NormalParam`] with values mapped
+    /// from this range.
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    ///
+    /// * `value` - The inital value of the parameter.
+    /// * `default` - The default value of the parameter.
+    pub fn normal_param(&self, value: f32, default: f32) -> NormalParam {
+        Range::normal_param(self, value, default)
+    }
+
+    /// Creates a new [`NormalParam`] with values mapped
+    /// from this range where `value` and `default` is
+    /// [`default_value`].
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    /// [`default_value`]: trait.Range.html#tymethod.default_value
+    pub fn default_normal_param(&self) -> NormalParam {
+        Range::default_normal_param(self)
+    }
+
+    /// Returns the corresponding [`Normal`] from the supplied value
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn map_to_normal(&self, value: f32) -> Normal {
+        Range::map_to_normal(self, value)
+    }
+
+    /// Returns the corresponding value from the supplied [`Normal`]
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn unmap_to_value(&self, normal: Normal) -> f32 {
+        Range::unmap_to_value(self, normal)
+    }
+}
+
+impl Range for FloatRange {
+    type Value = f32;
+
+    fn min(&self) -> f32 {
+        self.min
+    }
+
+    fn max(&self) -> f32 {
+        self.max
+    }
+
+    /// Returns `0.0` (constrained to this range)
+    fn default_value(&self) -> f32 {
+        self.clamp(0.0)
+    }
+
+    fn map_to_normal(&self, value: f32) -> Normal {
+        let value = self.clamp(value);
+        ((value - self.min) * self.span_recip).into()
+    }
+
+    fn unmap_to_value(&self, normal: Normal) -> f32 {
+        (normal.as_f32() * self.span) + self.min
+    }
+
+    fn format_value(&self, value: f32) -> String {
+        self.formatter.format(value)
+    }
+
+    fn parse_value(&self, text: &str) -> Option<f32> {
+        self.formatter.parse(text)
+    }
+}
+
+impl Default for FloatRange {
+    fn default() -> Self {
+        FloatRange::new(0.0, 1.0)
+    }
+}
+
+/// The serialized form of a [`FloatRange`], without the span that is
+/// recomputed from `min` and `max`.
+///
+/// [`FloatRange`]: struct.FloatRange.html
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename = "FloatRange")]
+struct FloatRangeDef {
+    min: f32,
+    max: f32,
+    formatter: ValueFormatter,
+}
+
+#[cfg(feature = "serde")]
+impl From<FloatRange> for FloatRangeDef {
+    fn from(range: FloatRange) -> Self {
+        Self {
+            min: range.min,
+            max: range.max,
+            formatter: range.formatter,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl std::convert::TryFrom<FloatRangeDef> for FloatRange {
+    type Error = &'static str;
+
+    fn try_from(def: FloatRangeDef) -> Result<Self, Self::Error> {
+        if !super::valid_span(def.min, def.max) {
+            return Err("min and max must be finite with max greater than min");
+        }
+
+        Ok(FloatRange::new(def.min, def.max).formatter(def.formatter))
+    }
+}
@@ -0,0 +1,282 @@
+use super::Range;
+use crate::core::{Normal, NormalParam, Precision, ValueFormatter};
+
+/// The minimum frequency of the 10 octave audible spectrum in Hz
+pub static SPECTRUM_MIN_HZ: f32 = 20.0;
+/// The maximum frequency of the 10 octave audible spectrum in Hz
+pub static SPECTRUM_MAX_HZ: f32 = 20_480.0;
+
+/// A range that defines a continuous logarithmic range of `f32` frequency
+/// values, with each octave spaced evenly.
+///
+/// Smaller frequencies will increment slower per slider movement than larger
+/// ones.
+///
+/// [`new`] constrains the range to the 10 octave audible spectrum, while
+/// [`unbounded`] can span any positive frequencies, such as LFO rates well
+/// below the audible spectrum. Values are displayed with 3 significant
+/// digits and engineering prefixes by default, such as `"1.20 kHz"`.
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::{FreqRange, Normal, Range};
+///
+/// // An LFO rate from 0.01 Hz to 50 Hz.
+/// let range = FreqRange::unbounded(0.01, 50.0);
+///
+/// assert_eq!(range.unmap_to_value(Normal::min()), 0.01);
+/// assert_eq!(range.unmap_to_value(Normal::max()), 50.0);
+/// ```
+///
+/// [`new`]: #method.new
+/// [`unbounded`]: #method.unbounded
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(into = "FreqRangeDef", try_from = "FreqRangeDef")
+)]
+pub struct FreqRange {
+    min: f32,
+    max: f32,
+    log_min: f32,
+    log_span: f32,
+    log_span_recip: f32,
+    formatter: ValueFormatter,
+}
+
+impl FreqRange {
+    /// Creates a new `FreqRange` constrained to the 10 octave audible
+    /// spectrum.
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - the minimum of the range in Hz (inclusive), will be
+    ///   constrained to `20.0 Hz <= min`
+    /// * `max` - the maximum of the range in Hz (inclusive), will be
+    ///   constrained to `max <= 20480.0 Hz`
+    ///
+    /// # Panics
+    ///
+    /// This will panic if
+    /// * `max` <= `min`, before or after they are constrained
+    ///
+    pub fn new(min: f32, max: f32) -> Self {
+        assert!(max > min, "max must be greater than min");
+
+        FreqRange::unbounded(min.max(SPECTRUM_MIN_HZ), max.min(SPECTRUM_MAX_HZ))
+    }
+
+    /// Creates a new `FreqRange` that can span any positive frequencies,
+    /// such as LFO rates below the audible spectrum
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - the minimum of the range in Hz (inclusive)
+    /// * `max` - the maximum of the range in Hz (inclusive)
+    ///
+    /// # Panics
+    ///
+    /// This will panic if
+    /// * `min` <= `0.0`
+    /// * `max` <= `min`
+    ///
+    pub fn unbounded(min: f32, max: f32) -> Self {
+        assert!(min > 0.0, "min must be greater than 0.0");
+        assert!(max > min, "max must be greater than min");
+
+        let log_min = min.log2();
+        let log_span = max.log2() - log_min;
+
+        Self {
+            min,
+            max,
+            log_min,
+            log_span,
+            log_span_recip: log_span.recip(),
+            formatter: ValueFormatter::new("Hz")
+                .precision(Precision::Significant(3))
+                .prefixes(true),
+        }
+    }
+
+    /// Returns the number of octaves this range spans
+    pub fn octaves(&self) -> f32 {
+        self.log_span
+    }
+
+    /// Sets the [`ValueFormatter`] used to display and parse values
+    ///
+    /// [`ValueFormatter`]: ../format/struct.ValueFormatter.html
+    pub fn formatter(mut self, formatter: ValueFormatter) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Creates a new [`NormalParam`] with values mapped
+    /// from this range.
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    ///
+    /// * `value` - The inital value of the parameter.
+    /// * `default` - The default value of the parameter.
+    pub fn normal_param(&self, value: f32, default: f32) -> NormalParam {
+        Range::normal_param(self, value, default)
+    }
+
+    /// Creates a new [`NormalParam`] with values mapped
+    /// from this range where `value` and `default` is
+    /// [`default_value`].
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    /// [`default_value`]: trait.Range.html#tymethod.default_value
+    pub fn default_normal_param(&self) -> NormalParam {
+        Range::default_normal_param(self)
+    }
+
+    /// Returns the corresponding [`Normal`] from the supplied value
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn map_to_normal(&self, value: f32) -> Normal {
+        Range::map_to_normal(self, value)
+    }
+
+    /// Returns the corresponding value from the supplied [`Normal`]
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn unmap_to_value(&self, normal: Normal) -> f32 {
+        Range::unmap_to_value(self, normal)
+    }
+}
+
+impl Range for FreqRange {
+    type Value = f32;
+
+    fn min(&self) -> f32 {
+        self.min
+    }
+
+    fn max(&self) -> f32 {
+        self.max
+    }
+
+    /// Returns `20480.0` Hz (constrained to this range)
+    fn default_value(&self) -> f32 {
+        self.clamp(SPECTRUM_MAX_HZ)
+    }
+
+    fn map_to_normal(&self, value: f32) -> Normal {
+        let value = self.clamp(value);
+        ((value.log2() - self.log_min) * self.log_span_recip).into()
+    }
+
+    fn unmap_to_value(&self, normal: Normal) -> f32 {
+        if normal == Normal::min() {
+            return self.min;
+        }
+        if normal == Normal::max() {
+            return self.max;
+        }
+
+        self.clamp(2.0_f32.powf(normal.scale(self.log_span) + self.log_min))
+    }
+
+    fn format_value(&self, value: f32) -> String {
+        self.formatter.format(value)
+    }
+
+    fn parse_value(&self, text: &str) -> Option<f32> {
+        self.formatter.parse(text)
+    }
+}
+
+impl Default for FreqRange {
+    fn default() -> Self {
+        FreqRange::new(SPECTRUM_MIN_HZ, 20_000.0)
+    }
+}
+
+/// The serialized form of a [`FreqRange`]. The logarithmic span used to
+/// map frequencies is recomputed from `min` and `max`.
+///
+/// [`FreqRange`]: struct.FreqRange.html
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename = "FreqRange")]
+struct FreqRangeDef {
+    min: f32,
+    max: f32,
+    formatter: ValueFormatter,
+}
+
+#[cfg(feature = "serde")]
+impl From<FreqRange> for FreqRangeDef {
+    fn from(range: FreqRange) -> Self {
+        Self {
+            min: range.min,
+            max: range.max,
+            formatter: range.formatter,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl std::convert::TryFrom<FreqRangeDef> for FreqRange {
+    type Error = &'static str;
+
+    fn try_from(def: FreqRangeDef) -> Result<Self, Self::Error> {
+        if !super::valid_span(def.min, def.max) {
+            return Err("min and max must be finite with max greater than min");
+        }
+        if def.min <= 0.0 {
+            return Err("min must be greater than 0.0");
+        }
+
+        Ok(FreqRange::unbounded(def.min, def.max).formatter(def.formatter))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_constrains_to_spectrum() {
+        let range = FreqRange::new(0.0, 30_000.0);
+
+        assert_eq!(range.min(), SPECTRUM_MIN_HZ);
+        assert_eq!(range.max(), SPECTRUM_MAX_HZ);
+        assert_eq!(range.octaves(), 10.0);
+    }
+
+    #[test]
+    fn unbounded_spans_below_spectrum() {
+        let range = FreqRange::unbounded(0.25, 4.0);
+
+        assert_eq!(range.min(), 0.25);
+        assert_eq!(range.octaves(), 4.0);
+        assert_eq!(range.unmap_to_value(Normal::new(0.5)), 1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "min must be greater than 0.0")]
+    fn unbounded_rejects_zero_min() {
+        let _ = FreqRange::unbounded(0.0, 50.0);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_recomputes_log_span() {
+        let range = FreqRange::unbounded(0.25, 4.0);
+        let json = serde_json::to_string(&range).unwrap();
+        let loaded: FreqRange = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(loaded.octaves(), 4.0);
+        assert_eq!(loaded.unmap_to_value(Normal::new(0.5)), 1.0);
+        assert_eq!(loaded.map_to_normal(2.0), range.map_to_normal(2.0));
+
+        let invalid = json.replace("\"min\":0.25", "\"min\":0.0");
+        assert!(serde_json::from_str::<FreqRange>(&invalid).is_err());
+    }
+}
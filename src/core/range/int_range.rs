@@ -0,0 +1,183 @@
+use super::Range;
+use crate::core::{Normal, NormalParam, Precision, ValueFormatter};
+
+/// A range that defines a discrete linear range of i32 values
+///
+/// Values are displayed as whole numbers by default.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(into = "IntRangeDef", try_from = "IntRangeDef")
+)]
+pub struct IntRange {
+    min: i32,
+    max: i32,
+    span: f32,
+    span_recip: f32,
+    formatter: ValueFormatter,
+}
+
+impl IntRange {
+    /// Creates a new `IntRange`
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - the minimum of the range (inclusive)
+    /// * `max` - the maximum of the range (inclusive)
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `max` <= `min`
+    pub fn new(min: i32, max: i32) -> Self {
+        assert!(max > min);
+
+        let span = (max - min) as f32;
+        let span_recip = span.recip();
+
+        Self {
+            min,
+            max,
+            span,
+            span_recip,
+            formatter: ValueFormatter::default()
+                .precision(Precision::Decimals(0)),
+        }
+    }
+
+    /// Sets the [`ValueFormatter`] used to display and parse values
+    ///
+    /// [`ValueFormatter`]: ../format/struct.ValueFormatter.html
+    pub fn formatter(mut self, formatter: ValueFormatter) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Creates a new [`NormalParam`] with values mapped
+    /// from this range.
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    ///
+    /// * `value` - The inital value of the parameter.
+    /// * `default` - The default value of the parameter.
+    pub fn normal_param(&self, value: i32, default: i32) -> NormalParam {
+        Range::normal_param(self, value, default)
+    }
+
+    /// Creates a new [`NormalParam`] with values mapped
+    /// from this range where `value` and `default` is
+    /// [`default_value`].
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    /// [`default_value`]: trait.Range.html#tymethod.default_value
+    pub fn default_normal_param(&self) -> NormalParam {
+        Range::default_normal_param(self)
+    }
+
+    /// Snaps a [`Normal`] to the closest integer value in this
+    /// range.
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn snap(&self, normal: &mut Normal) {
+        Range::snap(self, normal)
+    }
+
+    /// Returns the corresponding [`Normal`] from the supplied value
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn map_to_normal(&self, value: i32) -> Normal {
+        Range::map_to_normal(self, value)
+    }
+
+    /// Returns the corresponding value from the supplied [`Normal`]
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn unmap_to_value(&self, normal: Normal) -> i32 {
+        Range::unmap_to_value(self, normal)
+    }
+}
+
+impl Range for IntRange {
+    type Value = i32;
+
+    fn min(&self) -> i32 {
+        self.min
+    }
+
+    fn max(&self) -> i32 {
+        self.max
+    }
+
+    /// Returns `0` (constrained to this range)
+    fn default_value(&self) -> i32 {
+        self.clamp(0)
+    }
+
+    fn discrete_len(&self) -> Option<usize> {
+        Some((self.max - self.min) as usize + 1)
+    }
+
+    fn map_to_normal(&self, value: i32) -> Normal {
+        let value = self.clamp(value);
+        ((value - self.min) as f32 * self.span_recip).into()
+    }
+
+    fn unmap_to_value(&self, normal: Normal) -> i32 {
+        (normal.as_f32() * self.span).round() as i32 + self.min
+    }
+
+    fn format_value(&self, value: i32) -> String {
+        self.formatter.format(value as f32)
+    }
+
+    /// Parses typed text into a value, rounded to the closest integer
+    fn parse_value(&self, text: &str) -> Option<i32> {
+        self.formatter
+            .parse(text)
+            .filter(|value| value.is_finite())
+            .map(|value| value.round() as i32)
+    }
+}
+
+impl Default for IntRange {
+    fn default() -> Self {
+        IntRange::new(0, 100)
+    }
+}
+
+/// The serialized form of an [`IntRange`], without the span that is
+/// recomputed from `min` and `max`.
+///
+/// [`IntRange`]: struct.IntRange.html
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename = "IntRange")]
+struct IntRangeDef {
+    min: i32,
+    max: i32,
+    formatter: ValueFormatter,
+}
+
+#[cfg(feature = "serde")]
+impl From<IntRange> for IntRangeDef {
+    fn from(range: IntRange) -> Self {
+        Self {
+            min: range.min,
+            max: range.max,
+            formatter: range.formatter,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl std::convert::TryFrom<IntRangeDef> for IntRange {
+    type Error = &'static str;
+
+    fn try_from(def: IntRangeDef) -> Result<Self, Self::Error> {
+        if def.max <= def.min {
+            return Err("max must be greater than min");
+        }
+
+        Ok(IntRange::new(def.min, def.max).formatter(def.formatter))
+    }
+}
@@ -0,0 +1,253 @@
+use super::Range;
+use crate::core::{Normal, NormalParam, Precision, ValueFormatter};
+
+/// A range that defines a continuous logarithmic range of `dB` values,
+/// with an inflection/stationary point at 0 dB
+///
+/// Values around 0 dB (positive and negative) will increment slower per
+/// slider movement than values farther away from 0 dB.
+///
+/// Values are displayed with 1 decimal place by default, such as
+/// `"-6.0 dB"`.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(into = "LogDBRangeDef", try_from = "LogDBRangeDef")
+)]
+pub struct LogDBRange {
+    min: f32,
+    max: f32,
+    zero_position: Normal,
+    min_recip: f32,
+    max_recip: f32,
+    zero_pos_recip: f32,
+    one_min_zero_pos_recip: f32,
+    formatter: ValueFormatter,
+}
+
+impl LogDBRange {
+    /// Creates a new `LogDBRange`
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - the minimum of the range in dB (inclusive), must be <= 0.0
+    /// * `max` - the maximum of the range in dB (inclusive), must be >= 0.0
+    /// * `zero_position` - a normal that defines where on the slider 0 decibels
+    /// should be. For example, `Normal::new(0.5)` will have 0 dB at the center
+    /// of the slider. Normals of `1.0` and `0.0` can be used for only negative
+    /// or only positive decibels respectively
+    ///
+    /// # Panics
+    ///
+    /// This will panic if
+    /// * `max` <= `min`
+    /// * `min` > `0.0`
+    /// * `max` < `0.0`
+    ///
+    pub fn new(min: f32, max: f32, zero_position: Normal) -> Self {
+        assert!(max > min, "max must be greater than min");
+        assert!(max >= 0.0, "max must be 0.0 or positive");
+        assert!(min <= 0.0, "min must be 0.0 or negative");
+
+        let min_recip = if min == 0.0 { 0.0 } else { 1.0 / min };
+
+        let max_recip = if max == 0.0 { 0.0 } else { 1.0 / max };
+
+        let zero_pos_recip = if zero_position.as_f32() == 0.0 {
+            0.0
+        } else {
+            1.0 / zero_position.as_f32()
+        };
+
+        let one_min_zero_pos_recip = if zero_position.as_f32() == 0.0 {
+            0.0
+        } else {
+            1.0 / (1.0 - zero_position.as_f32())
+        };
+
+        Self {
+            min,
+            max,
+            zero_position,
+            min_recip,
+            max_recip,
+            zero_pos_recip,
+            one_min_zero_pos_recip,
+            formatter: ValueFormatter::new("dB")
+                .precision(Precision::Decimals(1)),
+        }
+    }
+
+    /// Sets the [`ValueFormatter`] used to display and parse values
+    ///
+    /// [`ValueFormatter`]: ../format/struct.ValueFormatter.html
+    pub fn formatter(mut self, formatter: ValueFormatter) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Creates a new [`NormalParam`] with values mapped
+    /// from this range.
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    ///
+    /// * `value` - The inital value of the parameter.
+    /// * `default` - The default value of the parameter.
+    pub fn normal_param(&self, value: f32, default: f32) -> NormalParam {
+        Range::normal_param(self, value, default)
+    }
+
+    /// Creates a new [`NormalParam`] with values mapped
+    /// from this range where `value` and `default` is
+    /// [`default_value`].
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    /// [`default_value`]: trait.Range.html#tymethod.default_value
+    pub fn default_normal_param(&self) -> NormalParam {
+        Range::default_normal_param(self)
+    }
+
+    /// Returns the corresponding [`Normal`] from the supplied value
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn map_to_normal(&self, value: f32) -> Normal {
+        Range::map_to_normal(self, value)
+    }
+
+    /// Returns the corresponding value from the supplied [`Normal`]
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn unmap_to_value(&self, normal: Normal) -> f32 {
+        Range::unmap_to_value(self, normal)
+    }
+}
+
+impl Range for LogDBRange {
+    type Value = f32;
+
+    fn min(&self) -> f32 {
+        self.min
+    }
+
+    fn max(&self) -> f32 {
+        self.max
+    }
+
+    /// Returns `0.0` dB
+    fn default_value(&self) -> f32 {
+        0.0
+    }
+
+    fn map_to_normal(&self, value: f32) -> Normal {
+        let value = self.clamp(value);
+        if value == 0.0 {
+            self.zero_position
+        } else if value < 0.0 {
+            if self.min >= 0.0 {
+                return 0.0.into();
+            }
+            let neg_normal = value * self.min_recip;
+
+            let log_normal = 1.0 - neg_normal.sqrt();
+
+            (log_normal * self.zero_position.as_f32()).into()
+        } else {
+            if self.max <= 0.0 {
+                return 1.0.into();
+            }
+            let pos_normal = value * self.max_recip;
+
+            let log_normal = pos_normal.sqrt();
+
+            ((log_normal * (1.0 - self.zero_position.as_f32()))
+                + self.zero_position.as_f32())
+            .into()
+        }
+    }
+
+    fn unmap_to_value(&self, normal: Normal) -> f32 {
+        if normal == self.zero_position {
+            0.0
+        } else if normal < self.zero_position {
+            if self.min >= 0.0 {
+                return self.min;
+            }
+            let neg_normal = 1.0 - (normal.as_f32() * self.zero_pos_recip);
+
+            let log_normal = 1.0 - (neg_normal * neg_normal);
+
+            (1.0 - log_normal) * self.min
+        } else {
+            if self.zero_position.as_f32() == 1.0 || self.max <= 0.0 {
+                return self.max;
+            }
+            let pos_normal = (normal.as_f32() - self.zero_position.as_f32())
+                * self.one_min_zero_pos_recip;
+
+            let log_normal = pos_normal * pos_normal;
+
+            log_normal * self.max
+        }
+    }
+
+    fn format_value(&self, value: f32) -> String {
+        self.formatter.format(value)
+    }
+
+    fn parse_value(&self, text: &str) -> Option<f32> {
+        self.formatter.parse(text)
+    }
+}
+
+impl Default for LogDBRange {
+    fn default() -> Self {
+        LogDBRange::new(-12.0, 12.0, 0.5.into())
+    }
+}
+
+/// The serialized form of a [`LogDBRange`]. The reciprocals used to map
+/// values on either side of `zero_position` are recomputed.
+///
+/// [`LogDBRange`]: struct.LogDBRange.html
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename = "LogDBRange")]
+struct LogDBRangeDef {
+    min: f32,
+    max: f32,
+    zero_position: Normal,
+    formatter: ValueFormatter,
+}
+
+#[cfg(feature = "serde")]
+impl From<LogDBRange> for LogDBRangeDef {
+    fn from(range: LogDBRange) -> Self {
+        Self {
+            min: range.min,
+            max: range.max,
+            zero_position: range.zero_position,
+            formatter: range.formatter,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl std::convert::TryFrom<LogDBRangeDef> for LogDBRange {
+    type Error = &'static str;
+
+    fn try_from(def: LogDBRangeDef) -> Result<Self, Self::Error> {
+        if !super::valid_span(def.min, def.max) {
+            return Err("min and max must be finite with max greater than min");
+        }
+        if def.max < 0.0 {
+            return Err("max must be 0.0 or positive");
+        }
+        if def.min > 0.0 {
+            return Err("min must be 0.0 or negative");
+        }
+
+        Ok(LogDBRange::new(def.min, def.max, def.zero_position)
+            .formatter(def.formatter))
+    }
+}
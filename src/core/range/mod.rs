@@ -0,0 +1,258 @@
+//! Ranges of parameter values that map to a [`Normal`]
+//!
+//! [`Normal`]: ../struct.Normal.html
+
+use crate::core::{Normal, NormalParam};
+
+use std::fmt::{Debug, Display};
+
+mod choice_range;
+mod curve_range;
+mod fader_range;
+mod float_range;
+mod freq_range;
+mod int_range;
+mod log_db_range;
+mod pitch_range;
+mod skew_range;
+mod tempo_range;
+
+pub use choice_range::ChoiceRange;
+pub use curve_range::{CurveInterpolation, CurveRange};
+pub use fader_range::{FaderRange, FaderTaper};
+pub use float_range::FloatRange;
+pub use freq_range::{FreqRange, SPECTRUM_MAX_HZ, SPECTRUM_MIN_HZ};
+pub use int_range::IntRange;
+pub use log_db_range::LogDBRange;
+pub use pitch_range::{
+    PitchMarks, PitchQuantize, PitchRange, PitchUnit, Scale,
+};
+pub use skew_range::SkewRange;
+pub use tempo_range::{DivisionKind, NoteDivision, TempoRange};
+
+/// The amount a [`Normal`] moves per step in a continuous [`Range`].
+///
+/// [`Normal`]: ../struct.Normal.html
+/// [`Range`]: trait.Range.html
+pub static CONTINUOUS_STEP: f32 = 0.01;
+
+/// A range of parameter values that maps to and from a [`Normal`]
+///
+/// This allows code such as preset storage, value displays, and automation
+/// to be generic over any kind of range.
+///
+/// [`FloatRange`], [`IntRange`], [`LogDBRange`] and [`FreqRange`] also
+/// have `map_to_normal`, `unmap_to_value`, `normal_param` and
+/// `default_normal_param` as inherent methods, as does `snap` on
+/// [`IntRange`], which can be called without importing this trait.
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::{FloatRange, IntRange, Normal, Range};
+///
+/// fn describe<R: Range>(range: &R, normal: Normal) -> String {
+///     range.format(range.snapped(normal))
+/// }
+///
+/// assert_eq!(describe(&IntRange::new(0, 10), Normal::new(0.42)), "4");
+/// assert_eq!(describe(&FloatRange::new(0.0, 2.0), Normal::new(0.5)), "1.00");
+/// ```
+///
+/// [`Normal`]: ../struct.Normal.html
+/// [`FloatRange`]: struct.FloatRange.html
+/// [`IntRange`]: struct.IntRange.html
+/// [`LogDBRange`]: struct.LogDBRange.html
+/// [`FreqRange`]: struct.FreqRange.html
+pub trait Range {
+    /// The type of value in this range
+    type Value: Copy + PartialOrd + Debug + Display;
+
+    /// Returns the minimum value of the range (inclusive)
+    fn min(&self) -> Self::Value;
+
+    /// Returns the maximum value of the range (inclusive)
+    fn max(&self) -> Self::Value;
+
+    /// Returns the value used by [`default_normal_param`]
+    ///
+    /// [`default_normal_param`]: #method.default_normal_param
+    fn default_value(&self) -> Self::Value;
+
+    /// Returns the corresponding [`Normal`] from the supplied value
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    fn map_to_normal(&self, value: Self::Value) -> Normal;
+
+    /// Returns the corresponding value from the supplied [`Normal`]
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    fn unmap_to_value(&self, normal: Normal) -> Self::Value;
+
+    /// Returns the number of discrete values in this range, or `None` if
+    /// the range is continuous.
+    fn discrete_len(&self) -> Option<usize> {
+        None
+    }
+
+    /// Returns `true` if the values of this range are quantized, so that a
+    /// widget given the range with its `snap` method steps between them.
+    ///
+    /// By default, only a discrete range is quantized.
+    fn is_quantized(&self) -> bool {
+        self.discrete_len().is_some()
+    }
+
+    /// Returns the value constrained to the minimum and maximum of this range
+    fn clamp(&self, value: Self::Value) -> Self::Value {
+        if value <= self.min() {
+            self.min()
+        } else if value >= self.max() {
+            self.max()
+        } else {
+            value
+        }
+    }
+
+    /// Creates a new [`NormalParam`] with values mapped
+    /// from this range.
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    ///
+    /// * `value` - The inital value of the parameter.
+    /// * `default` - The default value of the parameter.
+    fn normal_param(
+        &self,
+        value: Self::Value,
+        default: Self::Value,
+    ) -> NormalParam {
+        NormalParam {
+            value: self.map_to_normal(value),
+            default: self.map_to_normal(default),
+        }
+    }
+
+    /// Creates a new [`NormalParam`] with values mapped
+    /// from this range where `value` and `default` is
+    /// [`default_value`].
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    /// [`default_value`]: #tymethod.default_value
+    fn default_normal_param(&self) -> NormalParam {
+        self.normal_param(self.default_value(), self.default_value())
+    }
+
+    /// Returns the [`Normal`] of the closest value in this range.
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    fn snapped(&self, normal: Normal) -> Normal {
+        self.map_to_normal(self.unmap_to_value(normal))
+    }
+
+    /// Snaps a [`Normal`] to the closest value in this range.
+    ///
+    /// This is what makes discrete ranges such as [`IntRange`] "step"
+    /// when a widget is moved. A widget given the range with its `snap`
+    /// method, such as [`Knob::snap`], snaps automatically.
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    /// [`IntRange`]: struct.IntRange.html
+    /// [`Knob::snap`]: ../../native/knob/struct.Knob.html#method.snap
+    fn snap(&self, normal: &mut Normal) {
+        *normal = self.snapped(*normal);
+    }
+
+    /// Returns the [`Normal`] of the closest value in this range for a fine
+    /// drag of a widget, which may step more finely than [`snapped`].
+    ///
+    /// By default, this is the same as [`snapped`].
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    /// [`snapped`]: #method.snapped
+    fn snapped_fine(&self, normal: Normal) -> Normal {
+        self.snapped(normal)
+    }
+
+    /// Returns the [`Normal`] that is `steps` steps away from `normal`.
+    /// Negative `steps` move towards the minimum.
+    ///
+    /// A step is a single value in a discrete range, and
+    /// [`CONTINUOUS_STEP`] in a continuous range.
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    /// [`CONTINUOUS_STEP`]: static.CONTINUOUS_STEP.html
+    fn step(&self, normal: Normal, steps: i32) -> Normal {
+        match self.discrete_len() {
+            Some(len) if len > 1 => {
+                let step = 1.0 / (len - 1) as f32;
+                self.snapped(
+                    (self.snapped(normal).as_f32() + (steps as f32 * step))
+                        .into(),
+                )
+            }
+            Some(_) => Normal::min(),
+            None => (normal.as_f32() + (steps as f32 * CONTINUOUS_STEP)).into(),
+        }
+    }
+
+    /// Returns the value as display text.
+    fn format_value(&self, value: Self::Value) -> String {
+        value.to_string()
+    }
+
+    /// Returns the value of the supplied [`Normal`] as display text.
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    fn format(&self, normal: Normal) -> String {
+        self.format_value(self.unmap_to_value(normal))
+    }
+
+    /// Parses typed text into a value, or returns `None` if the text is not
+    /// a valid value.
+    ///
+    /// The returned value may be outside of this range.
+    fn parse_value(&self, _text: &str) -> Option<Self::Value> {
+        None
+    }
+
+    /// Parses typed text into the corresponding [`Normal`], or returns
+    /// `None` if the text is not a valid value.
+    ///
+    /// Values outside of this range are constrained to this range.
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    fn parse(&self, text: &str) -> Option<Normal> {
+        self.parse_value(text)
+            .map(|value| self.map_to_normal(self.clamp(value)))
+    }
+
+    /// Returns the value as the text stored in a [`Preset`], such as
+    /// `"1250.5"` rather than the display text `"1.25 kHz"`.
+    ///
+    /// The text must be parsed back to the same value, either by
+    /// [`parse_preset_text`] or as the value itself.
+    ///
+    /// [`Preset`]: ../preset/struct.Preset.html
+    /// [`parse_preset_text`]: #method.parse_preset_text
+    fn preset_text(&self, value: Self::Value) -> String {
+        value.to_string()
+    }
+
+    /// Parses text from a [`Preset`] that is not the value itself, such as
+    /// a label written by [`preset_text`]. Returns `None` to parse the text
+    /// as the value itself.
+    ///
+    /// [`Preset`]: ../preset/struct.Preset.html
+    /// [`preset_text`]: #method.preset_text
+    fn parse_preset_text(&self, _text: &str) -> Option<Self::Value> {
+        None
+    }
+}
+
+/// Returns `true` if `min` and `max` are finite and `max` is greater than
+/// `min`. This rejects `NaN`, so that a deserialized range never panics when
+/// it is created.
+#[cfg(feature = "serde")]
+fn valid_span(min: f32, max: f32) -> bool {
+    min.is_finite() && max.is_finite() && max > min
+}
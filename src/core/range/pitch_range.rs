@@ -0,0 +1,665 @@
+use super::{Range, CONTINUOUS_STEP};
+use crate::core::Normal;
+use crate::native::{text_marks, tick_marks};
+
+static NOTE_NAMES: [&str; 12] = [
+    "C", "C#", "D", "D#", "E", "F", "F#", "G", "G#", "A", "A#", "B",
+];
+
+/// How the values of a [`PitchRange`] are displayed
+///
+/// [`PitchRange`]: struct.PitchRange.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PitchUnit {
+    /// MIDI note numbers, displayed as note names such as `"C#4"`
+    /// (where note `60` is `"C4"`)
+    Note,
+    /// Offsets in semitones, displayed such as `"+7 st"`
+    Semitone,
+}
+
+/// A musical scale used to quantize a [`PitchRange`]
+///
+/// [`PitchRange`]: struct.PitchRange.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Scale {
+    root: u8,
+    degrees: [bool; 12],
+}
+
+impl Scale {
+    /// Creates a new `Scale`
+    ///
+    /// # Arguments
+    ///
+    /// * `root` - the pitch class of the root note, where `0` is C and
+    ///   `11` is B. For semitone offsets, this is the offset of the root
+    ///   from `0`.
+    /// * `intervals` - the notes in the scale in semitones above the root.
+    ///   The root itself is always in the scale.
+    pub fn new(root: u8, intervals: &[u8]) -> Self {
+        let mut degrees = [false; 12];
+        degrees[0] = true;
+        for interval in intervals {
+            degrees[usize::from(interval % 12)] = true;
+        }
+
+        Self {
+            root: root % 12,
+            degrees,
+        }
+    }
+
+    /// Creates a new `Scale` with all twelve notes
+    pub fn chromatic() -> Self {
+        Self {
+            root: 0,
+            degrees: [true; 12],
+        }
+    }
+
+    /// Creates a new major `Scale`
+    ///
+    /// * `root` - the pitch class of the root note, where `0` is C
+    pub fn major(root: u8) -> Self {
+        Self::new(root, &[0, 2, 4, 5, 7, 9, 11])
+    }
+
+    /// Creates a new natural minor `Scale`
+    ///
+    /// * `root` - the pitch class of the root note, where `0` is C
+    pub fn natural_minor(root: u8) -> Self {
+        Self::new(root, &[0, 2, 3, 5, 7, 8, 10])
+    }
+
+    /// Returns the pitch class of the root note
+    pub fn root(&self) -> u8 {
+        self.root
+    }
+
+    /// Returns `true` if the given note (or semitone offset) is in
+    /// this scale
+    pub fn contains(&self, note: i32) -> bool {
+        self.degrees[(note - i32::from(self.root)).rem_euclid(12) as usize]
+    }
+}
+
+/// How the values of a [`PitchRange`] are quantized
+///
+/// [`PitchRange`]: struct.PitchRange.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PitchQuantize {
+    /// Values are continuous
+    Off,
+    /// Values snap to the closest semitone
+    Semitone,
+    /// Values snap to the closest note in the [`Scale`]
+    ///
+    /// [`Scale`]: struct.Scale.html
+    Scale(Scale),
+}
+
+/// Where a [`PitchRange`] places its tick marks and text marks
+///
+/// [`PitchRange`]: struct.PitchRange.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PitchMarks {
+    /// A mark at every octave (every C for note numbers)
+    Octave,
+    /// A mark at every note allowed by the [`PitchQuantize`] of the range
+    ///
+    /// [`PitchQuantize`]: enum.PitchQuantize.html
+    Note,
+}
+
+/// A range that defines a continuous linear range of `f32` musical pitch
+/// values, either as MIDI note numbers or as semitone offsets.
+///
+/// The fractional part of a value is the fine tuning in cents (`0.01` is
+/// one cent). Values can optionally be quantized to semitones or to a
+/// [`Scale`], which [`unmap_to_value`] applies. A widget given a quantized
+/// range with its `snap` method, such as [`Knob::snap`], steps between the
+/// notes, and steps in whole cents during a fine drag.
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::{Normal, PitchQuantize, PitchRange, Range};
+///
+/// let range = PitchRange::notes(0.0, 127.0);
+/// assert_eq!(range.format_value(61.12), "C#4 +12c");
+///
+/// let transpose = PitchRange::semitones(-24.0, 24.0)
+///     .quantize(PitchQuantize::Semitone);
+/// assert_eq!(transpose.unmap_to_value(Normal::new(0.65)), 7.0);
+/// assert_eq!(transpose.format(Normal::new(0.65)), "+7 st");
+/// ```
+///
+/// [`Scale`]: struct.Scale.html
+/// [`unmap_to_value`]: #method.unmap_to_value
+/// [`Knob::snap`]: ../../native/knob/struct.Knob.html#method.snap
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(into = "PitchRangeDef", try_from = "PitchRangeDef")
+)]
+pub struct PitchRange {
+    min: f32,
+    max: f32,
+    span: f32,
+    span_recip: f32,
+    unit: PitchUnit,
+    quantize: PitchQuantize,
+}
+
+impl PitchRange {
+    /// Creates a new `PitchRange` of MIDI note numbers
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - the minimum note of the range (inclusive)
+    /// * `max` - the maximum note of the range (inclusive)
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `max` <= `min`
+    pub fn notes(min: f32, max: f32) -> Self {
+        Self::new(min, max, PitchUnit::Note)
+    }
+
+    /// Creates a new `PitchRange` of semitone offsets, such as for a
+    /// transpose parameter
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - the minimum offset of the range (inclusive)
+    /// * `max` - the maximum offset of the range (inclusive)
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `max` <= `min`
+    pub fn semitones(min: f32, max: f32) -> Self {
+        Self::new(min, max, PitchUnit::Semitone)
+    }
+
+    fn new(min: f32, max: f32, unit: PitchUnit) -> Self {
+        assert!(max > min, "max must be greater than min");
+
+        let span = max - min;
+
+        Self {
+            min,
+            max,
+            span,
+            span_recip: span.recip(),
+            unit,
+            quantize: PitchQuantize::Off,
+        }
+    }
+
+    /// Sets how values are quantized
+    pub fn quantize(mut self, quantize: PitchQuantize) -> Self {
+        self.quantize = quantize;
+        self
+    }
+
+    /// Returns how values are quantized
+    pub fn quantization(&self) -> PitchQuantize {
+        self.quantize
+    }
+
+    /// Returns how values are displayed
+    pub fn unit(&self) -> PitchUnit {
+        self.unit
+    }
+
+    /// Snaps a [`Normal`] to the closest whole cent, ignoring
+    /// quantization.
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn snap_fine(&self, normal: &mut Normal) {
+        *normal = self.snapped_fine(*normal);
+    }
+
+    /// Returns a [`tick_marks::Group`] with a tick mark at every octave or
+    /// at every note
+    ///
+    /// * `marks` - where to place the tick marks
+    /// * `tier` - the [`Tier`] of the tick marks
+    ///
+    /// [`tick_marks::Group`]: ../../native/tick_marks/struct.Group.html
+    /// [`Tier`]: ../../native/tick_marks/enum.Tier.html
+    pub fn tick_marks(
+        &self,
+        marks: PitchMarks,
+        tier: tick_marks::Tier,
+    ) -> tick_marks::Group {
+        self.mark_values(marks)
+            .into_iter()
+            .map(|value| (self.map_to_normal(value), tier))
+            .collect::<Vec<(Normal, tick_marks::Tier)>>()
+            .into()
+    }
+
+    /// Returns a [`text_marks::Group`] with the name of every octave or
+    /// every note at its position
+    ///
+    /// * `marks` - where to place the text marks
+    ///
+    /// [`text_marks::Group`]: ../../native/text_marks/struct.Group.html
+    pub fn text_marks(&self, marks: PitchMarks) -> text_marks::Group {
+        self.mark_values(marks)
+            .into_iter()
+            .map(|value| (self.map_to_normal(value), self.format_value(value)))
+            .collect::<Vec<(Normal, String)>>()
+            .into()
+    }
+
+    fn mark_values(&self, marks: PitchMarks) -> Vec<f32> {
+        let first = self.min.ceil() as i32;
+        let last = self.max.floor() as i32;
+
+        (first..=last)
+            .filter(|note| match marks {
+                PitchMarks::Octave => note.rem_euclid(12) == 0,
+                PitchMarks::Note => self.is_allowed(*note),
+            })
+            .map(|note| note as f32)
+            .collect()
+    }
+
+    fn is_allowed(&self, note: i32) -> bool {
+        match self.quantize {
+            PitchQuantize::Scale(scale) => scale.contains(note),
+            _ => true,
+        }
+    }
+
+    fn unmap_unquantized(&self, normal: Normal) -> f32 {
+        if normal == Normal::min() {
+            return self.min;
+        }
+        if normal == Normal::max() {
+            return self.max;
+        }
+
+        self.clamp(self.min + normal.scale(self.span))
+    }
+
+    fn quantized(&self, value: f32) -> f32 {
+        match self.quantize {
+            PitchQuantize::Off => value,
+            // The closest semitone may be outside of a range that does not
+            // start or end on a semitone.
+            PitchQuantize::Semitone => self.clamp(
+                value.round().max(self.min.ceil()).min(self.max.floor()),
+            ),
+            PitchQuantize::Scale(scale) => {
+                let note = value.round() as i32;
+
+                // Search outwards from the closest semitone for a note in
+                // the scale that is also in this range.
+                for distance in 0..12 {
+                    let mut candidates = [note - distance, note + distance];
+                    if value > note as f32 {
+                        candidates.swap(0, 1);
+                    }
+                    for candidate in candidates.iter() {
+                        let candidate_f = *candidate as f32;
+                        if scale.contains(*candidate)
+                            && candidate_f >= self.min
+                            && candidate_f <= self.max
+                        {
+                            return candidate_f;
+                        }
+                    }
+                }
+
+                self.clamp(value.round())
+            }
+        }
+    }
+
+    fn next_note(&self, from: f32, up: bool) -> Option<f32> {
+        let mut note = if up {
+            from.floor() as i32 + 1
+        } else {
+            from.ceil() as i32 - 1
+        };
+
+        while note as f32 >= self.min && note as f32 <= self.max {
+            if self.is_allowed(note) {
+                return Some(note as f32);
+            }
+            note += if up { 1 } else { -1 };
+        }
+
+        None
+    }
+}
+
+impl Range for PitchRange {
+    type Value = f32;
+
+    fn min(&self) -> f32 {
+        self.min
+    }
+
+    fn max(&self) -> f32 {
+        self.max
+    }
+
+    /// Returns middle C (`60.0`) for note numbers, or `0.0` for semitone
+    /// offsets (constrained to this range)
+    fn default_value(&self) -> f32 {
+        match self.unit {
+            PitchUnit::Note => self.clamp(60.0),
+            PitchUnit::Semitone => self.clamp(0.0),
+        }
+    }
+
+    fn map_to_normal(&self, value: f32) -> Normal {
+        let value = self.clamp(value);
+        ((value - self.min) * self.span_recip).into()
+    }
+
+    /// Returns the corresponding value from the supplied [`Normal`],
+    /// quantized to the closest allowed note.
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    fn unmap_to_value(&self, normal: Normal) -> f32 {
+        self.quantized(self.unmap_unquantized(normal))
+    }
+
+    fn is_quantized(&self) -> bool {
+        self.quantize != PitchQuantize::Off
+    }
+
+    /// Returns the [`Normal`] of the closest whole cent, ignoring
+    /// quantization.
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    fn snapped_fine(&self, normal: Normal) -> Normal {
+        let value = self.unmap_unquantized(normal);
+        self.map_to_normal((value * 100.0).round() / 100.0)
+    }
+
+    /// Returns the [`Normal`] that is `steps` steps away from `normal`.
+    ///
+    /// When quantized, a step is a single allowed note. Otherwise a step is
+    /// [`CONTINUOUS_STEP`].
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    /// [`CONTINUOUS_STEP`]: static.CONTINUOUS_STEP.html
+    fn step(&self, normal: Normal, steps: i32) -> Normal {
+        if self.quantize == PitchQuantize::Off {
+            return (normal.as_f32() + (steps as f32 * CONTINUOUS_STEP)).into();
+        }
+
+        let mut value = self.unmap_to_value(normal);
+        for _ in 0..steps.abs() {
+            match self.next_note(value, steps > 0) {
+                Some(note) => value = note,
+                None => break,
+            }
+        }
+
+        self.map_to_normal(value)
+    }
+
+    /// Returns the value as a note name such as `"C#4 +12c"`, or as a
+    /// semitone offset such as `"+7 st +12c"`
+    fn format_value(&self, value: f32) -> String {
+        let note = value.round() as i32;
+        let cents = ((value - note as f32) * 100.0).round() as i32;
+
+        let name = match self.unit {
+            PitchUnit::Note => format!(
+                "{}{}",
+                NOTE_NAMES[note.rem_euclid(12) as usize],
+                note.div_euclid(12) - 1
+            ),
+            PitchUnit::Semitone if note == 0 => String::from("0 st"),
+            PitchUnit::Semitone => format!("{:+} st", note),
+        };
+
+        if cents == 0 {
+            name
+        } else {
+            format!("{} {:+}c", name, cents)
+        }
+    }
+
+    /// Parses a note name such as `"C#4 +12c"` or `"Db4"`, or a semitone
+    /// offset such as `"+7 st"`. Plain numbers are also accepted.
+    fn parse_value(&self, text: &str) -> Option<f32> {
+        let mut text = text.trim();
+        let mut cents = 0.0;
+
+        if text.ends_with('c') || text.ends_with('C') {
+            if let Some(sign) = text.rfind(&['+', '-'][..]) {
+                if sign > 0 {
+                    cents = text[sign..text.len() - 1].trim().parse().ok()?;
+                    text = text[..sign].trim_end();
+                }
+            }
+        }
+
+        let value = match self.unit {
+            PitchUnit::Note => {
+                parse_note_name(text).or_else(|| text.parse().ok())?
+            }
+            PitchUnit::Semitone => {
+                let len = text.len();
+                if len >= 2 && text[len - 2..].eq_ignore_ascii_case("st") {
+                    text = text[..len - 2].trim_end();
+                }
+                text.parse().ok()?
+            }
+        };
+
+        if f32::is_finite(value) {
+            Some(value + (cents / 100.0))
+        } else {
+            None
+        }
+    }
+}
+
+/// Parses a note name such as `"C#4"` or `"Db4"` into a MIDI note number,
+/// where `"C4"` is `60`.
+fn parse_note_name(text: &str) -> Option<f32> {
+    let mut chars = text.chars();
+
+    let pitch_class: i32 = match chars.next()?.to_ascii_uppercase() {
+        'C' => 0,
+        'D' => 2,
+        'E' => 4,
+        'F' => 5,
+        'G' => 7,
+        'A' => 9,
+        'B' => 11,
+        _ => return None,
+    };
+
+    let rest = chars.as_str();
+    let (accidental, octave) = if let Some(octave) = rest.strip_prefix('#') {
+        (1, octave)
+    } else if let Some(octave) = rest.strip_prefix('b') {
+        (-1, octave)
+    } else {
+        (0, rest)
+    };
+
+    let octave: i32 = octave.trim().parse().ok()?;
+
+    Some((((octave + 1) * 12) + pitch_class + accidental) as f32)
+}
+
+impl Default for PitchRange {
+    fn default() -> Self {
+        PitchRange::notes(0.0, 127.0)
+    }
+}
+
+/// The serialized form of a [`PitchRange`], with its unit and
+/// quantization. The span is recomputed from `min` and `max`.
+///
+/// [`PitchRange`]: struct.PitchRange.html
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename = "PitchRange")]
+struct PitchRangeDef {
+    min: f32,
+    max: f32,
+    unit: PitchUnit,
+    quantize: PitchQuantize,
+}
+
+#[cfg(feature = "serde")]
+impl From<PitchRange> for PitchRangeDef {
+    fn from(range: PitchRange) -> Self {
+        Self {
+            min: range.min,
+            max: range.max,
+            unit: range.unit,
+            quantize: range.quantize,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl std::convert::TryFrom<PitchRangeDef> for PitchRange {
+    type Error = &'static str;
+
+    fn try_from(def: PitchRangeDef) -> Result<Self, Self::Error> {
+        if !super::valid_span(def.min, def.max) {
+            return Err("min and max must be finite with max greater than min");
+        }
+
+        Ok(PitchRange::new(def.min, def.max, def.unit).quantize(def.quantize))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scale_quantizes_to_closest_note_in_range() {
+        // C major: F# is not in the scale, so values around it move to the
+        // closer of F and G.
+        let range = PitchRange::notes(60.0, 72.0)
+            .quantize(PitchQuantize::Scale(Scale::major(0)));
+
+        assert_eq!(range.quantized(65.8), 65.0);
+        assert_eq!(range.quantized(66.2), 67.0);
+        assert_eq!(range.quantized(63.9), 64.0);
+
+        // The closest note in the scale is below the range, so the search
+        // moves up to the first note in it.
+        let range = PitchRange::notes(61.0, 72.0)
+            .quantize(PitchQuantize::Scale(Scale::major(0)));
+
+        assert_eq!(range.quantized(61.0), 62.0);
+    }
+
+    #[test]
+    fn semitone_quantizes_within_fractional_range() {
+        let range =
+            PitchRange::notes(0.3, 10.7).quantize(PitchQuantize::Semitone);
+
+        assert_eq!(range.unmap_to_value(Normal::min()), 1.0);
+        assert_eq!(range.unmap_to_value(Normal::max()), 10.0);
+        assert_eq!(range.quantized(4.4), 4.0);
+    }
+
+    #[test]
+    fn parses_note_names() {
+        let range = PitchRange::notes(0.0, 127.0);
+
+        assert_eq!(parse_note_name("C4"), Some(60.0));
+        assert_eq!(parse_note_name("c#4"), Some(61.0));
+        assert_eq!(parse_note_name("Db4"), Some(61.0));
+        assert_eq!(parse_note_name("A-1"), Some(9.0));
+        assert_eq!(parse_note_name("H4"), None);
+        assert_eq!(parse_note_name("C"), None);
+
+        assert_eq!(range.parse_value("C#4 +12c"), Some(61.12));
+        assert_eq!(range.parse_value("C4 -50c"), Some(59.5));
+        assert_eq!(range.parse_value("61.5"), Some(61.5));
+        assert_eq!(range.parse_value("X4"), None);
+    }
+
+    #[test]
+    fn parses_semitone_offsets() {
+        let range = PitchRange::semitones(-24.0, 24.0);
+
+        assert_eq!(range.parse_value("+7 st"), Some(7.0));
+        assert_eq!(range.parse_value("-12ST"), Some(-12.0));
+        assert_eq!(range.parse_value("0 st +5c"), Some(0.05));
+        assert_eq!(range.parse_value("C4"), None);
+    }
+
+    #[test]
+    fn next_note_skips_notes_outside_scale_and_range() {
+        let range = PitchRange::notes(60.0, 72.0)
+            .quantize(PitchQuantize::Scale(Scale::major(0)));
+
+        assert_eq!(range.next_note(64.0, true), Some(65.0));
+        assert_eq!(range.next_note(65.0, false), Some(64.0));
+        assert_eq!(range.next_note(64.5, true), Some(65.0));
+        assert_eq!(range.next_note(64.5, false), Some(64.0));
+        assert_eq!(range.next_note(72.0, true), None);
+        assert_eq!(range.next_note(60.0, false), None);
+    }
+
+    #[test]
+    fn steps_between_notes_when_quantized() {
+        let range = PitchRange::notes(60.0, 72.0)
+            .quantize(PitchQuantize::Scale(Scale::major(0)));
+
+        let e4 = range.map_to_normal(64.0);
+        assert_eq!(range.step(e4, 1), range.map_to_normal(65.0));
+        assert_eq!(range.step(e4, -2), range.map_to_normal(60.0));
+        assert_eq!(range.step(e4, 10), range.map_to_normal(72.0));
+    }
+
+    #[test]
+    fn snapped_fine_ignores_quantization() {
+        let range =
+            PitchRange::notes(60.0, 72.0).quantize(PitchQuantize::Semitone);
+
+        assert!(range.is_quantized());
+        assert!(!PitchRange::notes(60.0, 72.0).is_quantized());
+        assert_eq!(
+            range.snapped_fine(range.map_to_normal(64.123)),
+            range.map_to_normal(64.12)
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_recomputes_span() {
+        let range = PitchRange::semitones(-24.0, 24.0)
+            .quantize(PitchQuantize::Scale(Scale::natural_minor(9)));
+        let json = serde_json::to_string(&range).unwrap();
+        let loaded: PitchRange = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(loaded.unit(), PitchUnit::Semitone);
+        assert_eq!(loaded.quantization(), range.quantization());
+        assert_eq!(loaded.map_to_normal(7.0), range.map_to_normal(7.0));
+        assert_eq!(
+            loaded.unmap_to_value(Normal::new(0.6)),
+            range.unmap_to_value(Normal::new(0.6))
+        );
+
+        let invalid = json.replace("\"max\":24.0", "\"max\":-30.0");
+        assert!(serde_json::from_str::<PitchRange>(&invalid).is_err());
+    }
+}
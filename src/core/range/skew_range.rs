@@ -0,0 +1,304 @@
+use super::Range;
+use crate::core::{Normal, ValueFormatter};
+
+/// A range that maps a continuous range of `f32` values to a [`Normal`]
+/// along a power curve.
+///
+/// This is useful for parameters such as envelope times, filter resonance,
+/// and drive amounts, where the smaller values need more resolution than the
+/// larger ones. Values are displayed with 2 decimal places by default.
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::{Normal, Range, SkewRange};
+///
+/// // An attack time from 0.1 ms to 10 seconds with 500 ms at the center.
+/// let range = SkewRange::with_center(0.1, 10_000.0, 500.0);
+///
+/// assert!((range.unmap_to_value(Normal::center()) - 500.0).abs() < 0.01);
+/// assert_eq!(range.unmap_to_value(Normal::min()), 0.1);
+/// assert_eq!(range.unmap_to_value(Normal::max()), 10_000.0);
+/// ```
+///
+/// [`Normal`]: ../struct.Normal.html
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(into = "SkewRangeDef", try_from = "SkewRangeDef")
+)]
+pub struct SkewRange {
+    min: f32,
+    max: f32,
+    span: f64,
+    span_recip: f64,
+    skew: f64,
+    skew_recip: f64,
+    formatter: ValueFormatter,
+}
+
+impl SkewRange {
+    /// Creates a new `SkewRange`
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - the minimum of the range (inclusive)
+    /// * `max` - the maximum of the range (inclusive)
+    /// * `skew` - the exponent of the curve. `1.0` is linear, values less
+    ///   than `1.0` give more of the range to smaller values, and values
+    ///   greater than `1.0` give more of the range to larger values.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if
+    /// * `max` <= `min`
+    /// * `skew` <= `0.0`
+    pub fn new(min: f32, max: f32, skew: f32) -> Self {
+        assert!(max > min, "max must be greater than min");
+        assert!(skew > 0.0, "skew must be greater than 0.0");
+
+        let span = f64::from(max) - f64::from(min);
+        let skew = f64::from(skew);
+
+        Self {
+            min,
+            max,
+            span,
+            span_recip: span.recip(),
+            skew,
+            skew_recip: skew.recip(),
+            formatter: ValueFormatter::default(),
+        }
+    }
+
+    /// Creates a new `SkewRange` where the center of the range
+    /// (a [`Normal`] of `0.5`) maps to `center`.
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - the minimum of the range (inclusive)
+    /// * `max` - the maximum of the range (inclusive)
+    /// * `center` - the value at the center of the range
+    ///
+    /// # Panics
+    ///
+    /// This will panic if
+    /// * `max` <= `min`
+    /// * `center` is not between `min` and `max` (exclusive)
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn with_center(min: f32, max: f32, center: f32) -> Self {
+        assert!(max > min, "max must be greater than min");
+        assert!(
+            center > min && center < max,
+            "center must be between min and max"
+        );
+
+        let proportion = (f64::from(center) - f64::from(min))
+            / (f64::from(max) - f64::from(min));
+
+        let skew = 0.5f64.ln() / proportion.ln();
+
+        Self::new(min, max, skew as f32)
+    }
+
+    /// Returns the exponent of the curve
+    pub fn skew(&self) -> f32 {
+        self.skew as f32
+    }
+
+    /// Sets the [`ValueFormatter`] used to display and parse values
+    ///
+    /// [`ValueFormatter`]: ../format/struct.ValueFormatter.html
+    pub fn formatter(mut self, formatter: ValueFormatter) -> Self {
+        self.formatter = formatter;
+        self
+    }
+}
+
+impl Range for SkewRange {
+    type Value = f32;
+
+    fn min(&self) -> f32 {
+        self.min
+    }
+
+    fn max(&self) -> f32 {
+        self.max
+    }
+
+    /// Returns the minimum of the range
+    fn default_value(&self) -> f32 {
+        self.min
+    }
+
+    /// Returns the corresponding [`Normal`] from the supplied value
+    ///
+    /// The curve is computed with `f64` precision, so values round-trip
+    /// through [`unmap_to_value`] to within the precision of an `f32`.
+    /// The minimum and maximum always round-trip exactly.
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    /// [`unmap_to_value`]: #method.unmap_to_value
+    fn map_to_normal(&self, value: f32) -> Normal {
+        let value = self.clamp(value);
+        if value == self.min {
+            return Normal::min();
+        }
+        if value == self.max {
+            return Normal::max();
+        }
+
+        let proportion =
+            (f64::from(value) - f64::from(self.min)) * self.span_recip;
+
+        (proportion.powf(self.skew) as f32).into()
+    }
+
+    fn unmap_to_value(&self, normal: Normal) -> f32 {
+        if normal == Normal::min() {
+            return self.min;
+        }
+        if normal == Normal::max() {
+            return self.max;
+        }
+
+        let proportion = f64::from(normal.as_f32()).powf(self.skew_recip);
+
+        self.clamp((f64::from(self.min) + (proportion * self.span)) as f32)
+    }
+
+    fn format_value(&self, value: f32) -> String {
+        self.formatter.format(value)
+    }
+
+    fn parse_value(&self, text: &str) -> Option<f32> {
+        self.formatter.parse(text)
+    }
+}
+
+impl Default for SkewRange {
+    fn default() -> Self {
+        SkewRange::new(0.0, 1.0, 1.0)
+    }
+}
+
+/// The serialized form of a [`SkewRange`]. The span and the reciprocal of
+/// the skew are recomputed as `f64`.
+///
+/// [`SkewRange`]: struct.SkewRange.html
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename = "SkewRange")]
+struct SkewRangeDef {
+    min: f32,
+    max: f32,
+    skew: f32,
+    formatter: ValueFormatter,
+}
+
+#[cfg(feature = "serde")]
+impl From<SkewRange> for SkewRangeDef {
+    fn from(range: SkewRange) -> Self {
+        Self {
+            min: range.min,
+            max: range.max,
+            skew: range.skew(),
+            formatter: range.formatter,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl std::convert::TryFrom<SkewRangeDef> for SkewRange {
+    type Error = &'static str;
+
+    fn try_from(def: SkewRangeDef) -> Result<Self, Self::Error> {
+        if !super::valid_span(def.min, def.max) {
+            return Err("min and max must be finite with max greater than min");
+        }
+        if !def.skew.is_finite() || def.skew <= 0.0 {
+            return Err("skew must be finite and greater than 0.0");
+        }
+
+        Ok(SkewRange::new(def.min, def.max, def.skew).formatter(def.formatter))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn linear_skew_maps_linearly() {
+        let range = SkewRange::new(-1.0, 3.0, 1.0);
+
+        assert_eq!(range.unmap_to_value(Normal::new(0.25)), 0.0);
+        assert_eq!(range.map_to_normal(1.0), Normal::center());
+    }
+
+    #[test]
+    fn small_skew_gives_more_range_to_small_values() {
+        let range = SkewRange::new(0.0, 100.0, 0.5);
+
+        // A quarter of the values fill half of the range.
+        assert_eq!(range.map_to_normal(25.0), Normal::center());
+        assert_eq!(range.unmap_to_value(Normal::center()), 25.0);
+    }
+
+    #[test]
+    fn values_round_trip() {
+        let range = SkewRange::with_center(0.1, 10_000.0, 500.0);
+
+        assert_eq!(range.map_to_normal(0.1), Normal::min());
+        assert_eq!(range.map_to_normal(10_000.0), Normal::max());
+        assert_eq!(range.map_to_normal(-5.0), Normal::min());
+
+        for value in &[0.5, 20.0, 500.0, 1234.5, 9999.0] {
+            let normal = range.map_to_normal(*value);
+            let round_trip = range.unmap_to_value(normal);
+
+            assert!(
+                (round_trip - value).abs() <= value * 1.0e-6,
+                "{} became {}",
+                value,
+                round_trip
+            );
+        }
+    }
+
+    #[test]
+    fn with_center_maps_center() {
+        let range = SkewRange::with_center(0.0, 1.0, 0.25);
+
+        assert!((range.skew() - 0.5).abs() < 1.0e-6);
+        assert!((range.unmap_to_value(Normal::center()) - 0.25).abs() < 1.0e-6);
+    }
+
+    #[test]
+    #[should_panic(expected = "skew must be greater than 0.0")]
+    fn new_rejects_zero_skew() {
+        let _ = SkewRange::new(0.0, 1.0, 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "center must be between min and max")]
+    fn with_center_rejects_center_outside_range() {
+        let _ = SkewRange::with_center(0.0, 1.0, 1.0);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_recomputes_curve() {
+        let range = SkewRange::with_center(0.1, 10_000.0, 500.0);
+        let json = serde_json::to_string(&range).unwrap();
+        let loaded: SkewRange = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(loaded.skew(), range.skew());
+        assert_eq!(loaded.map_to_normal(500.0), range.map_to_normal(500.0));
+
+        let invalid = json.replace("\"skew\":", "\"skew\":-");
+        assert!(serde_json::from_str::<SkewRange>(&invalid).is_err());
+    }
+}
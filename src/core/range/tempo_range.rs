@@ -0,0 +1,557 @@
+use super::Range;
+use crate::core::Normal;
+use crate::native::{text_marks, tick_marks};
+
+use std::cmp::Ordering;
+use std::fmt;
+
+/// The rhythmic variant of a [`NoteDivision`]
+///
+/// [`NoteDivision`]: struct.NoteDivision.html
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DivisionKind {
+    /// A plain division
+    Straight,
+    /// A division 1.5 times as long as a straight one, labeled with a `D`
+    Dotted,
+    /// A division 2/3 as long as a straight one, labeled with a `T`
+    Triplet,
+}
+
+/// A tempo-synced length of time, such as a `1/8` note or `2` bars
+///
+/// Lengths are measured in whole notes, where a whole note is one bar
+/// of 4/4. Divisions are compared by their exact length, so `2/8` equals
+/// `1/4`, and `1/4D` equals `3/8`.
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::NoteDivision;
+///
+/// let division = NoteDivision::triplet(1, 8);
+///
+/// assert_eq!(division.to_string(), "1/8T");
+/// assert!((division.seconds(120.0) - 0.16667).abs() < 0.0001);
+/// ```
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "NoteDivisionDef"))]
+pub struct NoteDivision {
+    numerator: u32,
+    denominator: u32,
+    kind: DivisionKind,
+}
+
+impl NoteDivision {
+    /// Creates a new `NoteDivision`
+    ///
+    /// # Arguments
+    ///
+    /// * `numerator` - the number of notes
+    /// * `denominator` - the note value, where `1` is a whole note (one bar)
+    ///   and `8` is an eighth note
+    /// * `kind` - the [`DivisionKind`]
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `numerator` or `denominator` is `0`
+    ///
+    /// [`DivisionKind`]: enum.DivisionKind.html
+    pub fn new(numerator: u32, denominator: u32, kind: DivisionKind) -> Self {
+        assert!(numerator > 0, "numerator must be greater than 0");
+        assert!(denominator > 0, "denominator must be greater than 0");
+
+        Self {
+            numerator,
+            denominator,
+            kind,
+        }
+    }
+
+    /// Creates a new straight `NoteDivision`
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `numerator` or `denominator` is `0`
+    pub fn straight(numerator: u32, denominator: u32) -> Self {
+        Self::new(numerator, denominator, DivisionKind::Straight)
+    }
+
+    /// Creates a new dotted `NoteDivision`
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `numerator` or `denominator` is `0`
+    pub fn dotted(numerator: u32, denominator: u32) -> Self {
+        Self::new(numerator, denominator, DivisionKind::Dotted)
+    }
+
+    /// Creates a new triplet `NoteDivision`
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `numerator` or `denominator` is `0`
+    pub fn triplet(numerator: u32, denominator: u32) -> Self {
+        Self::new(numerator, denominator, DivisionKind::Triplet)
+    }
+
+    /// Returns the number of notes
+    pub fn numerator(&self) -> u32 {
+        self.numerator
+    }
+
+    /// Returns the note value, where `1` is a whole note
+    pub fn denominator(&self) -> u32 {
+        self.denominator
+    }
+
+    /// Returns the [`DivisionKind`]
+    ///
+    /// [`DivisionKind`]: enum.DivisionKind.html
+    pub fn kind(&self) -> DivisionKind {
+        self.kind
+    }
+
+    /// Returns the length of this division in whole notes (bars of 4/4)
+    pub fn whole_notes(&self) -> f32 {
+        let straight = self.numerator as f32 / self.denominator as f32;
+
+        match self.kind {
+            DivisionKind::Straight => straight,
+            DivisionKind::Dotted => straight * 1.5,
+            DivisionKind::Triplet => straight * 2.0 / 3.0,
+        }
+    }
+
+    /// Returns the length of this division in beats (quarter notes)
+    pub fn beats(&self) -> f32 {
+        self.whole_notes() * 4.0
+    }
+
+    /// Returns the length of this division in seconds at the given tempo
+    ///
+    /// * `bpm` - the tempo in quarter notes per minute
+    pub fn seconds(&self, bpm: f32) -> f32 {
+        self.beats() * 60.0 / bpm
+    }
+
+    /// Returns the rate of this division in Hz at the given tempo, such as
+    /// for an LFO that completes one cycle per division
+    ///
+    /// * `bpm` - the tempo in quarter notes per minute
+    pub fn hz(&self, bpm: f32) -> f32 {
+        self.seconds(bpm).recip()
+    }
+
+    /// Returns the exact length of this division in whole notes, as a
+    /// numerator and a denominator
+    fn ratio(&self) -> (u128, u128) {
+        let numerator = u128::from(self.numerator);
+        let denominator = u128::from(self.denominator);
+
+        match self.kind {
+            DivisionKind::Straight => (numerator, denominator),
+            DivisionKind::Dotted => (numerator * 3, denominator * 2),
+            DivisionKind::Triplet => (numerator * 2, denominator * 3),
+        }
+    }
+}
+
+impl PartialEq for NoteDivision {
+    /// Divisions are equal if they have the same length
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for NoteDivision {}
+
+impl PartialOrd for NoteDivision {
+    /// Divisions are ordered by their length
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for NoteDivision {
+    /// Divisions are ordered by their length
+    fn cmp(&self, other: &Self) -> Ordering {
+        let (numerator, denominator) = self.ratio();
+        let (other_numerator, other_denominator) = other.ratio();
+
+        (numerator * other_denominator).cmp(&(other_numerator * denominator))
+    }
+}
+
+impl fmt::Display for NoteDivision {
+    /// Formats the division as a label such as `"1/8"`, `"1/8D"`, `"1/8T"`,
+    /// or `"2/1"` for two bars
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.numerator, self.denominator)?;
+
+        match self.kind {
+            DivisionKind::Straight => Ok(()),
+            DivisionKind::Dotted => write!(f, "D"),
+            DivisionKind::Triplet => write!(f, "T"),
+        }
+    }
+}
+
+impl std::str::FromStr for NoteDivision {
+    type Err = &'static str;
+
+    /// Parses a label such as `"1/8"`, `"1/8D"`, `"1/8T"`, or `"2/1"`
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let text = text.trim();
+
+        let (text, kind) = match text.chars().last() {
+            Some('D') | Some('d') => {
+                (&text[..text.len() - 1], DivisionKind::Dotted)
+            }
+            Some('T') | Some('t') => {
+                (&text[..text.len() - 1], DivisionKind::Triplet)
+            }
+            _ => (text, DivisionKind::Straight),
+        };
+
+        let mut parts = text.splitn(2, '/');
+        let numerator: u32 = parts
+            .next()
+            .and_then(|part| part.trim().parse().ok())
+            .ok_or("expected a numerator")?;
+        let denominator: u32 = parts
+            .next()
+            .and_then(|part| part.trim().parse().ok())
+            .ok_or("expected a denominator")?;
+
+        if numerator == 0 || denominator == 0 {
+            return Err("numerator and denominator must be greater than 0");
+        }
+
+        Ok(NoteDivision::new(numerator, denominator, kind))
+    }
+}
+
+/// The serialized form of a [`NoteDivision`], checked when deserializing.
+///
+/// [`NoteDivision`]: struct.NoteDivision.html
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(rename = "NoteDivision")]
+struct NoteDivisionDef {
+    numerator: u32,
+    denominator: u32,
+    kind: DivisionKind,
+}
+
+#[cfg(feature = "serde")]
+impl std::convert::TryFrom<NoteDivisionDef> for NoteDivision {
+    type Error = &'static str;
+
+    fn try_from(def: NoteDivisionDef) -> Result<Self, Self::Error> {
+        if def.numerator == 0 {
+            return Err("numerator must be greater than 0");
+        }
+        if def.denominator == 0 {
+            return Err("denominator must be greater than 0");
+        }
+
+        Ok(NoteDivision::new(def.numerator, def.denominator, def.kind))
+    }
+}
+
+/// A range that maps a [`Normal`] onto an ordered table of tempo-synced
+/// [`NoteDivision`]s, such as for a synced delay time or LFO rate.
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::{Normal, NoteDivision, Range, TempoRange};
+///
+/// // From 1/64 to 8 bars, with dotted and triplet variants.
+/// let range = TempoRange::default();
+///
+/// assert_eq!(range.min(), NoteDivision::straight(1, 64));
+/// assert_eq!(range.max(), NoteDivision::straight(8, 1));
+///
+/// let normal = range.map_to_normal(NoteDivision::dotted(1, 8));
+/// assert_eq!(range.format(normal), "1/8D");
+/// ```
+///
+/// [`Normal`]: ../struct.Normal.html
+/// [`NoteDivision`]: struct.NoteDivision.html
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(into = "TempoRangeDef", try_from = "TempoRangeDef")
+)]
+pub struct TempoRange {
+    divisions: Vec<NoteDivision>,
+    span: f32,
+    span_recip: f32,
+}
+
+impl TempoRange {
+    /// Creates a new `TempoRange` from the standard table of divisions,
+    /// including dotted and triplet variants.
+    ///
+    /// The standard table spans `1/64` to `8/1` in powers of two.
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - the shortest division of the range (inclusive)
+    /// * `max` - the longest division of the range (inclusive)
+    ///
+    /// # Panics
+    ///
+    /// This will panic if there are less than two divisions between `min`
+    /// and `max`
+    pub fn new(min: NoteDivision, max: NoteDivision) -> Self {
+        Self::from_table(min, max, true)
+    }
+
+    /// Creates a new `TempoRange` from the standard table of divisions,
+    /// without dotted and triplet variants.
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - the shortest division of the range (inclusive)
+    /// * `max` - the longest division of the range (inclusive)
+    ///
+    /// # Panics
+    ///
+    /// This will panic if there are less than two divisions between `min`
+    /// and `max`
+    pub fn straight(min: NoteDivision, max: NoteDivision) -> Self {
+        Self::from_table(min, max, false)
+    }
+
+    /// Creates a new `TempoRange` from a custom table of divisions. The
+    /// divisions will be sorted from shortest to longest.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if there are less than two divisions
+    pub fn with_divisions<I>(divisions: I) -> Self
+    where
+        I: IntoIterator<Item = NoteDivision>,
+    {
+        let mut divisions: Vec<NoteDivision> = divisions.into_iter().collect();
+
+        assert!(divisions.len() >= 2, "there must be at least two divisions");
+
+        divisions.sort();
+
+        let span = (divisions.len() - 1) as f32;
+
+        Self {
+            divisions,
+            span,
+            span_recip: span.recip(),
+        }
+    }
+
+    fn from_table(
+        min: NoteDivision,
+        max: NoteDivision,
+        variants: bool,
+    ) -> Self {
+        let mut divisions = Vec::new();
+
+        for &(numerator, denominator) in [
+            (1, 64),
+            (1, 32),
+            (1, 16),
+            (1, 8),
+            (1, 4),
+            (1, 2),
+            (1, 1),
+            (2, 1),
+            (4, 1),
+            (8, 1),
+        ]
+        .iter()
+        {
+            divisions.push(NoteDivision::straight(numerator, denominator));
+            if variants {
+                divisions.push(NoteDivision::dotted(numerator, denominator));
+                divisions.push(NoteDivision::triplet(numerator, denominator));
+            }
+        }
+
+        Self::with_divisions(
+            divisions
+                .into_iter()
+                .filter(|division| *division >= min && *division <= max),
+        )
+    }
+
+    /// Returns all the divisions in this range, from shortest to longest
+    pub fn divisions(&self) -> &[NoteDivision] {
+        &self.divisions
+    }
+
+    /// Returns a [`tick_marks::Group`] with a tick mark at every division
+    ///
+    /// * `tier` - the [`Tier`] of the tick marks
+    ///
+    /// [`tick_marks::Group`]: ../../native/tick_marks/struct.Group.html
+    /// [`Tier`]: ../../native/tick_marks/enum.Tier.html
+    pub fn tick_marks(&self, tier: tick_marks::Tier) -> tick_marks::Group {
+        tick_marks::Group::evenly_spaced(self.divisions.len(), tier)
+    }
+
+    /// Returns a [`text_marks::Group`] with the label of every division
+    /// (such as `"1/8T"`) at its position
+    ///
+    /// [`text_marks::Group`]: ../../native/text_marks/struct.Group.html
+    pub fn text_marks(&self) -> text_marks::Group {
+        self.divisions
+            .iter()
+            .enumerate()
+            .map(|(i, division)| {
+                (
+                    Normal::from(i as f32 * self.span_recip),
+                    division.to_string(),
+                )
+            })
+            .collect::<Vec<(Normal, String)>>()
+            .into()
+    }
+}
+
+impl Range for TempoRange {
+    type Value = NoteDivision;
+
+    fn min(&self) -> NoteDivision {
+        self.divisions[0]
+    }
+
+    fn max(&self) -> NoteDivision {
+        self.divisions[self.divisions.len() - 1]
+    }
+
+    /// Returns the first division that is at least a quarter note long,
+    /// or the longest division
+    fn default_value(&self) -> NoteDivision {
+        let quarter = NoteDivision::straight(1, 4);
+
+        self.divisions
+            .iter()
+            .find(|division| **division >= quarter)
+            .copied()
+            .unwrap_or_else(|| self.max())
+    }
+
+    fn discrete_len(&self) -> Option<usize> {
+        Some(self.divisions.len())
+    }
+
+    /// Returns the corresponding [`Normal`] of the division in this range
+    /// with the closest length to `value`
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    fn map_to_normal(&self, value: NoteDivision) -> Normal {
+        let length = value.whole_notes().ln();
+
+        let mut closest = 0;
+        let mut closest_distance = f32::MAX;
+        for (i, division) in self.divisions.iter().enumerate() {
+            let distance = (division.whole_notes().ln() - length).abs();
+            if distance < closest_distance {
+                closest = i;
+                closest_distance = distance;
+            }
+        }
+
+        (closest as f32 * self.span_recip).into()
+    }
+
+    fn unmap_to_value(&self, normal: Normal) -> NoteDivision {
+        self.divisions[(normal.as_f32() * self.span).round() as usize]
+    }
+
+    /// Parses a label such as `"1/8"`, `"1/8D"`, or `"1/8T"`, ignoring
+    /// case
+    fn parse_value(&self, text: &str) -> Option<NoteDivision> {
+        text.parse().ok()
+    }
+}
+
+impl Default for TempoRange {
+    fn default() -> Self {
+        TempoRange::new(
+            NoteDivision::straight(1, 64),
+            NoteDivision::straight(8, 1),
+        )
+    }
+}
+
+/// The serialized form of a [`TempoRange`], which stores only the table
+/// of divisions. The span between divisions is recomputed from its
+/// length.
+///
+/// [`TempoRange`]: struct.TempoRange.html
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename = "TempoRange")]
+struct TempoRangeDef {
+    divisions: Vec<NoteDivision>,
+}
+
+#[cfg(feature = "serde")]
+impl From<TempoRange> for TempoRangeDef {
+    fn from(range: TempoRange) -> Self {
+        Self {
+            divisions: range.divisions,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl std::convert::TryFrom<TempoRangeDef> for TempoRange {
+    type Error = &'static str;
+
+    fn try_from(def: TempoRangeDef) -> Result<Self, Self::Error> {
+        if def.divisions.len() < 2 {
+            return Err("there must be at least two divisions");
+        }
+
+        Ok(TempoRange::with_divisions(def.divisions))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn divisions_of_equal_length_are_equal() {
+        assert_eq!(NoteDivision::straight(2, 8), NoteDivision::straight(1, 4));
+        assert_eq!(NoteDivision::dotted(1, 4), NoteDivision::straight(3, 8));
+        assert_eq!(NoteDivision::triplet(3, 8), NoteDivision::straight(1, 4));
+        assert_ne!(NoteDivision::triplet(1, 4), NoteDivision::straight(1, 4));
+    }
+
+    #[test]
+    fn equality_agrees_with_order() {
+        let a = NoteDivision::straight(2, 8);
+        let b = NoteDivision::straight(1, 4);
+
+        assert_eq!(a.partial_cmp(&b), Some(Ordering::Equal));
+        assert_eq!(a == b, a.cmp(&b) == Ordering::Equal);
+        assert!(NoteDivision::triplet(1, 4) < NoteDivision::straight(1, 4));
+        assert!(NoteDivision::dotted(1, 4) > NoteDivision::straight(1, 4));
+    }
+
+    #[test]
+    fn large_divisions_do_not_overflow() {
+        let a = NoteDivision::dotted(u32::MAX, 1);
+        let b = NoteDivision::triplet(u32::MAX, 1);
+
+        assert!(a > b);
+    }
+}
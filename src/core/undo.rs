@@ -0,0 +1,315 @@
+//! An undo/redo history of parameter edits
+
+use std::collections::VecDeque;
+
+use super::Normal;
+
+/// A change of a single parameter in an [`UndoHistory`]
+///
+/// [`UndoHistory`]: struct.UndoHistory.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct Change {
+    id: usize,
+    old: Normal,
+    new: Normal,
+}
+
+/// An undo/redo history of parameter edits.
+///
+/// Each step of the history is a group of changes that is undone as a
+/// whole. Changes recorded between [`begin_group`] and [`end_group`] are
+/// merged into a single step, keeping only the first old value and the
+/// last new value of each parameter. Call [`begin_group`] from the
+/// `on_grab` message of a widget and [`end_group`] from its `on_release`
+/// message, so that a whole drag gesture is undone at once. Groups are
+/// also useful for changes that happen together, such as the two axes of
+/// an `XYPad` or loading a preset.
+///
+/// Changes recorded outside of a group are each their own step.
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::{Normal, UndoHistory};
+///
+/// let mut history = UndoHistory::new(100);
+///
+/// // A drag gesture on parameter 0.
+/// history.begin_group();
+/// history.record(0, Normal::new(0.5), Normal::new(0.6));
+/// history.record(0, Normal::new(0.6), Normal::new(0.7));
+/// history.end_group();
+///
+/// assert_eq!(history.undo(), Some(vec![(0, Normal::new(0.5))]));
+/// assert_eq!(history.redo(), Some(vec![(0, Normal::new(0.7))]));
+/// ```
+///
+/// [`begin_group`]: #method.begin_group
+/// [`end_group`]: #method.end_group
+#[derive(Debug, Clone)]
+pub struct UndoHistory {
+    undo_steps: VecDeque<Vec<Change>>,
+    redo_steps: Vec<Vec<Change>>,
+    group: Vec<Change>,
+    group_depth: usize,
+    max_len: usize,
+}
+
+impl UndoHistory {
+    /// Creates a new empty `UndoHistory`
+    ///
+    /// * `max_len` - the maximum number of steps that can be undone. The
+    ///   oldest steps are forgotten first.
+    pub fn new(max_len: usize) -> Self {
+        Self {
+            undo_steps: VecDeque::new(),
+            redo_steps: Vec::new(),
+            group: Vec::new(),
+            group_depth: 0,
+            max_len,
+        }
+    }
+
+    /// Begins a group of changes that are undone as a single step, such
+    /// as when a widget is grabbed.
+    ///
+    /// Groups may be nested, in which case the step ends with the
+    /// outermost group.
+    pub fn begin_group(&mut self) {
+        self.group_depth += 1;
+    }
+
+    /// Ends a group of changes started by [`begin_group`], such as when a
+    /// widget is released. This does nothing if no group was begun.
+    ///
+    /// [`begin_group`]: #method.begin_group
+    pub fn end_group(&mut self) {
+        if self.group_depth == 0 {
+            return;
+        }
+
+        self.group_depth -= 1;
+        if self.group_depth == 0 {
+            let group = std::mem::take(&mut self.group);
+            self.push_step(group);
+        }
+    }
+
+    /// Returns `true` if a group begun by [`begin_group`] has not ended
+    ///
+    /// [`begin_group`]: #method.begin_group
+    pub fn is_grouping(&self) -> bool {
+        self.group_depth > 0
+    }
+
+    /// Records a change to a parameter. A new step clears the steps that
+    /// can be redone.
+    ///
+    /// * `id` - the id of the parameter
+    /// * `old` - the value of the parameter before the change
+    /// * `new` - the value of the parameter after the change
+    pub fn record(&mut self, id: usize, old: Normal, new: Normal) {
+        if self.group_depth == 0 {
+            self.push_step(vec![Change { id, old, new }]);
+            return;
+        }
+
+        match self.group.iter_mut().find(|change| change.id == id) {
+            Some(change) => change.new = new,
+            None => self.group.push(Change { id, old, new }),
+        }
+    }
+
+    /// Undoes the last step, and returns the `(id, value)` of each
+    /// parameter to restore, or `None` if there is nothing to undo.
+    ///
+    /// Nothing is undone while a group is open, such as during a drag, so
+    /// that the rest of the gesture is still recorded as a single step.
+    pub fn undo(&mut self) -> Option<Vec<(usize, Normal)>> {
+        if self.is_grouping() {
+            return None;
+        }
+
+        let step = self.undo_steps.pop_back()?;
+        let values = step
+            .iter()
+            .rev()
+            .map(|change| (change.id, change.old))
+            .collect();
+        self.redo_steps.push(step);
+
+        Some(values)
+    }
+
+    /// Redoes the last undone step, and returns the `(id, value)` of each
+    /// parameter to apply, or `None` if there is nothing to redo.
+    ///
+    /// Nothing is redone while a group is open.
+    pub fn redo(&mut self) -> Option<Vec<(usize, Normal)>> {
+        if self.is_grouping() {
+            return None;
+        }
+
+        let step = self.redo_steps.pop()?;
+        let values =
+            step.iter().map(|change| (change.id, change.new)).collect();
+        self.undo_steps.push_back(step);
+
+        Some(values)
+    }
+
+    /// Returns `true` if there is a step that can be undone now
+    pub fn can_undo(&self) -> bool {
+        !self.is_grouping() && !self.undo_steps.is_empty()
+    }
+
+    /// Returns `true` if there is a step that can be redone now
+    pub fn can_redo(&self) -> bool {
+        !self.is_grouping() && !self.redo_steps.is_empty()
+    }
+
+    /// Forgets every step, such as when a new document is opened
+    pub fn clear(&mut self) {
+        self.undo_steps.clear();
+        self.redo_steps.clear();
+        self.group.clear();
+        self.group_depth = 0;
+    }
+
+    fn push_step(&mut self, mut step: Vec<Change>) {
+        // Changes that end where they started, such as a drag that
+        // returns to its starting point, are not worth undoing.
+        step.retain(|change| change.old != change.new);
+        if step.is_empty() || self.max_len == 0 {
+            return;
+        }
+
+        self.redo_steps.clear();
+
+        if self.undo_steps.len() == self.max_len {
+            let _ = self.undo_steps.pop_front();
+        }
+        self.undo_steps.push_back(step);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn n(value: f32) -> Normal {
+        Normal::new(value)
+    }
+
+    #[test]
+    fn group_keeps_first_old_and_last_new_value() {
+        let mut history = UndoHistory::new(10);
+        history.begin_group();
+        history.record(0, n(0.1), n(0.2));
+        history.record(1, n(0.5), n(0.6));
+        history.record(0, n(0.2), n(0.3));
+        history.end_group();
+
+        assert_eq!(history.undo(), Some(vec![(1, n(0.5)), (0, n(0.1))]));
+        assert_eq!(history.undo(), None);
+        assert_eq!(history.redo(), Some(vec![(0, n(0.3)), (1, n(0.6))]));
+    }
+
+    #[test]
+    fn nested_groups_end_with_the_outermost_group() {
+        let mut history = UndoHistory::new(10);
+        history.begin_group();
+        history.record(0, n(0.1), n(0.2));
+        history.begin_group();
+        history.record(1, n(0.5), n(0.6));
+        history.end_group();
+
+        assert!(history.is_grouping());
+        history.end_group();
+        assert!(!history.is_grouping());
+
+        assert_eq!(history.undo(), Some(vec![(1, n(0.5)), (0, n(0.1))]));
+        assert!(!history.can_undo());
+    }
+
+    #[test]
+    fn changes_outside_a_group_are_separate_steps() {
+        let mut history = UndoHistory::new(10);
+        history.record(0, n(0.1), n(0.2));
+        history.record(0, n(0.2), n(0.3));
+
+        assert_eq!(history.undo(), Some(vec![(0, n(0.2))]));
+        assert_eq!(history.undo(), Some(vec![(0, n(0.1))]));
+    }
+
+    #[test]
+    fn undo_waits_for_an_open_group() {
+        let mut history = UndoHistory::new(10);
+        history.record(1, n(0.5), n(0.6));
+        history.begin_group();
+        history.record(0, n(0.1), n(0.2));
+
+        assert!(!history.can_undo());
+        assert_eq!(history.undo(), None);
+        assert_eq!(history.redo(), None);
+        assert!(history.is_grouping());
+
+        // The rest of the drag is still part of the same step.
+        history.record(0, n(0.2), n(0.3));
+        history.end_group();
+
+        assert_eq!(history.undo(), Some(vec![(0, n(0.1))]));
+        assert_eq!(history.undo(), Some(vec![(1, n(0.5))]));
+    }
+
+    #[test]
+    fn group_that_returns_to_its_start_is_not_a_step() {
+        let mut history = UndoHistory::new(10);
+        history.begin_group();
+        history.record(0, n(0.1), n(0.4));
+        history.record(0, n(0.4), n(0.1));
+        history.end_group();
+
+        assert!(!history.can_undo());
+        assert_eq!(history.undo(), None);
+    }
+
+    #[test]
+    fn new_step_invalidates_redo() {
+        let mut history = UndoHistory::new(10);
+        history.record(0, n(0.1), n(0.2));
+        history.record(0, n(0.2), n(0.3));
+        let _ = history.undo();
+        assert!(history.can_redo());
+
+        history.record(1, n(0.5), n(0.6));
+
+        assert!(!history.can_redo());
+        assert_eq!(history.redo(), None);
+        assert_eq!(history.undo(), Some(vec![(1, n(0.5))]));
+        assert_eq!(history.undo(), Some(vec![(0, n(0.1))]));
+    }
+
+    #[test]
+    fn unchanged_step_keeps_redo() {
+        let mut history = UndoHistory::new(10);
+        history.record(0, n(0.1), n(0.2));
+        let _ = history.undo();
+
+        history.record(1, n(0.5), n(0.5));
+
+        assert_eq!(history.redo(), Some(vec![(0, n(0.2))]));
+    }
+
+    #[test]
+    fn oldest_steps_are_forgotten_first() {
+        let mut history = UndoHistory::new(2);
+        history.record(0, n(0.1), n(0.2));
+        history.record(0, n(0.2), n(0.3));
+        history.record(0, n(0.3), n(0.4));
+
+        assert_eq!(history.undo(), Some(vec![(0, n(0.3))]));
+        assert_eq!(history.undo(), Some(vec![(0, n(0.2))]));
+        assert_eq!(history.undo(), None);
+    }
+}
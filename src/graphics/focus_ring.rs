@@ -0,0 +1,53 @@
+//! Drawing of the ring around a widget that has keyboard focus
+
+use iced_graphics::Primitive;
+use iced_native::{Background, Color, Rectangle};
+
+use crate::style::focus_ring::Style;
+
+/// Draws a focus ring around a widget if it has keyboard focus
+///
+/// * `primitive` - the primitive of the widget
+/// * `bounds` - the bounds of the widget
+/// * `is_focused` - whether the widget has keyboard focus
+/// * `style` - the style of the ring, or `None` for no ring
+/// * `is_round` - whether the widget is round, such as a `Knob`
+pub(crate) fn draw(
+    primitive: Primitive,
+    bounds: &Rectangle,
+    is_focused: bool,
+    style: Option<Style>,
+    is_round: bool,
+) -> Primitive {
+    let style = match style {
+        Some(style) if is_focused => style,
+        _ => return primitive,
+    };
+
+    let spread = style.offset + style.width;
+
+    let ring_bounds = Rectangle {
+        x: bounds.x - spread,
+        y: bounds.y - spread,
+        width: bounds.width + (spread * 2.0),
+        height: bounds.height + (spread * 2.0),
+    };
+
+    let border_radius = if is_round {
+        ring_bounds.width.min(ring_bounds.height) / 2.0
+    } else {
+        style.border_radius
+    };
+
+    let ring = Primitive::Quad {
+        bounds: ring_bounds,
+        background: Background::Color(Color::TRANSPARENT),
+        border_radius,
+        border_width: style.width,
+        border_color: style.color,
+    };
+
+    Primitive::Group {
+        primitives: vec![primitive, ring],
+    }
+}
@@ -2,14 +2,15 @@
 //!
 //! [`Param`]: ../core/param/struct.Param.html
 
+use crate::core::math::TWO_PI;
 use crate::core::{ModulationRange, Normal};
-use crate::graphics::{text_marks, tick_marks};
+use crate::graphics::{focus_ring, text_entry, text_marks, tick_marks};
 use crate::native::knob;
 use iced_graphics::canvas::{path::Arc, Frame, Path, Stroke};
 use iced_graphics::{Backend, Primitive, Renderer};
 use iced_native::{mouse, Background, Point, Rectangle, Size, Vector};
 
-pub use crate::native::knob::State;
+pub use crate::native::knob::{State, TurnMode};
 pub use crate::style::knob::{
     ArcBipolarStyle, ArcStyle, CircleNotch, CircleStyle, LineCap, LineNotch,
     ModRangeArcStyle, NotchShape, Style, StyleLength, StyleSheet,
@@ -35,6 +36,7 @@ struct KnobInfo {
     radius: f32,
     value: Normal,
     value_angle: f32,
+    notch_angle: f32,
 }
 
 /// A rotating knob GUI widget that controls a [`Param`]
@@ -50,8 +52,8 @@ impl<B: Backend> knob::Renderer for Renderer<B> {
         &mut self,
         bounds: Rectangle,
         cursor_position: Point,
-        normal: Normal,
-        is_dragging: bool,
+        state: &State,
+        turn_mode: TurnMode,
         mod_range_1: Option<&ModulationRange>,
         mod_range_2: Option<&ModulationRange>,
         tick_marks: Option<&tick_marks::Group>,
@@ -61,10 +63,11 @@ impl<B: Backend> knob::Renderer for Renderer<B> {
         text_marks_cache: &text_marks::PrimitiveCache,
     ) -> Self::Output {
         let is_mouse_over = bounds.contains(cursor_position);
+        let normal = state.normal_param.value;
 
         let angle_range = style_sheet.angle_range();
 
-        let style = if is_dragging {
+        let style = if state.is_dragging() {
             style_sheet.dragging()
         } else if is_mouse_over {
             style_sheet.hovered()
@@ -121,8 +124,18 @@ impl<B: Backend> knob::Renderer for Renderer<B> {
             } else {
                 angle_range.min() + std::f32::consts::FRAC_PI_2
             };
-        let angle_span = angle_range.max() - angle_range.min();
+        // An endless knob has no end stops, so it spans a full circle.
+        let angle_span = if turn_mode == TurnMode::Endless {
+            TWO_PI
+        } else {
+            angle_range.max() - angle_range.min()
+        };
         let value_angle = start_angle + (normal.scale(angle_span));
+        let notch_angle = if let TurnMode::MultiTurn(turns) = turn_mode {
+            start_angle + (normal.scale(TWO_PI * f32::from(turns.max(1))))
+        } else {
+            value_angle
+        };
 
         let knob_info = KnobInfo {
             bounds,
@@ -131,34 +144,60 @@ impl<B: Backend> knob::Renderer for Renderer<B> {
             radius,
             value: normal,
             value_angle,
+            notch_angle,
         };
 
-        (
-            match style {
-                Style::Circle(style) => draw_circle_style(
-                    &knob_info,
-                    style,
-                    &value_markers,
-                    tick_marks_cache,
-                    text_marks_cache,
-                ),
-                Style::Arc(style) => draw_arc_style(
-                    &knob_info,
-                    style,
-                    &value_markers,
-                    tick_marks_cache,
-                    text_marks_cache,
-                ),
-                Style::ArcBipolar(style) => draw_arc_bipolar_style(
-                    &knob_info,
-                    style,
-                    &value_markers,
-                    tick_marks_cache,
-                    text_marks_cache,
-                ),
-            },
-            mouse::Interaction::default(),
-        )
+        let primitives = match style {
+            Style::Circle(style) => draw_circle_style(
+                &knob_info,
+                style,
+                &value_markers,
+                tick_marks_cache,
+                text_marks_cache,
+            ),
+            Style::Arc(style) => draw_arc_style(
+                &knob_info,
+                style,
+                &value_markers,
+                tick_marks_cache,
+                text_marks_cache,
+            ),
+            Style::ArcBipolar(style) => draw_arc_bipolar_style(
+                &knob_info,
+                style,
+                &value_markers,
+                tick_marks_cache,
+                text_marks_cache,
+            ),
+        };
+
+        let primitives = focus_ring::draw(
+            primitives,
+            &knob_info.bounds,
+            state.is_focused(),
+            style_sheet.focus_ring_style(),
+            true,
+        );
+
+        let primitives = match state.text_entry() {
+            Some(text_entry) => {
+                let style = if text_entry.is_invalid() {
+                    style_sheet.invalid_text_entry_style()
+                } else {
+                    style_sheet.text_entry_style()
+                };
+
+                text_entry::draw(
+                    primitives,
+                    &knob_info.bounds,
+                    text_entry,
+                    &style,
+                )
+            }
+            None => primitives,
+        };
+
+        (primitives, mouse::Interaction::default())
     }
 }
 
@@ -456,7 +495,7 @@ fn draw_mod_range_arc(
 }
 
 fn draw_circle_notch(knob_info: &KnobInfo, style: &CircleNotch) -> Primitive {
-    let value_angle = knob_info.value_angle + std::f32::consts::FRAC_PI_2;
+    let value_angle = knob_info.notch_angle + std::f32::consts::FRAC_PI_2;
 
     let (dx, dy) = if value_angle < -0.001 || value_angle > 0.001 {
         value_angle.sin_cos()
@@ -489,7 +528,7 @@ fn draw_circle_notch(knob_info: &KnobInfo, style: &CircleNotch) -> Primitive {
 }
 
 fn draw_line_notch(knob_info: &KnobInfo, style: &LineNotch) -> Primitive {
-    let value_angle = knob_info.value_angle + std::f32::consts::FRAC_PI_2;
+    let value_angle = knob_info.notch_angle + std::f32::consts::FRAC_PI_2;
 
     let stroke = Stroke {
         width: style.width.from_knob_diameter(knob_info.bounds.width),
@@ -7,6 +7,9 @@ pub mod ramp;
 pub mod v_slider;
 pub mod xy_pad;
 
+mod focus_ring;
+mod text_entry;
+
 pub mod text_marks;
 pub mod tick_marks;
 
@@ -2,6 +2,7 @@
 //!
 //! [`Param`]: ../core/param/struct.Param.html
 
+use crate::graphics::focus_ring;
 use crate::native::mod_range_input;
 
 use iced_graphics::{Backend, Primitive, Renderer};
@@ -26,6 +27,7 @@ impl<B: Backend> mod_range_input::Renderer for Renderer<B> {
         bounds: Rectangle,
         cursor_position: Point,
         is_dragging: bool,
+        is_focused: bool,
         style_sheet: &Self::Style,
     ) -> Self::Output {
         let is_mouse_over = bounds.contains(cursor_position);
@@ -38,6 +40,8 @@ impl<B: Backend> mod_range_input::Renderer for Renderer<B> {
             style_sheet.active()
         };
 
+        let is_round = matches!(style, Style::Circle(_));
+
         let dot: Primitive = match style {
             Style::Circle(style) => {
                 let bounds_x = bounds.x.floor();
@@ -80,6 +84,15 @@ impl<B: Backend> mod_range_input::Renderer for Renderer<B> {
             Style::Invisible => Primitive::None,
         };
 
-        (dot, mouse::Interaction::default())
+        (
+            focus_ring::draw(
+                dot,
+                &bounds,
+                is_focused,
+                style_sheet.focus_ring_style(),
+                is_round,
+            ),
+            mouse::Interaction::default(),
+        )
     }
 }
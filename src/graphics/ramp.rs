@@ -3,7 +3,7 @@
 //!
 //! [`Param`]: ../core/param/trait.Param.html
 
-use crate::core::Normal;
+use crate::graphics::focus_ring;
 use crate::native::ramp;
 use iced_graphics::canvas::{Frame, LineCap, Path, Stroke};
 use iced_graphics::{Backend, Primitive, Renderer};
@@ -27,14 +27,14 @@ impl<B: Backend> ramp::Renderer for Renderer<B> {
         &mut self,
         bounds: Rectangle,
         cursor_position: Point,
-        normal: Normal,
-        is_dragging: bool,
+        state: &State,
         style_sheet: &Self::Style,
         direction: RampDirection,
     ) -> Self::Output {
         let is_mouse_over = bounds.contains(cursor_position);
+        let normal = state.normal_param.value;
 
-        let style = if is_dragging {
+        let style = if state.is_dragging() {
             style_sheet.dragging()
         } else if is_mouse_over {
             style_sheet.hovered()
@@ -284,9 +284,15 @@ impl<B: Backend> ramp::Renderer for Renderer<B> {
         };
 
         (
-            Primitive::Group {
-                primitives: vec![back, line],
-            },
+            focus_ring::draw(
+                Primitive::Group {
+                    primitives: vec![back, line],
+                },
+                &bounds,
+                state.is_focused(),
+                style_sheet.focus_ring_style(),
+                false,
+            ),
             mouse::Interaction::default(),
         )
     }
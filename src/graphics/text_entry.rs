@@ -0,0 +1,58 @@
+//! Drawing of the text entry over a widget while the user types a value
+
+use iced_graphics::{HorizontalAlignment, Primitive, VerticalAlignment};
+use iced_native::{Background, Rectangle};
+
+pub use crate::native::text_entry::State;
+use crate::style::text_entry::Style;
+
+/// Draws an open text entry over a widget
+///
+/// * `primitive` - the primitive of the widget
+/// * `bounds` - the bounds of the widget
+/// * `text_entry` - the state of the open text entry
+/// * `style` - the style of the text entry
+pub(crate) fn draw(
+    primitive: Primitive,
+    bounds: &Rectangle,
+    text_entry: &State,
+    style: &Style,
+) -> Primitive {
+    let center = bounds.center();
+    let width = bounds.width.max(f32::from(style.width)).round();
+    let height = f32::from(style.height);
+
+    let entry_bounds = Rectangle {
+        x: (center.x - (width / 2.0)).round(),
+        y: (center.y - (height / 2.0)).round(),
+        width,
+        height,
+    };
+
+    let back = Primitive::Quad {
+        bounds: entry_bounds,
+        background: Background::Color(style.back_color),
+        border_radius: style.border_radius,
+        border_width: style.border_width,
+        border_color: style.border_color,
+    };
+
+    let text = Primitive::Text {
+        content: format!("{}|", text_entry.text()),
+        size: f32::from(style.text_size),
+        bounds: Rectangle {
+            x: entry_bounds.center_x(),
+            y: entry_bounds.center_y(),
+            width: entry_bounds.width,
+            height: entry_bounds.height,
+        },
+        color: style.text_color,
+        font: style.font,
+        horizontal_alignment: HorizontalAlignment::Center,
+        vertical_alignment: VerticalAlignment::Center,
+    };
+
+    Primitive::Group {
+        primitives: vec![primitive, back, text],
+    }
+}
@@ -3,12 +3,12 @@
 //! [`Param`]: ../core/param/trait.Param.html
 
 use crate::core::{ModulationRange, Normal};
-use crate::graphics::{text_marks, tick_marks};
+use crate::graphics::{focus_ring, text_entry, text_marks, tick_marks};
 use crate::native::v_slider;
 use iced_graphics::{Backend, Primitive, Renderer};
 use iced_native::{mouse, Background, Color, Point, Rectangle};
 
-pub use crate::native::v_slider::State;
+pub use crate::native::v_slider::{DragMode, State};
 pub use crate::style::v_slider::{
     ClassicHandle, ClassicRail, ClassicStyle, ModRangePlacement, ModRangeStyle,
     RectBipolarStyle, RectStyle, Style, StyleSheet, TextMarksStyle,
@@ -38,12 +38,20 @@ pub type VSlider<'a, Message, Backend> =
 impl<B: Backend> v_slider::Renderer for Renderer<B> {
     type Style = Box<dyn StyleSheet>;
 
+    fn handle_height(&self, style_sheet: &Self::Style) -> f32 {
+        match style_sheet.active() {
+            Style::Texture(style) => f32::from(style.handle_height),
+            Style::Classic(style) => f32::from(style.handle.height),
+            Style::Rect(style) => f32::from(style.handle_height),
+            Style::RectBipolar(style) => f32::from(style.handle_height),
+        }
+    }
+
     fn draw(
         &mut self,
         bounds: Rectangle,
         cursor_position: Point,
-        normal: Normal,
-        is_dragging: bool,
+        state: &State,
         mod_range_1: Option<&ModulationRange>,
         mod_range_2: Option<&ModulationRange>,
         tick_marks: Option<&tick_marks::Group>,
@@ -53,8 +61,9 @@ impl<B: Backend> v_slider::Renderer for Renderer<B> {
         text_marks_cache: &text_marks::PrimitiveCache,
     ) -> Self::Output {
         let is_mouse_over = bounds.contains(cursor_position);
+        let normal = state.normal_param.value;
 
-        let style = if is_dragging {
+        let style = if state.is_dragging() {
             style_sheet.dragging()
         } else if is_mouse_over {
             style_sheet.hovered()
@@ -115,6 +124,27 @@ impl<B: Backend> v_slider::Renderer for Renderer<B> {
             ),
         };
 
+        let primitives = focus_ring::draw(
+            primitives,
+            &bounds,
+            state.is_focused(),
+            style_sheet.focus_ring_style(),
+            false,
+        );
+
+        let primitives = match state.text_entry() {
+            Some(text_entry) => {
+                let style = if text_entry.is_invalid() {
+                    style_sheet.invalid_text_entry_style()
+                } else {
+                    style_sheet.text_entry_style()
+                };
+
+                text_entry::draw(primitives, &bounds, text_entry, &style)
+            }
+            None => primitives,
+        };
+
         (primitives, mouse::Interaction::default())
     }
 }
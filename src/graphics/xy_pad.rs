@@ -3,7 +3,7 @@
 //!
 //! [`Param`]: ../core/param/trait.Param.html
 
-use crate::core::Normal;
+use crate::graphics::focus_ring;
 use crate::native::xy_pad;
 use iced_graphics::{Backend, Primitive, Renderer};
 use iced_native::{mouse, Background, Color, Point, Rectangle};
@@ -31,14 +31,14 @@ impl<B: Backend> xy_pad::Renderer for Renderer<B> {
         &mut self,
         bounds: Rectangle,
         cursor_position: Point,
-        normal_x: Normal,
-        normal_y: Normal,
-        is_dragging: bool,
+        state: &State,
         style_sheet: &Self::Style,
     ) -> Self::Output {
         let is_mouse_over = bounds.contains(cursor_position);
+        let normal_x = state.normal_param_x.value;
+        let normal_y = state.normal_param_y.value;
 
-        let style = if is_dragging {
+        let style = if state.is_dragging() {
             style_sheet.dragging()
         } else if is_mouse_over {
             style_sheet.hovered()
@@ -184,17 +184,30 @@ impl<B: Backend> xy_pad::Renderer for Renderer<B> {
             }
         };
 
+        let pad_bounds = Rectangle {
+            x: bounds_x,
+            y: bounds_y,
+            width: bounds_size,
+            height: bounds_size,
+        };
+
         (
-            Primitive::Group {
-                primitives: vec![
-                    back,
-                    h_center_line,
-                    v_center_line,
-                    h_rail,
-                    v_rail,
-                    handle,
-                ],
-            },
+            focus_ring::draw(
+                Primitive::Group {
+                    primitives: vec![
+                        back,
+                        h_center_line,
+                        v_center_line,
+                        h_rail,
+                        v_rail,
+                        handle,
+                    ],
+                },
+                &pad_bounds,
+                state.is_focused(),
+                style_sheet.focus_ring_style(),
+                false,
+            ),
             mouse::Interaction::default(),
         )
     }
@@ -30,7 +30,7 @@
 //! // Import iced_audio modules.
 //! use iced_audio::{
 //!     h_slider, knob, tick_marks, v_slider, xy_pad, FloatRange, FreqRange,
-//!     HSlider, IntRange, Knob, LogDBRange, Normal, VSlider, XYPad,
+//!     HSlider, IntRange, Knob, LogDBRange, Normal, Range, VSlider, XYPad,
 //! };
 //!
 //! // The message when a parameter widget is moved by the user
@@ -58,7 +58,7 @@
 //!     // * LogDBRange - a logarithmic range of decibel values. Values around 0 dB
 //!     // will increment slower than values farther away from 0 dB.
 //!     // * FreqRange - a logarithmic range of frequency values. Each octave in
-//!     // the 10 octave spectrum (from 20 Hz to 20480 Hz) is spaced evenly.
+//!     // the range is spaced evenly.
 //!     //
 //!     float_range: FloatRange,
 //!     int_range: IntRange,
@@ -126,26 +126,23 @@
 //!             //
 //!             // Now do something useful with that value!
 //!             Message::HSliderInt(normal) => {
-//!                 // Integer ranges must be snapped to make the widget "step" when moved.
-//!                 self.int_range
-//!                     .snap(&mut self.h_slider_state.normal_param.value);
-//!
-//!                 let value = self.int_range.unmap_to_value(normal);
-//!                 self.output_text = format!("HSliderInt: {}", value);
+//!                 self.output_text =
+//!                     format!("HSliderInt: {}", self.int_range.format(normal));
 //!             }
 //!             Message::VSliderDB(normal) => {
-//!                 let value = self.db_range.unmap_to_value(normal);
-//!                 self.output_text = format!("VSliderDB: {:.3}", value);
+//!                 self.output_text =
+//!                     format!("VSliderDB: {}", self.db_range.format(normal));
 //!             }
 //!             Message::KnobFreq(normal) => {
-//!                 let value = self.freq_range.unmap_to_value(normal);
-//!                 self.output_text = format!("KnobFreq: {:.2}", value);
+//!                 self.output_text =
+//!                     format!("KnobFreq: {}", self.freq_range.format(normal));
 //!             }
 //!             Message::XYPadFloat(normal_x, normal_y) => {
-//!                 let value_x = self.float_range.unmap_to_value(normal_x);
-//!                 let value_y = self.float_range.unmap_to_value(normal_y);
-//!                 self.output_text =
-//!                     format!("XYPadFloat: x: {:.2}, y: {:.2}", value_x, value_y);
+//!                 self.output_text = format!(
+//!                     "XYPadFloat: x: {}, y: {}",
+//!                     self.float_range.format(normal_x),
+//!                     self.float_range.format(normal_y)
+//!                 );
 //!             }
 //!         }
 //!     }
@@ -155,7 +152,9 @@
 //!         let h_slider_widget =
 //!             HSlider::new(&mut self.h_slider_state, Message::HSliderInt)
 //!                 // Add the tick mark group to this widget.
-//!                 .tick_marks(&self.center_tick_mark);
+//!                 .tick_marks(&self.center_tick_mark)
+//!                 // Make the widget "step" between the integers of the range.
+//!                 .snap(&self.int_range);
 //!
 //!         let v_slider_widget =
 //!             VSlider::new(&mut self.v_slider_state, Message::VSliderDB)
@@ -199,7 +198,10 @@
 #![deny(missing_docs)]
 #![deny(missing_debug_implementations)]
 #![deny(unused_results)]
-#![forbid(rust_2018_idioms)]
+#![cfg_attr(not(feature = "serde"), forbid(rust_2018_idioms))]
+// The serde derives allow `unused_extern_crates`, a lint of
+// `rust_2018_idioms` that `forbid` would not let them allow.
+#![cfg_attr(feature = "serde", deny(rust_2018_idioms))]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
 //extern crate simdeez;
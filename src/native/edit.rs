@@ -0,0 +1,52 @@
+//! Edit gestures shared by every widget
+//!
+//! A host records automation between the `on_grab` and `on_release`
+//! messages of a widget. A drag sends them when the widget is grabbed and
+//! released, while a key press, a scroll of the mouse wheel, an entered
+//! value, or a reset to the default is a complete edit gesture on its own.
+
+/// Pushes the messages of an edit that is a complete gesture on its own:
+/// a grab, the change, and a release
+///
+/// * `messages` - the messages of the widget
+/// * `on_grab` - the grab message of the widget, if any
+/// * `change` - the message with the new value of the widget
+/// * `on_release` - the release message of the widget, if any
+pub(crate) fn push_gesture<Message>(
+    messages: &mut Vec<Message>,
+    on_grab: Option<&dyn Fn() -> Message>,
+    change: Message,
+    on_release: Option<&dyn Fn() -> Message>,
+) {
+    if let Some(on_grab) = on_grab {
+        messages.push(on_grab());
+    }
+
+    messages.push(change);
+
+    if let Some(on_release) = on_release {
+        messages.push(on_release());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gesture_is_grab_change_release() {
+        let mut messages = Vec::new();
+        let on_grab = || "grab";
+        let on_release = || "release";
+
+        push_gesture(
+            &mut messages,
+            Some(&on_grab),
+            "change",
+            Some(&on_release),
+        );
+        push_gesture(&mut messages, None, "change", None);
+
+        assert_eq!(messages, ["grab", "change", "release", "change"]);
+    }
+}
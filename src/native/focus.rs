@@ -0,0 +1,322 @@
+//! Keyboard focus shared by every widget
+//!
+//! Only one widget has focus at a time. Widgets receive events in the
+//! order they appear in the layout, so `Tab` hands focus to the next widget
+//! that receives the same event, and `Shift + Tab` to the last widget that
+//! received it before. When `Tab` leaves the last widget, focus wraps
+//! around to the first widget when it receives the next keyboard event,
+//! such as the release of `Tab`.
+//!
+//! Focus is only moved between the widgets of this crate. Other widgets,
+//! such as a `TextInput` of Iced, are skipped by `Tab`, and they keep their
+//! own focus. Focus is shared by every widget on the same thread, so an
+//! application with several windows has one focused widget across all of
+//! them.
+
+use std::cell::Cell;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use iced_native::{keyboard, mouse, Event};
+
+static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// A pending move of focus to a widget that has not received the event yet
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Handoff {
+    None,
+    /// The next widget to receive an event takes focus
+    Next,
+    /// The widget with an id takes focus
+    To(usize),
+    /// The last widget to receive a `Tab` key takes focus
+    Last,
+}
+
+thread_local! {
+    static FOCUSED: Cell<Option<usize>> = const { Cell::new(None) };
+    static HANDOFF: Cell<Handoff> = const { Cell::new(Handoff::None) };
+    static LAST_TABBED: Cell<Option<usize>> = const { Cell::new(None) };
+}
+
+/// A change to the value of a focused widget made with the keyboard
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) enum Command {
+    /// Moves the value by a number of steps along the x and y axes
+    Step { x: f32, y: f32 },
+    /// Moves the value by a number of pages
+    Page(f32),
+    /// Sets the value to the minimum
+    Min,
+    /// Sets the value to the maximum
+    Max,
+    /// Resets the value to the default
+    Default,
+}
+
+impl Command {
+    /// Returns the new value of a widget with a single value. Steps along
+    /// both axes move the value. The result is not constrained, so that
+    /// endless widgets can wrap it.
+    pub(crate) fn apply(
+        self,
+        value: f32,
+        default: f32,
+        key_step: f32,
+        page_step: f32,
+    ) -> f32 {
+        match self {
+            Command::Step { x, y } => value + (x + y) * key_step,
+            Command::Page(pages) => value + pages * page_step,
+            Command::Min => 0.0,
+            Command::Max => 1.0,
+            Command::Default => default,
+        }
+    }
+}
+
+/// The keyboard focus of a single widget
+#[derive(Debug)]
+pub(crate) struct Focus {
+    id: usize,
+}
+
+impl Clone for Focus {
+    /// Returns a new `Focus` without focus, so that the state of a widget
+    /// can be cloned into another widget
+    fn clone(&self) -> Self {
+        Self::new()
+    }
+}
+
+impl Focus {
+    pub(crate) fn new() -> Self {
+        Self {
+            id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
+        }
+    }
+
+    pub(crate) fn is_focused(&self) -> bool {
+        FOCUSED.with(|focused| focused.get() == Some(self.id))
+    }
+
+    pub(crate) fn focus(&mut self) {
+        FOCUSED.with(|focused| focused.set(Some(self.id)));
+        HANDOFF.with(|handoff| handoff.set(Handoff::None));
+    }
+
+    pub(crate) fn unfocus(&mut self) {
+        if self.is_focused() {
+            FOCUSED.with(|focused| focused.set(None));
+        }
+    }
+
+    /// Moves focus for an event, and returns the [`Command`] of a key
+    /// pressed while the widget has focus
+    ///
+    /// * `is_mouse_over` - whether the cursor is over the widget
+    ///
+    /// [`Command`]: enum.Command.html
+    pub(crate) fn on_event(
+        &mut self,
+        event: &Event,
+        is_mouse_over: bool,
+    ) -> Option<Command> {
+        let handed_off = match event {
+            Event::Keyboard(_) => self.take_handoff(),
+            _ => false,
+        };
+
+        if let Event::Keyboard(keyboard::Event::KeyPressed {
+            key_code: keyboard::KeyCode::Tab,
+            modifiers,
+        }) = event
+        {
+            if !handed_off && self.is_focused() {
+                self.tab(modifiers.shift);
+            }
+            LAST_TABBED.with(|last| last.set(Some(self.id)));
+            return None;
+        }
+
+        if handed_off {
+            return None;
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(_)) => {
+                if is_mouse_over {
+                    self.focus();
+                } else {
+                    self.unfocus();
+                }
+                None
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code, ..
+            }) => {
+                if !self.is_focused() {
+                    return None;
+                }
+
+                match key_code {
+                    keyboard::KeyCode::Left => {
+                        Some(Command::Step { x: -1.0, y: 0.0 })
+                    }
+                    keyboard::KeyCode::Right => {
+                        Some(Command::Step { x: 1.0, y: 0.0 })
+                    }
+                    keyboard::KeyCode::Up => {
+                        Some(Command::Step { x: 0.0, y: 1.0 })
+                    }
+                    keyboard::KeyCode::Down => {
+                        Some(Command::Step { x: 0.0, y: -1.0 })
+                    }
+                    keyboard::KeyCode::PageUp => Some(Command::Page(1.0)),
+                    keyboard::KeyCode::PageDown => Some(Command::Page(-1.0)),
+                    keyboard::KeyCode::Home => Some(Command::Min),
+                    keyboard::KeyCode::End => Some(Command::Max),
+                    keyboard::KeyCode::Delete
+                    | keyboard::KeyCode::Backspace => Some(Command::Default),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Takes focus if it was handed off to this widget, and returns `true`
+    /// if it did
+    fn take_handoff(&mut self) -> bool {
+        let is_target = match HANDOFF.with(Cell::get) {
+            Handoff::None => false,
+            Handoff::Next => true,
+            Handoff::To(id) => id == self.id,
+            Handoff::Last => LAST_TABBED.with(Cell::get) == Some(self.id),
+        };
+
+        if is_target {
+            self.focus();
+        }
+        is_target
+    }
+
+    fn tab(&mut self, backwards: bool) {
+        self.unfocus();
+
+        if !backwards {
+            // The next widget takes focus when it receives this event. If
+            // this is the last widget, the first widget takes focus when
+            // it receives the next keyboard event.
+            HANDOFF.with(|handoff| handoff.set(Handoff::Next));
+            return;
+        }
+
+        // The widget before this one takes focus when it receives the next
+        // keyboard event. If this is the first widget, the last widget to receive the
+        // previous `Tab` is the last widget, and it takes focus when it
+        // receives this event.
+        let handoff = match LAST_TABBED.with(Cell::get) {
+            Some(previous) => Handoff::To(previous),
+            None => Handoff::Last,
+        };
+        HANDOFF.with(|cell| cell.set(handoff));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(key_code: keyboard::KeyCode, shift: bool) -> [Event; 2] {
+        let modifiers = keyboard::Modifiers {
+            shift,
+            ..Default::default()
+        };
+
+        [
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers,
+            }),
+            Event::Keyboard(keyboard::Event::KeyReleased {
+                key_code,
+                modifiers,
+            }),
+        ]
+    }
+
+    fn send(widgets: &mut [Focus], event: &Event) {
+        for widget in widgets.iter_mut() {
+            let _ = widget.on_event(event, false);
+        }
+    }
+
+    fn focused(widgets: &[Focus]) -> Vec<bool> {
+        widgets.iter().map(Focus::is_focused).collect()
+    }
+
+    #[test]
+    fn clone_has_own_focus() {
+        let mut focus = Focus::new();
+        focus.focus();
+
+        let clone = focus.clone();
+
+        assert!(focus.is_focused());
+        assert!(!clone.is_focused());
+    }
+
+    #[test]
+    fn tab_moves_focus_forward_and_wraps() {
+        let mut widgets = [Focus::new(), Focus::new(), Focus::new()];
+        widgets[0].focus();
+
+        for expected in &[[false, true, false], [false, false, true]] {
+            for event in key(keyboard::KeyCode::Tab, false).iter() {
+                send(&mut widgets, event);
+            }
+            assert_eq!(focused(&widgets), expected.to_vec());
+        }
+
+        let [press, release] = key(keyboard::KeyCode::Tab, false);
+        send(&mut widgets, &press);
+        assert_eq!(focused(&widgets), vec![false, false, false]);
+
+        // A mouse event does not take the focus that wraps around.
+        send(
+            &mut widgets,
+            &Event::Mouse(mouse::Event::CursorMoved { x: 0.0, y: 0.0 }),
+        );
+        assert_eq!(focused(&widgets), vec![false, false, false]);
+
+        send(&mut widgets, &release);
+        assert_eq!(focused(&widgets), vec![true, false, false]);
+    }
+
+    #[test]
+    fn shift_tab_moves_focus_backward_and_wraps() {
+        let mut widgets = [Focus::new(), Focus::new(), Focus::new()];
+        widgets[1].focus();
+
+        for expected in &[[true, false, false], [false, false, true]] {
+            for event in key(keyboard::KeyCode::Tab, true).iter() {
+                send(&mut widgets, event);
+            }
+            assert_eq!(focused(&widgets), expected.to_vec());
+        }
+    }
+
+    #[test]
+    fn keys_command_only_the_focused_widget() {
+        let mut widgets = [Focus::new(), Focus::new()];
+        widgets[1].focus();
+
+        let [press, _] = key(keyboard::KeyCode::Up, false);
+
+        assert_eq!(widgets[0].on_event(&press, false), None);
+        assert_eq!(
+            widgets[1].on_event(&press, false),
+            Some(Command::Step { x: 0.0, y: 1.0 })
+        );
+    }
+}
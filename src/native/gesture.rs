@@ -0,0 +1,415 @@
+//! Mouse gestures shared by every widget
+//!
+//! A [`GestureBindings`] maps clicks of mouse buttons, and modifier keys
+//! held down while dragging, to the [`Action`]s of a widget. Create one
+//! for the whole application and pass it to each widget with
+//! `.gestures()`, so that every widget responds to the same gestures.
+//!
+//! A gesture matches only while holding down exactly its modifier keys. A
+//! drag bound to `Ctrl` is not a fine drag while holding down `Ctrl` and
+//! `Shift`, so bind each combination of keys that should perform an action.
+//!
+//! [`GestureBindings`]: struct.GestureBindings.html
+//! [`Action`]: enum.Action.html
+
+use std::borrow::Cow;
+
+use iced_native::{keyboard, mouse};
+
+/// The bindings used by a widget without a [`GestureBindings`]. A
+/// double-click opens the text entry, or resets the widget if it has no
+/// text entry, and holding down `Ctrl` while dragging drags finely.
+///
+/// The double-click is bound to both [`Action::TextEntry`] and
+/// [`Action::Reset`]. A widget performs the first of them it can, so a
+/// widget without a text entry falls back to a reset. Removing
+/// [`Action::TextEntry`] with [`GestureBindings::unbind`] makes every
+/// double-click a reset.
+///
+/// [`GestureBindings`]: struct.GestureBindings.html
+/// [`Action::TextEntry`]: enum.Action.html#variant.TextEntry
+/// [`Action::Reset`]: enum.Action.html#variant.Reset
+/// [`GestureBindings::unbind`]: struct.GestureBindings.html#method.unbind
+pub const DEFAULT_BINDINGS: &[(Gesture, Action)] = &[
+    (
+        Gesture::Click {
+            button: mouse::Button::Left,
+            kind: ClickKind::Double,
+            modifiers: NO_MODIFIERS,
+        },
+        Action::TextEntry,
+    ),
+    (
+        Gesture::Click {
+            button: mouse::Button::Left,
+            kind: ClickKind::Double,
+            modifiers: NO_MODIFIERS,
+        },
+        Action::Reset,
+    ),
+    (
+        Gesture::Drag {
+            modifiers: keyboard::Modifiers {
+                shift: false,
+                control: true,
+                alt: false,
+                logo: false,
+            },
+        },
+        Action::FineDrag,
+    ),
+];
+
+const NO_MODIFIERS: keyboard::Modifiers = keyboard::Modifiers {
+    shift: false,
+    control: false,
+    alt: false,
+    logo: false,
+};
+
+/// The number of clicks in a [`Gesture`]
+///
+/// [`Gesture`]: enum.Gesture.html
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ClickKind {
+    /// A single click
+    Single,
+    /// A double-click. A triple-click is also a double-click.
+    Double,
+}
+
+impl From<mouse::click::Kind> for ClickKind {
+    fn from(kind: mouse::click::Kind) -> Self {
+        match kind {
+            mouse::click::Kind::Single => ClickKind::Single,
+            _ => ClickKind::Double,
+        }
+    }
+}
+
+/// A gesture of the mouse and the modifier keys
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Gesture {
+    /// A click of a mouse button while holding down exactly the modifier
+    /// keys
+    Click {
+        /// The mouse button
+        button: mouse::Button,
+        /// The number of clicks
+        kind: ClickKind,
+        /// The modifier keys
+        modifiers: keyboard::Modifiers,
+    },
+    /// A drag with the left mouse button while holding down exactly the
+    /// modifier keys
+    Drag {
+        /// The modifier keys
+        modifiers: keyboard::Modifiers,
+    },
+}
+
+impl Gesture {
+    /// Creates a new [`Gesture::Click`] of the left mouse button without
+    /// modifier keys
+    ///
+    /// [`Gesture::Click`]: #variant.Click
+    pub fn click(kind: ClickKind) -> Self {
+        Gesture::Click {
+            button: mouse::Button::Left,
+            kind,
+            modifiers: NO_MODIFIERS,
+        }
+    }
+
+    /// Creates a new single [`Gesture::Click`] of a mouse button without
+    /// modifier keys, such as a right-click
+    ///
+    /// [`Gesture::Click`]: #variant.Click
+    pub fn button(button: mouse::Button) -> Self {
+        Gesture::Click {
+            button,
+            kind: ClickKind::Single,
+            modifiers: NO_MODIFIERS,
+        }
+    }
+
+    /// Creates a new [`Gesture::Drag`]
+    ///
+    /// [`Gesture::Drag`]: #variant.Drag
+    pub fn drag(modifiers: keyboard::Modifiers) -> Self {
+        Gesture::Drag { modifiers }
+    }
+
+    /// Sets the modifier keys of the [`Gesture`]
+    ///
+    /// [`Gesture`]: enum.Gesture.html
+    pub fn with_modifiers(self, modifiers: keyboard::Modifiers) -> Self {
+        match self {
+            Gesture::Click { button, kind, .. } => Gesture::Click {
+                button,
+                kind,
+                modifiers,
+            },
+            Gesture::Drag { .. } => Gesture::Drag { modifiers },
+        }
+    }
+}
+
+/// An action a widget performs for a [`Gesture`]
+///
+/// [`Gesture`]: enum.Gesture.html
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Action {
+    /// Resets the widget to its default value. This is bound to a click.
+    Reset,
+    /// Drags with the `modifier_scalar` of the widget. This is bound to a
+    /// drag.
+    FineDrag,
+    /// Drags with the `ultra_fine_scalar` of the widget. This is bound to a
+    /// drag.
+    UltraFineDrag,
+    /// Opens the text entry of the widget. This is bound to a click, and
+    /// is skipped if the widget has no text entry.
+    TextEntry,
+    /// Produces the message of `on_context_request`, such as to open a
+    /// context menu. This is bound to a click, and is skipped if the
+    /// widget has no `on_context_request`.
+    ContextRequest,
+}
+
+/// A set of bindings from [`Gesture`]s to [`Action`]s, shared by every
+/// widget.
+///
+/// A gesture may be bound to more than one action, in which case the
+/// first action the widget can perform is used. For example, a
+/// double-click can open the text entry of a widget that has one, and
+/// reset any other widget.
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::native::gesture::{
+///     Action, ClickKind, Gesture, GestureBindings,
+/// };
+/// use iced_native::{keyboard, mouse};
+///
+/// let alt = keyboard::Modifiers {
+///     alt: true,
+///     ..Default::default()
+/// };
+/// let shift = keyboard::Modifiers {
+///     shift: true,
+///     ..Default::default()
+/// };
+///
+/// let alt_click = Gesture::click(ClickKind::Single).with_modifiers(alt);
+/// let right_click = Gesture::button(mouse::Button::Right);
+///
+/// let gestures = GestureBindings::new()
+///     .bind(alt_click, Action::Reset)
+///     .bind(Gesture::click(ClickKind::Double), Action::TextEntry)
+///     .bind(right_click, Action::ContextRequest)
+///     .bind(Gesture::drag(shift), Action::FineDrag);
+/// ```
+///
+/// [`Gesture`]: enum.Gesture.html
+/// [`Action`]: enum.Action.html
+#[derive(Debug, Clone, PartialEq)]
+pub struct GestureBindings {
+    bindings: Vec<(Gesture, Action)>,
+}
+
+impl GestureBindings {
+    /// Creates a new `GestureBindings` without any bindings
+    pub fn new() -> Self {
+        Self {
+            bindings: Vec::new(),
+        }
+    }
+
+    /// Binds a [`Gesture`] to an [`Action`]
+    ///
+    /// [`Gesture`]: enum.Gesture.html
+    /// [`Action`]: enum.Action.html
+    pub fn bind(mut self, gesture: Gesture, action: Action) -> Self {
+        self.bindings.push((gesture, action));
+        self
+    }
+
+    /// Removes every binding to an [`Action`]
+    ///
+    /// [`Action`]: enum.Action.html
+    pub fn unbind(&mut self, action: Action) {
+        self.bindings.retain(|(_, bound)| *bound != action);
+    }
+
+    /// Returns every binding
+    pub fn bindings(&self) -> &[(Gesture, Action)] {
+        &self.bindings
+    }
+}
+
+impl Default for GestureBindings {
+    /// Returns the [`DEFAULT_BINDINGS`]
+    ///
+    /// [`DEFAULT_BINDINGS`]: constant.DEFAULT_BINDINGS.html
+    fn default() -> Self {
+        Self {
+            bindings: DEFAULT_BINDINGS.to_vec(),
+        }
+    }
+}
+
+/// Returns `bindings` with [`Action::FineDrag`] bound only to a drag with
+/// `modifiers`, for the deprecated `modifier_keys` of each widget
+///
+/// * `bindings` - the bindings of the widget, or `None` for the
+///   [`DEFAULT_BINDINGS`]
+///
+/// [`Action::FineDrag`]: enum.Action.html#variant.FineDrag
+/// [`DEFAULT_BINDINGS`]: constant.DEFAULT_BINDINGS.html
+pub(crate) fn rebind_fine_drag(
+    bindings: Option<Cow<'_, GestureBindings>>,
+    modifiers: keyboard::Modifiers,
+) -> Cow<'_, GestureBindings> {
+    let mut bindings =
+        bindings.map_or_else(GestureBindings::default, Cow::into_owned);
+
+    bindings.unbind(Action::FineDrag);
+
+    Cow::Owned(bindings.bind(Gesture::drag(modifiers), Action::FineDrag))
+}
+
+/// Returns the [`Action`] of a click, or `None` if the click is not bound
+/// to an action the widget can perform
+///
+/// * `bindings` - the bindings of the widget, or `None` for the
+///   [`DEFAULT_BINDINGS`]
+/// * `has_text_entry` - whether the widget has a text entry
+/// * `has_context_request` - whether the widget has an
+///   `on_context_request`
+///
+/// [`Action`]: enum.Action.html
+/// [`DEFAULT_BINDINGS`]: constant.DEFAULT_BINDINGS.html
+pub(crate) fn click_action(
+    bindings: Option<&GestureBindings>,
+    button: mouse::Button,
+    kind: ClickKind,
+    modifiers: keyboard::Modifiers,
+    has_text_entry: bool,
+    has_context_request: bool,
+) -> Option<Action> {
+    let bindings = match bindings {
+        Some(bindings) => &bindings.bindings,
+        None => DEFAULT_BINDINGS,
+    };
+
+    let click = Gesture::Click {
+        button,
+        kind,
+        modifiers,
+    };
+
+    bindings
+        .iter()
+        .filter(|(gesture, _)| *gesture == click)
+        .map(|(_, action)| *action)
+        .find(|action| match action {
+            Action::Reset => true,
+            Action::TextEntry => has_text_entry,
+            Action::ContextRequest => has_context_request,
+            Action::FineDrag | Action::UltraFineDrag => false,
+        })
+}
+
+/// Returns the [`Action`] of a drag, or `None` for a normal drag
+///
+/// * `bindings` - the bindings of the widget, or `None` for the
+///   [`DEFAULT_BINDINGS`]
+/// * `modifiers` - the modifier keys held down
+///
+/// [`Action`]: enum.Action.html
+/// [`DEFAULT_BINDINGS`]: constant.DEFAULT_BINDINGS.html
+pub(crate) fn drag_action(
+    bindings: Option<&GestureBindings>,
+    modifiers: keyboard::Modifiers,
+) -> Option<Action> {
+    let bindings = match bindings {
+        Some(bindings) => &bindings.bindings,
+        None => DEFAULT_BINDINGS,
+    };
+
+    let drag = Gesture::Drag { modifiers };
+
+    bindings
+        .iter()
+        .filter(|(gesture, _)| *gesture == drag)
+        .map(|(_, action)| *action)
+        .find(|action| {
+            *action == Action::FineDrag || *action == Action::UltraFineDrag
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn modifiers(shift: bool, control: bool) -> keyboard::Modifiers {
+        keyboard::Modifiers {
+            shift,
+            control,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn double_click_falls_back_to_reset() {
+        let double_click = |has_text_entry| {
+            click_action(
+                None,
+                mouse::Button::Left,
+                ClickKind::Double,
+                NO_MODIFIERS,
+                has_text_entry,
+                false,
+            )
+        };
+
+        assert_eq!(double_click(true), Some(Action::TextEntry));
+        assert_eq!(double_click(false), Some(Action::Reset));
+    }
+
+    #[test]
+    fn drag_modifiers_match_exactly() {
+        assert_eq!(
+            drag_action(None, modifiers(false, true)),
+            Some(Action::FineDrag)
+        );
+        assert_eq!(drag_action(None, modifiers(true, true)), None);
+        assert_eq!(drag_action(None, NO_MODIFIERS), None);
+    }
+
+    #[test]
+    fn rebind_fine_drag_replaces_binding() {
+        let bindings = rebind_fine_drag(None, modifiers(true, false));
+
+        assert_eq!(
+            drag_action(Some(&bindings), modifiers(true, false)),
+            Some(Action::FineDrag)
+        );
+        assert_eq!(drag_action(Some(&bindings), modifiers(false, true)), None);
+
+        // The other default bindings are kept.
+        assert_eq!(
+            click_action(
+                Some(&bindings),
+                mouse::Button::Left,
+                ClickKind::Double,
+                NO_MODIFIERS,
+                false,
+                false,
+            ),
+            Some(Action::Reset)
+        );
+    }
+}
@@ -2,6 +2,7 @@
 //!
 //! [`NormalParam`]: ../core/normal_param/struct.Param.html
 
+use std::borrow::Cow;
 use std::fmt::Debug;
 
 use iced_native::{
@@ -12,11 +13,40 @@ use iced_native::{
 use std::hash::Hash;
 
 use crate::core::{ModulationRange, Normal, NormalParam};
-use crate::native::{text_marks, tick_marks};
+use crate::native::focus::Focus;
+use crate::native::gesture::{self, GestureBindings};
+use crate::native::{edit, snap, text_entry, text_marks, tick_marks, wheel};
 
 static DEFAULT_HEIGHT: u16 = 14;
 static DEFAULT_SCALAR: f32 = 0.9575;
 static DEFAULT_MODIFIER_SCALAR: f32 = 0.02;
+static DEFAULT_ULTRA_FINE_SCALAR: f32 = 0.002;
+static DEFAULT_WHEEL_SCALAR: f32 = 0.01;
+static DEFAULT_WHEEL_PIXEL_SCALAR: f32 = 0.001;
+static DEFAULT_WHEEL_MODIFIER_SCALAR: f32 = 0.1;
+static DEFAULT_WHEEL_ULTRA_FINE_SCALAR: f32 = 0.01;
+static DEFAULT_KEY_STEP: f32 = 0.01;
+static DEFAULT_PAGE_STEP: f32 = 0.1;
+
+/// How an [`HSlider`] or a [`VSlider`] moves when the user clicks and
+/// drags it
+///
+/// [`HSlider`]: struct.HSlider.html
+/// [`VSlider`]: ../v_slider/struct.VSlider.html
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum DragMode {
+    /// The handle moves by the distance the mouse moves, wherever the user
+    /// clicks
+    #[default]
+    Relative,
+    /// The handle jumps to the click, and follows the cursor while the user
+    /// drags. A fine drag still moves the handle relatively.
+    Absolute,
+    /// A click off the handle jumps the handle to the click. The handle
+    /// then moves by the distance the mouse moves, as with a click on the
+    /// handle.
+    JumpOffHandle,
+}
 
 /// A horizontal slider GUI widget that controls a [`NormalParam`]
 ///
@@ -28,9 +58,20 @@ static DEFAULT_MODIFIER_SCALAR: f32 = 0.02;
 pub struct HSlider<'a, Message, Renderer: self::Renderer> {
     state: &'a mut State,
     on_change: Box<dyn Fn(Normal) -> Message>,
+    on_grab: Option<Box<dyn Fn() -> Message>>,
+    on_release: Option<Box<dyn Fn() -> Message>>,
+    on_context_request: Option<Box<dyn Fn(Point) -> Message>>,
     scalar: f32,
     modifier_scalar: f32,
-    modifier_keys: keyboard::Modifiers,
+    ultra_fine_scalar: f32,
+    wheel_scalar: f32,
+    wheel_pixel_scalar: f32,
+    wheel_modifier_scalar: f32,
+    wheel_ultra_fine_scalar: f32,
+    key_step: f32,
+    page_step: f32,
+    gestures: Option<Cow<'a, GestureBindings>>,
+    drag_mode: DragMode,
     width: Length,
     height: Length,
     style: Renderer::Style,
@@ -38,6 +79,8 @@ pub struct HSlider<'a, Message, Renderer: self::Renderer> {
     text_marks: Option<&'a text_marks::Group>,
     mod_range_1: Option<&'a ModulationRange>,
     mod_range_2: Option<&'a ModulationRange>,
+    text_entry: Option<&'a dyn text_entry::EntryFormat>,
+    snap: Option<&'a dyn snap::Snap>,
 }
 
 impl<'a, Message, Renderer: self::Renderer> HSlider<'a, Message, Renderer> {
@@ -56,12 +99,20 @@ impl<'a, Message, Renderer: self::Renderer> HSlider<'a, Message, Renderer> {
         HSlider {
             state,
             on_change: Box::new(on_change),
+            on_grab: None,
+            on_release: None,
+            on_context_request: None,
             scalar: DEFAULT_SCALAR,
             modifier_scalar: DEFAULT_MODIFIER_SCALAR,
-            modifier_keys: keyboard::Modifiers {
-                control: true,
-                ..Default::default()
-            },
+            ultra_fine_scalar: DEFAULT_ULTRA_FINE_SCALAR,
+            wheel_scalar: DEFAULT_WHEEL_SCALAR,
+            wheel_pixel_scalar: DEFAULT_WHEEL_PIXEL_SCALAR,
+            wheel_modifier_scalar: DEFAULT_WHEEL_MODIFIER_SCALAR,
+            wheel_ultra_fine_scalar: DEFAULT_WHEEL_ULTRA_FINE_SCALAR,
+            key_step: DEFAULT_KEY_STEP,
+            page_step: DEFAULT_PAGE_STEP,
+            gestures: None,
+            drag_mode: DragMode::default(),
             width: Length::Fill,
             height: Length::from(Length::Units(DEFAULT_HEIGHT)),
             style: Renderer::Style::default(),
@@ -69,9 +120,59 @@ impl<'a, Message, Renderer: self::Renderer> HSlider<'a, Message, Renderer> {
             text_marks: None,
             mod_range_1: None,
             mod_range_2: None,
+            text_entry: None,
+            snap: None,
         }
     }
 
+    /// Sets the message that will be produced when the user grabs the
+    /// [`HSlider`] to begin an edit gesture.
+    ///
+    /// A host needs this to begin recording automation. It is followed by
+    /// the messages of `on_change`, and then by the message of
+    /// [`on_release`].
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`on_release`]: #method.on_release
+    pub fn on_grab<F>(mut self, on_grab: F) -> Self
+    where
+        F: 'static + Fn() -> Message,
+    {
+        self.on_grab = Some(Box::new(on_grab));
+        self
+    }
+
+    /// Sets the message that will be produced when the user releases the
+    /// [`HSlider`] to end an edit gesture.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    pub fn on_release<F>(mut self, on_release: F) -> Self
+    where
+        F: 'static + Fn() -> Message,
+    {
+        self.on_release = Some(Box::new(on_release));
+        self
+    }
+
+    /// Sets the message that will be produced when the user asks for the
+    /// context of the [`HSlider`], such as to open a context menu. The message
+    /// receives the position of the cursor.
+    ///
+    /// No gesture asks for the context by default. Bind
+    /// [`Action::ContextRequest`] with [`gestures`], such as to a
+    /// right-click.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`Action::ContextRequest`]: ../gesture/enum.Action.html#variant.ContextRequest
+    /// [`gestures`]: #method.gestures
+    pub fn on_context_request<F>(mut self, on_context_request: F) -> Self
+    where
+        F: 'static + Fn(Point) -> Message,
+    {
+        self.on_context_request = Some(Box::new(on_context_request));
+        self
+    }
+
     /// Sets the width of the [`HSlider`].
     ///
     /// The default height is `Length::Fill`.
@@ -100,16 +201,6 @@ impl<'a, Message, Renderer: self::Renderer> HSlider<'a, Message, Renderer> {
         self
     }
 
-    /// Sets the modifier keys of the [`HSlider`].
-    ///
-    /// The default modifier key is `Ctrl`.
-    ///
-    /// [`HSlider`]: struct.HSlider.html
-    pub fn modifier_keys(mut self, modifier_keys: keyboard::Modifiers) -> Self {
-        self.modifier_keys = modifier_keys;
-        self
-    }
-
     /// Sets the scalar to use when the user drags the slider per pixel.
     ///
     /// For example, a scalar of `0.5` will cause the slider to move half a
@@ -124,12 +215,12 @@ impl<'a, Message, Renderer: self::Renderer> HSlider<'a, Message, Renderer> {
     }
 
     /// Sets the scalar to use when the user drags the slider while holding down
-    /// the modifier key.
+    /// the modifier keys of a fine drag.
     ///
     /// For example, a scalar of `0.5` will cause the slider to move half a
     /// pixel for every pixel the mouse moves.
     ///
-    /// The default scalar is `0.02`, and the default modifier key is `Ctrl`.
+    /// The default scalar is `0.02`.
     ///
     /// [`HSlider`]: struct.HSlider.html
     pub fn modifier_scalar(mut self, scalar: f32) -> Self {
@@ -137,6 +228,147 @@ impl<'a, Message, Renderer: self::Renderer> HSlider<'a, Message, Renderer> {
         self
     }
 
+    /// Sets the scalar to use when the user drags the [`HSlider`] while holding
+    /// down the modifier keys of an ultra-fine drag. This is used in place
+    /// of `modifier_scalar`.
+    ///
+    /// An ultra-fine drag has no modifier keys by default. Bind them with
+    /// [`gestures`].
+    ///
+    /// The default scalar is `0.002`.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`gestures`]: #method.gestures
+    pub fn ultra_fine_scalar(mut self, scalar: f32) -> Self {
+        self.ultra_fine_scalar = scalar;
+        self
+    }
+
+    /// Sets the distance to move the [`HSlider`] for each line the mouse
+    /// wheel scrolls, as a [`Normal`] distance.
+    ///
+    /// The default scalar is `0.01`.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn wheel_scalar(mut self, scalar: f32) -> Self {
+        self.wheel_scalar = scalar;
+        self
+    }
+
+    /// Sets the distance to move the [`HSlider`] for each pixel the mouse
+    /// wheel or touchpad scrolls, as a [`Normal`] distance.
+    ///
+    /// The default scalar is `0.001`.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn wheel_pixel_scalar(mut self, scalar: f32) -> Self {
+        self.wheel_pixel_scalar = scalar;
+        self
+    }
+
+    /// Sets the scalar to use when the user scrolls the mouse wheel while
+    /// holding down the modifier keys of a fine drag.
+    ///
+    /// For example, a scalar of `0.1` will cause the [`HSlider`] to move a
+    /// tenth of the distance it moves without them.
+    ///
+    /// The default scalar is `0.1`.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    pub fn wheel_modifier_scalar(mut self, scalar: f32) -> Self {
+        self.wheel_modifier_scalar = scalar;
+        self
+    }
+
+    /// Sets the scalar to use when the user scrolls the mouse wheel over
+    /// the [`HSlider`] while holding down the modifier keys of an ultra-fine
+    /// drag. This is used in place of `wheel_modifier_scalar`.
+    ///
+    /// An ultra-fine drag has no modifier keys by default. Bind them with
+    /// [`gestures`].
+    ///
+    /// The default scalar is `0.01`.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`gestures`]: #method.gestures
+    pub fn wheel_ultra_fine_scalar(mut self, scalar: f32) -> Self {
+        self.wheel_ultra_fine_scalar = scalar;
+        self
+    }
+
+    /// Sets the distance to move the [`HSlider`] when the user presses an
+    /// arrow key while it has keyboard focus, as a [`Normal`] distance.
+    ///
+    /// The default step is `0.01`.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn key_step(mut self, step: f32) -> Self {
+        self.key_step = step;
+        self
+    }
+
+    /// Sets the distance to move the [`HSlider`] when the user presses
+    /// `Page Up` or `Page Down` while it has keyboard focus, as a
+    /// [`Normal`] distance.
+    ///
+    /// The default step is `0.1`.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn page_step(mut self, step: f32) -> Self {
+        self.page_step = step;
+        self
+    }
+
+    /// Sets the [`GestureBindings`] of the [`HSlider`], which map clicks and
+    /// drags to actions such as a reset or a fine drag. Share the same
+    /// bindings between every widget.
+    ///
+    /// The default bindings are [`DEFAULT_BINDINGS`].
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`GestureBindings`]: ../gesture/struct.GestureBindings.html
+    /// [`DEFAULT_BINDINGS`]: ../gesture/constant.DEFAULT_BINDINGS.html
+    pub fn gestures(mut self, gestures: &'a GestureBindings) -> Self {
+        self.gestures = Some(Cow::Borrowed(gestures));
+        self
+    }
+
+    /// Sets the modifier keys of a fine drag of the [`HSlider`].
+    ///
+    /// The default modifier key is `Ctrl`.
+    ///
+    /// This binds [`Action::FineDrag`] to a drag with exactly these
+    /// modifier keys in the [`GestureBindings`] of the [`HSlider`], so call it
+    /// after [`gestures`]. A fine drag no longer starts while holding down
+    /// more keys than these, such as `Ctrl` and `Shift` for `Ctrl`.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`Action::FineDrag`]: ../gesture/enum.Action.html#variant.FineDrag
+    /// [`GestureBindings`]: ../gesture/struct.GestureBindings.html
+    /// [`gestures`]: #method.gestures
+    #[deprecated(note = "bind `gesture::Action::FineDrag` with `gestures`")]
+    pub fn modifier_keys(mut self, modifier_keys: keyboard::Modifiers) -> Self {
+        self.gestures = Some(gesture::rebind_fine_drag(
+            self.gestures.take(),
+            modifier_keys,
+        ));
+        self
+    }
+
+    /// Sets the [`DragMode`] of the [`HSlider`]. The default mode is
+    /// `DragMode::Relative`.
+    ///
+    /// [`DragMode`]: enum.DragMode.html
+    /// [`HSlider`]: struct.HSlider.html
+    pub fn drag_mode(mut self, drag_mode: DragMode) -> Self {
+        self.drag_mode = drag_mode;
+        self
+    }
+
     /// Sets the tick marks to display. Note your [`StyleSheet`] must
     /// also implement `tick_marks_style(&self) -> Option<tick_marks::Style>` for
     /// them to display (which the default style does).
@@ -178,6 +410,41 @@ impl<'a, Message, Renderer: self::Renderer> HSlider<'a, Message, Renderer> {
         self.mod_range_1 = Some(mod_range);
         self
     }
+
+    /// Enables the text entry of the [`HSlider`], where the user can type an
+    /// exact value. The typed text is parsed through `format`, which is
+    /// usually the range of the parameter.
+    ///
+    /// By default, a double-click opens the text entry instead of resetting
+    /// the [`HSlider`] to its default value. Change this with [`gestures`].
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`gestures`]: #method.gestures
+    pub fn text_entry(
+        mut self,
+        format: &'a dyn text_entry::EntryFormat,
+    ) -> Self {
+        self.text_entry = Some(format);
+        self
+    }
+
+    /// Snaps the [`HSlider`] to the values of a quantized range, such as an
+    /// [`IntRange`], a [`ChoiceRange`] or a quantized [`PitchRange`], so
+    /// that it steps between them. Each scroll of the mouse wheel and press
+    /// of an arrow key moves it by at least one value. A fine drag steps
+    /// between the fine values of the range instead, such as the whole
+    /// cents of a [`PitchRange`].
+    ///
+    /// A range that is not quantized does not snap.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`IntRange`]: ../../core/range/struct.IntRange.html
+    /// [`ChoiceRange`]: ../../core/range/struct.ChoiceRange.html
+    /// [`PitchRange`]: ../../core/range/struct.PitchRange.html
+    pub fn snap(mut self, snap: &'a dyn snap::Snap) -> Self {
+        self.snap = Some(snap);
+        self
+    }
 }
 
 /// The local state of an [`HSlider`].
@@ -194,6 +461,8 @@ pub struct State {
     continuous_normal: f32,
     pressed_modifiers: keyboard::Modifiers,
     last_click: Option<mouse::Click>,
+    focus: Focus,
+    text_entry: Option<text_entry::State>,
     tick_marks_cache: crate::graphics::tick_marks::PrimitiveCache,
     text_marks_cache: crate::graphics::text_marks::PrimitiveCache,
 }
@@ -214,6 +483,8 @@ impl State {
             continuous_normal: normal_param.value.as_f32(),
             pressed_modifiers: Default::default(),
             last_click: None,
+            focus: Focus::new(),
+            text_entry: None,
             tick_marks_cache: Default::default(),
             text_marks_cache: Default::default(),
         }
@@ -231,6 +502,48 @@ impl State {
     pub fn is_dragging(&self) -> bool {
         self.is_dragging
     }
+
+    /// Does the [`HSlider`] have keyboard focus?
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    pub fn is_focused(&self) -> bool {
+        self.focus.is_focused()
+    }
+
+    /// Gives keyboard focus to the [`HSlider`], taking it from any other
+    /// widget.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    pub fn focus(&mut self) {
+        self.focus.focus();
+    }
+
+    /// Removes keyboard focus from the [`HSlider`].
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    pub fn unfocus(&mut self) {
+        self.focus.unfocus();
+    }
+
+    /// Is the text entry of the [`HSlider`] open?
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    pub fn is_entering_text(&self) -> bool {
+        self.text_entry.is_some()
+    }
+
+    /// Returns the open text entry of the [`HSlider`], if any
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    pub fn text_entry(&self) -> Option<&text_entry::State> {
+        self.text_entry.as_ref()
+    }
+}
+
+impl From<NormalParam> for State {
+    fn from(normal_param: NormalParam) -> Self {
+        State::new(normal_param)
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer>
@@ -264,9 +577,68 @@ where
         layout: Layout<'_>,
         cursor_position: Point,
         messages: &mut Vec<Message>,
-        _renderer: &Renderer,
+        renderer: &Renderer,
         _clipboard: Option<&dyn Clipboard>,
     ) -> event::Status {
+        if let (Some(entry), Some(format)) =
+            (&mut self.state.text_entry, self.text_entry)
+        {
+            match entry.on_event(
+                &event,
+                format,
+                layout.bounds().contains(cursor_position),
+            ) {
+                Some(text_entry::Action::Edit) => {
+                    return event::Status::Captured;
+                }
+                Some(text_entry::Action::Apply(normal)) => {
+                    self.state.text_entry = None;
+                    self.state.set(snap::snapped(self.snap, normal));
+
+                    // An entered value is a complete edit gesture on its own.
+                    edit::push_gesture(
+                        messages,
+                        self.on_grab.as_deref(),
+                        (self.on_change)(self.state.normal_param.value),
+                        self.on_release.as_deref(),
+                    );
+
+                    return event::Status::Captured;
+                }
+                Some(text_entry::Action::Cancel) => {
+                    self.state.text_entry = None;
+                }
+                None => {}
+            }
+        }
+
+        if let Some(command) = self
+            .state
+            .focus
+            .on_event(&event, layout.bounds().contains(cursor_position))
+        {
+            let normal = snap::apply(
+                self.snap,
+                command,
+                self.state.normal_param.value.as_f32(),
+                self.state.normal_param.default.as_f32(),
+                self.key_step,
+                self.page_step,
+            );
+
+            self.state.set(snap::snapped(self.snap, normal.into()));
+
+            // A key press is a complete edit gesture on its own.
+            edit::push_gesture(
+                messages,
+                self.on_grab.as_deref(),
+                (self.on_change)(self.state.normal_param.value),
+                self.on_release.as_deref(),
+            );
+
+            return event::Status::Captured;
+        }
+
         match event {
             Event::Mouse(mouse_event) => match mouse_event {
                 mouse::Event::CursorMoved { .. } => {
@@ -278,14 +650,31 @@ where
                                 - self.state.prev_drag_x)
                                 / bounds_width;
 
-                            if self
-                                .state
-                                .pressed_modifiers
-                                .matches(self.modifier_keys)
-                            {
-                                movement_x *= self.modifier_scalar;
-                            } else {
-                                movement_x *= self.scalar;
+                            let action = gesture::drag_action(
+                                self.gestures.as_deref(),
+                                self.state.pressed_modifiers,
+                            );
+
+                            match action {
+                                Some(gesture::Action::FineDrag) => {
+                                    movement_x *= self.modifier_scalar;
+                                }
+                                Some(gesture::Action::UltraFineDrag) => {
+                                    movement_x *= self.ultra_fine_scalar;
+                                }
+                                _ if self.drag_mode == DragMode::Absolute => {
+                                    let normal = normal_at(
+                                        &layout.bounds(),
+                                        renderer.handle_width(&self.style),
+                                        cursor_position.x,
+                                    );
+
+                                    movement_x = normal.as_f32()
+                                        - self.state.continuous_normal;
+                                }
+                                _ => {
+                                    movement_x *= self.scalar;
+                                }
                             }
 
                             let normal =
@@ -294,7 +683,8 @@ where
                             self.state.continuous_normal = normal;
                             self.state.prev_drag_x = cursor_position.x;
 
-                            self.state.normal_param.value = normal.into();
+                            self.state.normal_param.value =
+                                snap::dragged(self.snap, normal.into(), action);
 
                             messages.push((self.on_change)(
                                 self.state.normal_param.value,
@@ -304,37 +694,150 @@ where
                         }
                     }
                 }
-                mouse::Event::ButtonPressed(mouse::Button::Left) => {
+                mouse::Event::ButtonPressed(button) => {
                     if layout.bounds().contains(cursor_position) {
-                        let click = mouse::Click::new(
-                            cursor_position,
-                            self.state.last_click,
-                        );
-
-                        match click.kind() {
-                            mouse::click::Kind::Single => {
-                                self.state.is_dragging = true;
-                                self.state.prev_drag_x = cursor_position.x;
-                            }
-                            _ => {
+                        let kind = if button == mouse::Button::Left {
+                            let click = mouse::Click::new(
+                                cursor_position,
+                                self.state.last_click,
+                            );
+                            self.state.last_click = Some(click);
+
+                            gesture::ClickKind::from(click.kind())
+                        } else {
+                            gesture::ClickKind::Single
+                        };
+
+                        match gesture::click_action(
+                            self.gestures.as_deref(),
+                            button,
+                            kind,
+                            self.state.pressed_modifiers,
+                            self.text_entry.is_some(),
+                            self.on_context_request.is_some(),
+                        ) {
+                            Some(gesture::Action::Reset) => {
                                 self.state.is_dragging = false;
 
                                 self.state.normal_param.value =
                                     self.state.normal_param.default;
 
-                                messages.push((self.on_change)(
-                                    self.state.normal_param.value,
-                                ));
+                                edit::push_gesture(
+                                    messages,
+                                    self.on_grab.as_deref(),
+                                    (self.on_change)(
+                                        self.state.normal_param.value,
+                                    ),
+                                    self.on_release.as_deref(),
+                                );
                             }
-                        }
+                            Some(gesture::Action::TextEntry) => {
+                                if let Some(format) = self.text_entry {
+                                    self.state.text_entry =
+                                        Some(text_entry::State::new(
+                                            format.entry_text(
+                                                self.state.normal_param.value,
+                                            ),
+                                        ));
+                                }
+                            }
+                            Some(gesture::Action::ContextRequest) => {
+                                if let Some(on_context_request) =
+                                    &self.on_context_request
+                                {
+                                    messages.push(on_context_request(
+                                        cursor_position,
+                                    ));
+                                }
+                            }
+                            _ if button == mouse::Button::Left => {
+                                self.state.is_dragging = true;
+
+                                if let Some(on_grab) = &self.on_grab {
+                                    messages.push(on_grab());
+                                }
+
+                                self.state.prev_drag_x = cursor_position.x;
 
-                        self.state.last_click = Some(click);
+                                let bounds = layout.bounds();
+                                let handle_width =
+                                    renderer.handle_width(&self.style);
+
+                                let jump = match self.drag_mode {
+                                    DragMode::Relative => false,
+                                    DragMode::Absolute => true,
+                                    DragMode::JumpOffHandle => !is_over_handle(
+                                        &bounds,
+                                        handle_width,
+                                        self.state.normal_param.value,
+                                        cursor_position.x,
+                                    ),
+                                };
+
+                                if jump {
+                                    self.state.set(snap::snapped(
+                                        self.snap,
+                                        normal_at(
+                                            &bounds,
+                                            handle_width,
+                                            cursor_position.x,
+                                        ),
+                                    ));
+
+                                    messages.push((self.on_change)(
+                                        self.state.normal_param.value,
+                                    ));
+                                }
+                            }
+                            _ => return event::Status::Ignored,
+                        }
 
                         return event::Status::Captured;
                     }
                 }
+                mouse::Event::WheelScrolled { delta }
+                    if !self.state.is_dragging
+                        && layout.bounds().contains(cursor_position) =>
+                {
+                    // Both axes move the slider, so a horizontal scroll
+                    // works as well as a vertical one.
+                    let (x, y) = wheel::movement(
+                        delta,
+                        self.wheel_scalar,
+                        self.wheel_pixel_scalar,
+                        gesture::drag_action(
+                            self.gestures.as_deref(),
+                            self.state.pressed_modifiers,
+                        ),
+                        self.wheel_modifier_scalar,
+                        self.wheel_ultra_fine_scalar,
+                    );
+
+                    let normal = snap::step(
+                        self.snap,
+                        self.state.normal_param.value.as_f32(),
+                        x + y,
+                    );
+
+                    self.state.set(snap::snapped(self.snap, normal.into()));
+
+                    edit::push_gesture(
+                        messages,
+                        self.on_grab.as_deref(),
+                        (self.on_change)(self.state.normal_param.value),
+                        self.on_release.as_deref(),
+                    );
+
+                    return event::Status::Captured;
+                }
                 mouse::Event::ButtonReleased(mouse::Button::Left) => {
-                    self.state.is_dragging = false;
+                    if self.state.is_dragging {
+                        self.state.is_dragging = false;
+
+                        if let Some(on_release) = &self.on_release {
+                            messages.push(on_release());
+                        }
+                    }
                     self.state.continuous_normal =
                         self.state.normal_param.value.as_f32();
 
@@ -372,8 +875,7 @@ where
         renderer.draw(
             layout.bounds(),
             cursor_position,
-            self.state.normal_param.value,
-            self.state.is_dragging,
+            self.state,
             self.mod_range_1,
             self.mod_range_2,
             self.tick_marks,
@@ -393,6 +895,29 @@ where
     }
 }
 
+/// Returns the value that puts the center of the handle at the cursor
+fn normal_at(bounds: &Rectangle, handle_width: f32, cursor_x: f32) -> Normal {
+    let width = bounds.width - handle_width;
+
+    if width <= 0.0 {
+        return Normal::min();
+    }
+
+    ((cursor_x - bounds.x - (handle_width / 2.0)) / width).into()
+}
+
+/// Returns `true` if the cursor is over the handle
+fn is_over_handle(
+    bounds: &Rectangle,
+    handle_width: f32,
+    normal: Normal,
+    cursor_x: f32,
+) -> bool {
+    let handle_x = bounds.x + normal.scale(bounds.width - handle_width);
+
+    cursor_x >= handle_x && cursor_x <= handle_x + handle_width
+}
+
 /// The renderer of an [`HSlider`].
 ///
 /// Your renderer will need to implement this trait before being
@@ -403,25 +928,31 @@ pub trait Renderer: iced_native::Renderer {
     /// The style supported by this renderer.
     type Style: Default;
 
+    /// Returns the width of the handle of an [`HSlider`] in pixels, from
+    /// the active style. A click within the handle does not jump the
+    /// handle in `DragMode::JumpOffHandle`.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    fn handle_width(&self, style: &Self::Style) -> f32;
+
     /// Draws an [`HSlider`].
     ///
     /// It receives:
     ///   * the bounds of the [`HSlider`]
     ///   * the current cursor position
-    ///   * the current normal of the [`HSlider`]
+    ///   * the [`State`] of the [`HSlider`]
     ///   * the height of the handle in pixels
-    ///   * whether the slider is currently being dragged
     ///   * any tick marks to display
     ///   * any text marks to display
     ///   * the style of the [`HSlider`]
     ///
     /// [`HSlider`]: struct.HSlider.html
+    /// [`State`]: struct.State.html
     fn draw(
         &mut self,
         bounds: Rectangle,
         cursor_position: Point,
-        normal: Normal,
-        is_dragging: bool,
+        state: &State,
         mod_range_1: Option<&ModulationRange>,
         mod_range_2: Option<&ModulationRange>,
         tick_marks: Option<&tick_marks::Group>,
@@ -2,6 +2,7 @@
 //!
 //! [`NormalParam`]: ../core/normal_param/struct.NormalParam.html
 
+use std::borrow::Cow;
 use std::fmt::Debug;
 
 use iced_native::{
@@ -12,11 +13,52 @@ use iced_native::{
 use std::hash::Hash;
 
 use crate::core::{ModulationRange, Normal, NormalParam};
-use crate::native::{text_marks, tick_marks};
+use crate::native::focus::Focus;
+use crate::native::gesture::{self, GestureBindings};
+use crate::native::{edit, snap, text_entry, text_marks, tick_marks, wheel};
 
 static DEFAULT_SIZE: u16 = 30;
 static DEFAULT_SCALAR: f32 = 0.00385;
 static DEFAULT_MODIFIER_SCALAR: f32 = 0.02;
+static DEFAULT_ULTRA_FINE_SCALAR: f32 = 0.002;
+static DEFAULT_WHEEL_SCALAR: f32 = 0.01;
+static DEFAULT_WHEEL_PIXEL_SCALAR: f32 = 0.001;
+static DEFAULT_WHEEL_MODIFIER_SCALAR: f32 = 0.1;
+static DEFAULT_WHEEL_ULTRA_FINE_SCALAR: f32 = 0.01;
+static DEFAULT_KEY_STEP: f32 = 0.01;
+static DEFAULT_PAGE_STEP: f32 = 0.1;
+
+/// How a [`Knob`] turns across its range of values
+///
+/// The [`KnobAngleRange`] of the style sheet is the arc of the value, tick
+/// marks and text marks within one circle. A turn beyond a full circle
+/// cannot be drawn as such an arc, so it is a `TurnMode` of the [`Knob`]
+/// rather than an angle range outside `0` to `2*PI`.
+///
+/// [`Knob`]: struct.Knob.html
+/// [`KnobAngleRange`]: ../../core/knob_angle_range/struct.KnobAngleRange.html
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum TurnMode {
+    /// The knob turns once between its minimum and maximum angle, and stops
+    /// at both ends
+    #[default]
+    Bounded,
+    /// The knob turns without end stops, such as an endless rotary encoder
+    /// for a phase. The value wraps around from `1.0` back to `0.0`, and
+    /// the knob is drawn as a full circle, in place of the
+    /// [`KnobAngleRange`].
+    ///
+    /// [`KnobAngleRange`]: ../../core/knob_angle_range/struct.KnobAngleRange.html
+    Endless,
+    /// The notch of the knob rotates the given number of times across the
+    /// range of values, such as for a fine tune knob. Each turn takes as
+    /// much mouse movement as the whole range of a `Bounded` knob. The
+    /// value arc, tick marks and text marks still span the
+    /// [`KnobAngleRange`] once.
+    ///
+    /// [`KnobAngleRange`]: ../../core/knob_angle_range/struct.KnobAngleRange.html
+    MultiTurn(u16),
+}
 
 /// A rotating knob GUI widget that controls a [`NormalParam`]
 ///
@@ -26,14 +68,27 @@ pub struct Knob<'a, Message, Renderer: self::Renderer> {
     state: &'a mut State,
     size: Length,
     on_change: Box<dyn Fn(Normal) -> Message>,
+    on_grab: Option<Box<dyn Fn() -> Message>>,
+    on_release: Option<Box<dyn Fn() -> Message>>,
+    on_context_request: Option<Box<dyn Fn(Point) -> Message>>,
     scalar: f32,
     modifier_scalar: f32,
-    modifier_keys: keyboard::Modifiers,
+    ultra_fine_scalar: f32,
+    wheel_scalar: f32,
+    wheel_pixel_scalar: f32,
+    wheel_modifier_scalar: f32,
+    wheel_ultra_fine_scalar: f32,
+    key_step: f32,
+    page_step: f32,
+    gestures: Option<Cow<'a, GestureBindings>>,
+    turn_mode: TurnMode,
     style: Renderer::Style,
     tick_marks: Option<&'a tick_marks::Group>,
     text_marks: Option<&'a text_marks::Group>,
     mod_range_1: Option<&'a ModulationRange>,
     mod_range_2: Option<&'a ModulationRange>,
+    text_entry: Option<&'a dyn text_entry::EntryFormat>,
+    snap: Option<&'a dyn snap::Snap>,
 }
 
 impl<'a, Message, Renderer: self::Renderer> Knob<'a, Message, Renderer> {
@@ -53,20 +108,78 @@ impl<'a, Message, Renderer: self::Renderer> Knob<'a, Message, Renderer> {
             state,
             size: Length::from(Length::Units(DEFAULT_SIZE)),
             on_change: Box::new(on_change),
+            on_grab: None,
+            on_release: None,
+            on_context_request: None,
             scalar: DEFAULT_SCALAR,
             modifier_scalar: DEFAULT_MODIFIER_SCALAR,
-            modifier_keys: keyboard::Modifiers {
-                control: true,
-                ..Default::default()
-            },
+            ultra_fine_scalar: DEFAULT_ULTRA_FINE_SCALAR,
+            wheel_scalar: DEFAULT_WHEEL_SCALAR,
+            wheel_pixel_scalar: DEFAULT_WHEEL_PIXEL_SCALAR,
+            wheel_modifier_scalar: DEFAULT_WHEEL_MODIFIER_SCALAR,
+            wheel_ultra_fine_scalar: DEFAULT_WHEEL_ULTRA_FINE_SCALAR,
+            key_step: DEFAULT_KEY_STEP,
+            page_step: DEFAULT_PAGE_STEP,
+            gestures: None,
+            turn_mode: TurnMode::default(),
             style: Renderer::Style::default(),
             tick_marks: None,
             text_marks: None,
             mod_range_1: None,
             mod_range_2: None,
+            text_entry: None,
+            snap: None,
         }
     }
 
+    /// Sets the message that will be produced when the user grabs the
+    /// [`Knob`] to begin an edit gesture.
+    ///
+    /// A host needs this to begin recording automation. It is followed by
+    /// the messages of `on_change`, and then by the message of
+    /// [`on_release`].
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`on_release`]: #method.on_release
+    pub fn on_grab<F>(mut self, on_grab: F) -> Self
+    where
+        F: 'static + Fn() -> Message,
+    {
+        self.on_grab = Some(Box::new(on_grab));
+        self
+    }
+
+    /// Sets the message that will be produced when the user releases the
+    /// [`Knob`] to end an edit gesture.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    pub fn on_release<F>(mut self, on_release: F) -> Self
+    where
+        F: 'static + Fn() -> Message,
+    {
+        self.on_release = Some(Box::new(on_release));
+        self
+    }
+
+    /// Sets the message that will be produced when the user asks for the
+    /// context of the [`Knob`], such as to open a context menu. The message
+    /// receives the position of the cursor.
+    ///
+    /// No gesture asks for the context by default. Bind
+    /// [`Action::ContextRequest`] with [`gestures`], such as to a
+    /// right-click.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`Action::ContextRequest`]: ../gesture/enum.Action.html#variant.ContextRequest
+    /// [`gestures`]: #method.gestures
+    pub fn on_context_request<F>(mut self, on_context_request: F) -> Self
+    where
+        F: 'static + Fn(Point) -> Message,
+    {
+        self.on_context_request = Some(Box::new(on_context_request));
+        self
+    }
+
     /// Sets the diameter of the [`Knob`]. The default size is
     /// `Length::from(Length::Units(31))`.
     ///
@@ -76,6 +189,16 @@ impl<'a, Message, Renderer: self::Renderer> Knob<'a, Message, Renderer> {
         self
     }
 
+    /// Sets the [`TurnMode`] of the [`Knob`]. The default mode is
+    /// `TurnMode::Bounded`.
+    ///
+    /// [`TurnMode`]: enum.TurnMode.html
+    /// [`Knob`]: struct.Knob.html
+    pub fn turn_mode(mut self, turn_mode: TurnMode) -> Self {
+        self.turn_mode = turn_mode;
+        self
+    }
+
     /// Sets the style of the [`Knob`].
     ///
     /// [`Knob`]: struct.Knob.html
@@ -96,29 +219,150 @@ impl<'a, Message, Renderer: self::Renderer> Knob<'a, Message, Renderer> {
         self
     }
 
-    /// Sets the modifier keys of the [`Knob`].
+    /// Sets the scalar to use when the user drags the knobs while holding down
+    /// the modifier keys of a fine drag. This is multiplied to the value set by
+    /// `Knob::scalar()` (which the default is `0.00385`).
     ///
-    /// The default modifier key is `Ctrl`.
+    /// For example, a `modifier_scalar` of `0.5` will cause the knob to turn
+    /// half as fast during a fine drag.
+    ///
+    /// The default `modifier_scalar` is `0.02`.
     ///
     /// [`Knob`]: struct.Knob.html
-    pub fn modifier_keys(mut self, modifier_keys: keyboard::Modifiers) -> Self {
-        self.modifier_keys = modifier_keys;
+    pub fn modifier_scalar(mut self, scalar: f32) -> Self {
+        self.modifier_scalar = scalar;
         self
     }
 
-    /// Sets the scalar to use when the user drags the knobs while holding down
-    /// the modifier key. This is multiplied to the value set by
-    /// `Knob::scalar()` (which the default is `0.00385`).
+    /// Sets the scalar to use when the user drags the [`Knob`] while holding
+    /// down the modifier keys of an ultra-fine drag. This is used in place
+    /// of `modifier_scalar`, and is also multiplied to the value set by
+    /// `Knob::scalar()`.
     ///
-    /// For example, a `modifier_scalar` of `0.5` will cause the knob to turn
-    /// half as fast when the modifier key is down.
+    /// An ultra-fine drag has no modifier keys by default. Bind them with
+    /// [`gestures`].
     ///
-    /// The default `modifier_scalar` is `0.02`, and the default modifier key
-    /// is `Ctrl`.
+    /// The default scalar is `0.002`.
     ///
     /// [`Knob`]: struct.Knob.html
-    pub fn modifier_scalar(mut self, scalar: f32) -> Self {
-        self.modifier_scalar = scalar;
+    /// [`gestures`]: #method.gestures
+    pub fn ultra_fine_scalar(mut self, scalar: f32) -> Self {
+        self.ultra_fine_scalar = scalar;
+        self
+    }
+
+    /// Sets the distance to move the [`Knob`] for each line the mouse
+    /// wheel scrolls, as a [`Normal`] distance.
+    ///
+    /// The default scalar is `0.01`.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn wheel_scalar(mut self, scalar: f32) -> Self {
+        self.wheel_scalar = scalar;
+        self
+    }
+
+    /// Sets the distance to move the [`Knob`] for each pixel the mouse
+    /// wheel or touchpad scrolls, as a [`Normal`] distance.
+    ///
+    /// The default scalar is `0.001`.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn wheel_pixel_scalar(mut self, scalar: f32) -> Self {
+        self.wheel_pixel_scalar = scalar;
+        self
+    }
+
+    /// Sets the scalar to use when the user scrolls the mouse wheel while
+    /// holding down the modifier keys of a fine drag.
+    ///
+    /// For example, a scalar of `0.1` will cause the [`Knob`] to move a
+    /// tenth of the distance it moves without them.
+    ///
+    /// The default scalar is `0.1`.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    pub fn wheel_modifier_scalar(mut self, scalar: f32) -> Self {
+        self.wheel_modifier_scalar = scalar;
+        self
+    }
+
+    /// Sets the scalar to use when the user scrolls the mouse wheel over
+    /// the [`Knob`] while holding down the modifier keys of an ultra-fine
+    /// drag. This is used in place of `wheel_modifier_scalar`.
+    ///
+    /// An ultra-fine drag has no modifier keys by default. Bind them with
+    /// [`gestures`].
+    ///
+    /// The default scalar is `0.01`.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`gestures`]: #method.gestures
+    pub fn wheel_ultra_fine_scalar(mut self, scalar: f32) -> Self {
+        self.wheel_ultra_fine_scalar = scalar;
+        self
+    }
+
+    /// Sets the distance to move the [`Knob`] when the user presses an
+    /// arrow key while it has keyboard focus, as a [`Normal`] distance.
+    ///
+    /// The default step is `0.01`.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn key_step(mut self, step: f32) -> Self {
+        self.key_step = step;
+        self
+    }
+
+    /// Sets the distance to move the [`Knob`] when the user presses
+    /// `Page Up` or `Page Down` while it has keyboard focus, as a
+    /// [`Normal`] distance.
+    ///
+    /// The default step is `0.1`.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn page_step(mut self, step: f32) -> Self {
+        self.page_step = step;
+        self
+    }
+
+    /// Sets the [`GestureBindings`] of the [`Knob`], which map clicks and
+    /// drags to actions such as a reset or a fine drag. Share the same
+    /// bindings between every widget.
+    ///
+    /// The default bindings are [`DEFAULT_BINDINGS`].
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`GestureBindings`]: ../gesture/struct.GestureBindings.html
+    /// [`DEFAULT_BINDINGS`]: ../gesture/constant.DEFAULT_BINDINGS.html
+    pub fn gestures(mut self, gestures: &'a GestureBindings) -> Self {
+        self.gestures = Some(Cow::Borrowed(gestures));
+        self
+    }
+
+    /// Sets the modifier keys of a fine drag of the [`Knob`].
+    ///
+    /// The default modifier key is `Ctrl`.
+    ///
+    /// This binds [`Action::FineDrag`] to a drag with exactly these
+    /// modifier keys in the [`GestureBindings`] of the [`Knob`], so call it
+    /// after [`gestures`]. A fine drag no longer starts while holding down
+    /// more keys than these, such as `Ctrl` and `Shift` for `Ctrl`.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`Action::FineDrag`]: ../gesture/enum.Action.html#variant.FineDrag
+    /// [`GestureBindings`]: ../gesture/struct.GestureBindings.html
+    /// [`gestures`]: #method.gestures
+    #[deprecated(note = "bind `gesture::Action::FineDrag` with `gestures`")]
+    pub fn modifier_keys(mut self, modifier_keys: keyboard::Modifiers) -> Self {
+        self.gestures = Some(gesture::rebind_fine_drag(
+            self.gestures.take(),
+            modifier_keys,
+        ));
         self
     }
 
@@ -163,6 +407,41 @@ impl<'a, Message, Renderer: self::Renderer> Knob<'a, Message, Renderer> {
         self.mod_range_1 = Some(mod_range);
         self
     }
+
+    /// Enables the text entry of the [`Knob`], where the user can type an
+    /// exact value. The typed text is parsed through `format`, which is
+    /// usually the range of the parameter.
+    ///
+    /// By default, a double-click opens the text entry instead of resetting
+    /// the [`Knob`] to its default value. Change this with [`gestures`].
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`gestures`]: #method.gestures
+    pub fn text_entry(
+        mut self,
+        format: &'a dyn text_entry::EntryFormat,
+    ) -> Self {
+        self.text_entry = Some(format);
+        self
+    }
+
+    /// Snaps the [`Knob`] to the values of a quantized range, such as an
+    /// [`IntRange`], a [`ChoiceRange`] or a quantized [`PitchRange`], so
+    /// that it steps between them. Each scroll of the mouse wheel and press
+    /// of an arrow key moves it by at least one value. A fine drag steps
+    /// between the fine values of the range instead, such as the whole
+    /// cents of a [`PitchRange`].
+    ///
+    /// A range that is not quantized does not snap.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`IntRange`]: ../../core/range/struct.IntRange.html
+    /// [`ChoiceRange`]: ../../core/range/struct.ChoiceRange.html
+    /// [`PitchRange`]: ../../core/range/struct.PitchRange.html
+    pub fn snap(mut self, snap: &'a dyn snap::Snap) -> Self {
+        self.snap = Some(snap);
+        self
+    }
 }
 
 /// The local state of a [`Knob`].
@@ -179,6 +458,8 @@ pub struct State {
     continuous_normal: f32,
     pressed_modifiers: keyboard::Modifiers,
     last_click: Option<mouse::Click>,
+    focus: Focus,
+    text_entry: Option<text_entry::State>,
     tick_marks_cache: crate::graphics::tick_marks::PrimitiveCache,
     text_marks_cache: crate::graphics::text_marks::PrimitiveCache,
 }
@@ -199,6 +480,8 @@ impl State {
             continuous_normal: normal_param.value.as_f32(),
             pressed_modifiers: Default::default(),
             last_click: None,
+            focus: Focus::new(),
+            text_entry: None,
             tick_marks_cache: Default::default(),
             text_marks_cache: Default::default(),
         }
@@ -216,6 +499,48 @@ impl State {
     pub fn is_dragging(&self) -> bool {
         self.is_dragging
     }
+
+    /// Does the [`Knob`] have keyboard focus?
+    ///
+    /// [`Knob`]: struct.Knob.html
+    pub fn is_focused(&self) -> bool {
+        self.focus.is_focused()
+    }
+
+    /// Gives keyboard focus to the [`Knob`], taking it from any other
+    /// widget.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    pub fn focus(&mut self) {
+        self.focus.focus();
+    }
+
+    /// Removes keyboard focus from the [`Knob`].
+    ///
+    /// [`Knob`]: struct.Knob.html
+    pub fn unfocus(&mut self) {
+        self.focus.unfocus();
+    }
+
+    /// Is the text entry of the [`Knob`] open?
+    ///
+    /// [`Knob`]: struct.Knob.html
+    pub fn is_entering_text(&self) -> bool {
+        self.text_entry.is_some()
+    }
+
+    /// Returns the open text entry of the [`Knob`], if any
+    ///
+    /// [`Knob`]: struct.Knob.html
+    pub fn text_entry(&self) -> Option<&text_entry::State> {
+        self.text_entry.as_ref()
+    }
+}
+
+impl From<NormalParam> for State {
+    fn from(normal_param: NormalParam) -> Self {
+        State::new(normal_param)
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer>
@@ -252,6 +577,71 @@ where
         _renderer: &Renderer,
         _clipboard: Option<&dyn Clipboard>,
     ) -> event::Status {
+        if let (Some(entry), Some(format)) =
+            (&mut self.state.text_entry, self.text_entry)
+        {
+            match entry.on_event(
+                &event,
+                format,
+                layout.bounds().contains(cursor_position),
+            ) {
+                Some(text_entry::Action::Edit) => {
+                    return event::Status::Captured;
+                }
+                Some(text_entry::Action::Apply(normal)) => {
+                    self.state.text_entry = None;
+                    self.state.set(snap::snapped(self.snap, normal));
+
+                    // An entered value is a complete edit gesture on its own.
+                    edit::push_gesture(
+                        messages,
+                        self.on_grab.as_deref(),
+                        (self.on_change)(self.state.normal_param.value),
+                        self.on_release.as_deref(),
+                    );
+
+                    return event::Status::Captured;
+                }
+                Some(text_entry::Action::Cancel) => {
+                    self.state.text_entry = None;
+                }
+                None => {}
+            }
+        }
+
+        if let Some(command) = self
+            .state
+            .focus
+            .on_event(&event, layout.bounds().contains(cursor_position))
+        {
+            let mut normal = snap::apply(
+                self.snap,
+                command,
+                self.state.normal_param.value.as_f32(),
+                self.state.normal_param.default.as_f32(),
+                self.key_step,
+                self.page_step,
+            );
+
+            if self.turn_mode == TurnMode::Endless
+                && !(0.0..=1.0).contains(&normal)
+            {
+                normal = normal.rem_euclid(1.0);
+            }
+
+            self.state.set(snap::snapped(self.snap, normal.into()));
+
+            // A key press is a complete edit gesture on its own.
+            edit::push_gesture(
+                messages,
+                self.on_grab.as_deref(),
+                (self.on_change)(self.state.normal_param.value),
+                self.on_release.as_deref(),
+            );
+
+            return event::Status::Captured;
+        }
+
         match event {
             Event::Mouse(mouse_event) => match mouse_event {
                 mouse::Event::CursorMoved { .. } => {
@@ -260,18 +650,31 @@ where
                             - self.state.prev_drag_y)
                             * self.scalar;
 
-                        if self
-                            .state
-                            .pressed_modifiers
-                            .matches(self.modifier_keys)
-                        {
-                            movement_y *= self.modifier_scalar;
+                        let action = gesture::drag_action(
+                            self.gestures.as_deref(),
+                            self.state.pressed_modifiers,
+                        );
+
+                        match action {
+                            Some(gesture::Action::FineDrag) => {
+                                movement_y *= self.modifier_scalar;
+                            }
+                            Some(gesture::Action::UltraFineDrag) => {
+                                movement_y *= self.ultra_fine_scalar;
+                            }
+                            _ => {}
+                        }
+
+                        if let TurnMode::MultiTurn(turns) = self.turn_mode {
+                            movement_y /= f32::from(turns.max(1));
                         }
 
                         let mut normal =
                             self.state.continuous_normal - movement_y;
 
-                        if normal < 0.0 {
+                        if self.turn_mode == TurnMode::Endless {
+                            normal = normal.rem_euclid(1.0);
+                        } else if normal < 0.0 {
                             normal = 0.0;
                         } else if normal > 1.0 {
                             normal = 1.0;
@@ -280,7 +683,8 @@ where
                         self.state.continuous_normal = normal;
                         self.state.prev_drag_y = cursor_position.y;
 
-                        self.state.normal_param.value = normal.into();
+                        self.state.normal_param.value =
+                            snap::dragged(self.snap, normal.into(), action);
 
                         messages.push((self.on_change)(
                             self.state.normal_param.value,
@@ -289,37 +693,126 @@ where
                         return event::Status::Captured;
                     }
                 }
-                mouse::Event::ButtonPressed(mouse::Button::Left) => {
+                mouse::Event::ButtonPressed(button) => {
                     if layout.bounds().contains(cursor_position) {
-                        let click = mouse::Click::new(
-                            cursor_position,
-                            self.state.last_click,
-                        );
-
-                        match click.kind() {
-                            mouse::click::Kind::Single => {
-                                self.state.is_dragging = true;
-                                self.state.prev_drag_y = cursor_position.y;
-                            }
-                            _ => {
+                        let kind = if button == mouse::Button::Left {
+                            let click = mouse::Click::new(
+                                cursor_position,
+                                self.state.last_click,
+                            );
+                            self.state.last_click = Some(click);
+
+                            gesture::ClickKind::from(click.kind())
+                        } else {
+                            gesture::ClickKind::Single
+                        };
+
+                        match gesture::click_action(
+                            self.gestures.as_deref(),
+                            button,
+                            kind,
+                            self.state.pressed_modifiers,
+                            self.text_entry.is_some(),
+                            self.on_context_request.is_some(),
+                        ) {
+                            Some(gesture::Action::Reset) => {
                                 self.state.is_dragging = false;
 
                                 self.state.normal_param.value =
                                     self.state.normal_param.default;
 
-                                messages.push((self.on_change)(
-                                    self.state.normal_param.value,
-                                ));
+                                edit::push_gesture(
+                                    messages,
+                                    self.on_grab.as_deref(),
+                                    (self.on_change)(
+                                        self.state.normal_param.value,
+                                    ),
+                                    self.on_release.as_deref(),
+                                );
                             }
-                        }
+                            Some(gesture::Action::TextEntry) => {
+                                if let Some(format) = self.text_entry {
+                                    self.state.text_entry =
+                                        Some(text_entry::State::new(
+                                            format.entry_text(
+                                                self.state.normal_param.value,
+                                            ),
+                                        ));
+                                }
+                            }
+                            Some(gesture::Action::ContextRequest) => {
+                                if let Some(on_context_request) =
+                                    &self.on_context_request
+                                {
+                                    messages.push(on_context_request(
+                                        cursor_position,
+                                    ));
+                                }
+                            }
+                            _ if button == mouse::Button::Left => {
+                                self.state.is_dragging = true;
 
-                        self.state.last_click = Some(click);
+                                if let Some(on_grab) = &self.on_grab {
+                                    messages.push(on_grab());
+                                }
+
+                                self.state.prev_drag_y = cursor_position.y;
+                            }
+                            _ => return event::Status::Ignored,
+                        }
 
                         return event::Status::Captured;
                     }
                 }
+                mouse::Event::WheelScrolled { delta }
+                    if !self.state.is_dragging
+                        && layout.bounds().contains(cursor_position) =>
+                {
+                    let (_, mut movement) = wheel::movement(
+                        delta,
+                        self.wheel_scalar,
+                        self.wheel_pixel_scalar,
+                        gesture::drag_action(
+                            self.gestures.as_deref(),
+                            self.state.pressed_modifiers,
+                        ),
+                        self.wheel_modifier_scalar,
+                        self.wheel_ultra_fine_scalar,
+                    );
+
+                    if let TurnMode::MultiTurn(turns) = self.turn_mode {
+                        movement /= f32::from(turns.max(1));
+                    }
+
+                    let mut normal = snap::step(
+                        self.snap,
+                        self.state.normal_param.value.as_f32(),
+                        movement,
+                    );
+
+                    if self.turn_mode == TurnMode::Endless {
+                        normal = normal.rem_euclid(1.0);
+                    }
+
+                    self.state.set(snap::snapped(self.snap, normal.into()));
+
+                    edit::push_gesture(
+                        messages,
+                        self.on_grab.as_deref(),
+                        (self.on_change)(self.state.normal_param.value),
+                        self.on_release.as_deref(),
+                    );
+
+                    return event::Status::Captured;
+                }
                 mouse::Event::ButtonReleased(mouse::Button::Left) => {
-                    self.state.is_dragging = false;
+                    if self.state.is_dragging {
+                        self.state.is_dragging = false;
+
+                        if let Some(on_release) = &self.on_release {
+                            messages.push(on_release());
+                        }
+                    }
                     self.state.continuous_normal =
                         self.state.normal_param.value.as_f32();
 
@@ -357,8 +850,8 @@ where
         renderer.draw(
             layout.bounds(),
             cursor_position,
-            self.state.normal_param.value,
-            self.state.is_dragging,
+            self.state,
+            self.turn_mode,
             self.mod_range_1,
             self.mod_range_2,
             self.tick_marks,
@@ -392,19 +885,22 @@ pub trait Renderer: iced_native::Renderer {
     /// It receives:
     ///   * the bounds of the [`Knob`]
     ///   * the current cursor position
-    ///   * the current normal of the [`Knob`]
-    ///   * whether the knob is currently being dragged
+    ///   * the [`State`] of the [`Knob`]
+    ///   * the [`TurnMode`] of the [`Knob`]
+    ///   * any modulation ranges to display
     ///   * any tick marks to display
     ///   * any text marks to display
     ///   * the style of the [`Knob`]
     ///
     /// [`Knob`]: struct.Knob.html
+    /// [`TurnMode`]: enum.TurnMode.html
+    /// [`State`]: struct.State.html
     fn draw(
         &mut self,
         bounds: Rectangle,
         cursor_position: Point,
-        normal: Normal,
-        is_dragging: bool,
+        state: &State,
+        turn_mode: TurnMode,
         mod_range_1: Option<&ModulationRange>,
         mod_range_2: Option<&ModulationRange>,
         tick_marks: Option<&tick_marks::Group>,
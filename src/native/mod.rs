@@ -1,14 +1,21 @@
 //! A renderer-agnostic native GUI runtime for Iced Audio.
 
+pub mod gesture;
 pub mod h_slider;
 pub mod knob;
 pub mod mod_range_input;
 pub mod ramp;
+pub mod snap;
+pub mod text_entry;
 pub mod text_marks;
 pub mod tick_marks;
 pub mod v_slider;
 pub mod xy_pad;
 
+mod edit;
+mod focus;
+mod wheel;
+
 #[doc(no_inline)]
 pub use h_slider::HSlider;
 #[doc(no_inline)]
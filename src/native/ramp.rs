@@ -3,6 +3,7 @@
 //!
 //! [`NormalParam`]: ../core/normal_param/struct.NormalParam.html
 
+use std::borrow::Cow;
 use std::fmt::Debug;
 
 use iced_native::{
@@ -13,11 +14,22 @@ use iced_native::{
 use std::hash::Hash;
 
 use crate::core::{Normal, NormalParam};
+use crate::native::edit;
+use crate::native::focus::Focus;
+use crate::native::gesture::{self, GestureBindings};
+use crate::native::wheel;
 
 static DEFAULT_WIDTH: u16 = 40;
 static DEFAULT_HEIGHT: u16 = 20;
 static DEFAULT_SCALAR: f32 = 0.00385;
 static DEFAULT_MODIFIER_SCALAR: f32 = 0.02;
+static DEFAULT_ULTRA_FINE_SCALAR: f32 = 0.002;
+static DEFAULT_WHEEL_SCALAR: f32 = 0.01;
+static DEFAULT_WHEEL_PIXEL_SCALAR: f32 = 0.001;
+static DEFAULT_WHEEL_MODIFIER_SCALAR: f32 = 0.1;
+static DEFAULT_WHEEL_ULTRA_FINE_SCALAR: f32 = 0.01;
+static DEFAULT_KEY_STEP: f32 = 0.01;
+static DEFAULT_PAGE_STEP: f32 = 0.1;
 
 /// The direction of a [`Ramp`] widget.
 #[derive(Debug, Copy, Clone)]
@@ -43,9 +55,19 @@ impl Default for RampDirection {
 pub struct Ramp<'a, Message, Renderer: self::Renderer> {
     state: &'a mut State,
     on_change: Box<dyn Fn(Normal) -> Message>,
+    on_grab: Option<Box<dyn Fn() -> Message>>,
+    on_release: Option<Box<dyn Fn() -> Message>>,
+    on_context_request: Option<Box<dyn Fn(Point) -> Message>>,
     scalar: f32,
     modifier_scalar: f32,
-    modifier_keys: keyboard::Modifiers,
+    ultra_fine_scalar: f32,
+    wheel_scalar: f32,
+    wheel_pixel_scalar: f32,
+    wheel_modifier_scalar: f32,
+    wheel_ultra_fine_scalar: f32,
+    key_step: f32,
+    page_step: f32,
+    gestures: Option<Cow<'a, GestureBindings>>,
     width: Length,
     height: Length,
     style: Renderer::Style,
@@ -76,12 +98,19 @@ impl<'a, Message, Renderer: self::Renderer> Ramp<'a, Message, Renderer> {
         Ramp {
             state,
             on_change: Box::new(on_change),
+            on_grab: None,
+            on_release: None,
+            on_context_request: None,
             scalar: DEFAULT_SCALAR,
             modifier_scalar: DEFAULT_MODIFIER_SCALAR,
-            modifier_keys: keyboard::Modifiers {
-                control: true,
-                ..Default::default()
-            },
+            ultra_fine_scalar: DEFAULT_ULTRA_FINE_SCALAR,
+            wheel_scalar: DEFAULT_WHEEL_SCALAR,
+            wheel_pixel_scalar: DEFAULT_WHEEL_PIXEL_SCALAR,
+            wheel_modifier_scalar: DEFAULT_WHEEL_MODIFIER_SCALAR,
+            wheel_ultra_fine_scalar: DEFAULT_WHEEL_ULTRA_FINE_SCALAR,
+            key_step: DEFAULT_KEY_STEP,
+            page_step: DEFAULT_PAGE_STEP,
+            gestures: None,
             width: Length::from(Length::Units(DEFAULT_WIDTH)),
             height: Length::from(Length::Units(DEFAULT_HEIGHT)),
             style: Renderer::Style::default(),
@@ -89,6 +118,54 @@ impl<'a, Message, Renderer: self::Renderer> Ramp<'a, Message, Renderer> {
         }
     }
 
+    /// Sets the message that will be produced when the user grabs the
+    /// [`Ramp`] to begin an edit gesture.
+    ///
+    /// A host needs this to begin recording automation. It is followed by
+    /// the messages of `on_change`, and then by the message of
+    /// [`on_release`].
+    ///
+    /// [`Ramp`]: struct.Ramp.html
+    /// [`on_release`]: #method.on_release
+    pub fn on_grab<F>(mut self, on_grab: F) -> Self
+    where
+        F: 'static + Fn() -> Message,
+    {
+        self.on_grab = Some(Box::new(on_grab));
+        self
+    }
+
+    /// Sets the message that will be produced when the user releases the
+    /// [`Ramp`] to end an edit gesture.
+    ///
+    /// [`Ramp`]: struct.Ramp.html
+    pub fn on_release<F>(mut self, on_release: F) -> Self
+    where
+        F: 'static + Fn() -> Message,
+    {
+        self.on_release = Some(Box::new(on_release));
+        self
+    }
+
+    /// Sets the message that will be produced when the user asks for the
+    /// context of the [`Ramp`], such as to open a context menu. The message
+    /// receives the position of the cursor.
+    ///
+    /// No gesture asks for the context by default. Bind
+    /// [`Action::ContextRequest`] with [`gestures`], such as to a
+    /// right-click.
+    ///
+    /// [`Ramp`]: struct.Ramp.html
+    /// [`Action::ContextRequest`]: ../gesture/enum.Action.html#variant.ContextRequest
+    /// [`gestures`]: #method.gestures
+    pub fn on_context_request<F>(mut self, on_context_request: F) -> Self
+    where
+        F: 'static + Fn(Point) -> Message,
+    {
+        self.on_context_request = Some(Box::new(on_context_request));
+        self
+    }
+
     /// Sets the width of the [`Ramp`].
     /// The default width is `Length::from(Length::Units(30))`.
     ///
@@ -127,29 +204,150 @@ impl<'a, Message, Renderer: self::Renderer> Ramp<'a, Message, Renderer> {
         self
     }
 
-    /// Sets the modifier keys of the [`Ramp`].
+    /// Sets the scalar to use when the user drags the Ramps while holding down
+    /// the modifier keys of a fine drag. This is multiplied to the value set by
+    /// `Ramp::scalar()` (which the default is `0.00385`).
     ///
-    /// The default modifier key is `Ctrl`.
+    /// For example, a `modifier_scalar` of `0.5` will cause the ramp to move
+    /// half as fast during a fine drag.
+    ///
+    /// The default `modifier_scalar` is `0.02`.
     ///
     /// [`Ramp`]: struct.Ramp.html
-    pub fn modifier_keys(mut self, modifier_keys: keyboard::Modifiers) -> Self {
-        self.modifier_keys = modifier_keys;
+    pub fn modifier_scalar(mut self, scalar: f32) -> Self {
+        self.modifier_scalar = scalar;
         self
     }
 
-    /// Sets the scalar to use when the user drags the Ramps while holding down
-    /// the modifier key. This is multiplied to the value set by
-    /// `Ramp::scalar()` (which the default is `0.00385`).
+    /// Sets the scalar to use when the user drags the [`Ramp`] while holding
+    /// down the modifier keys of an ultra-fine drag. This is used in place
+    /// of `modifier_scalar`, and is also multiplied to the value set by
+    /// `Ramp::scalar()`.
     ///
-    /// For example, a `modifier_scalar` of `0.5` will cause the ramp to move
-    /// half as fast when the modifier key is down.
+    /// An ultra-fine drag has no modifier keys by default. Bind them with
+    /// [`gestures`].
     ///
-    /// The default `modifier_scalar` is `0.02`, and the default modifier key
-    /// is `Ctrl`.
+    /// The default scalar is `0.002`.
     ///
     /// [`Ramp`]: struct.Ramp.html
-    pub fn modifier_scalar(mut self, scalar: f32) -> Self {
-        self.modifier_scalar = scalar;
+    /// [`gestures`]: #method.gestures
+    pub fn ultra_fine_scalar(mut self, scalar: f32) -> Self {
+        self.ultra_fine_scalar = scalar;
+        self
+    }
+
+    /// Sets the distance to move the [`Ramp`] for each line the mouse
+    /// wheel scrolls, as a [`Normal`] distance.
+    ///
+    /// The default scalar is `0.01`.
+    ///
+    /// [`Ramp`]: struct.Ramp.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn wheel_scalar(mut self, scalar: f32) -> Self {
+        self.wheel_scalar = scalar;
+        self
+    }
+
+    /// Sets the distance to move the [`Ramp`] for each pixel the mouse
+    /// wheel or touchpad scrolls, as a [`Normal`] distance.
+    ///
+    /// The default scalar is `0.001`.
+    ///
+    /// [`Ramp`]: struct.Ramp.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn wheel_pixel_scalar(mut self, scalar: f32) -> Self {
+        self.wheel_pixel_scalar = scalar;
+        self
+    }
+
+    /// Sets the scalar to use when the user scrolls the mouse wheel while
+    /// holding down the modifier keys of a fine drag.
+    ///
+    /// For example, a scalar of `0.1` will cause the [`Ramp`] to move a
+    /// tenth of the distance it moves without them.
+    ///
+    /// The default scalar is `0.1`.
+    ///
+    /// [`Ramp`]: struct.Ramp.html
+    pub fn wheel_modifier_scalar(mut self, scalar: f32) -> Self {
+        self.wheel_modifier_scalar = scalar;
+        self
+    }
+
+    /// Sets the scalar to use when the user scrolls the mouse wheel over
+    /// the [`Ramp`] while holding down the modifier keys of an ultra-fine
+    /// drag. This is used in place of `wheel_modifier_scalar`.
+    ///
+    /// An ultra-fine drag has no modifier keys by default. Bind them with
+    /// [`gestures`].
+    ///
+    /// The default scalar is `0.01`.
+    ///
+    /// [`Ramp`]: struct.Ramp.html
+    /// [`gestures`]: #method.gestures
+    pub fn wheel_ultra_fine_scalar(mut self, scalar: f32) -> Self {
+        self.wheel_ultra_fine_scalar = scalar;
+        self
+    }
+
+    /// Sets the distance to move the [`Ramp`] when the user presses an
+    /// arrow key while it has keyboard focus, as a [`Normal`] distance.
+    ///
+    /// The default step is `0.01`.
+    ///
+    /// [`Ramp`]: struct.Ramp.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn key_step(mut self, step: f32) -> Self {
+        self.key_step = step;
+        self
+    }
+
+    /// Sets the distance to move the [`Ramp`] when the user presses
+    /// `Page Up` or `Page Down` while it has keyboard focus, as a
+    /// [`Normal`] distance.
+    ///
+    /// The default step is `0.1`.
+    ///
+    /// [`Ramp`]: struct.Ramp.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn page_step(mut self, step: f32) -> Self {
+        self.page_step = step;
+        self
+    }
+
+    /// Sets the [`GestureBindings`] of the [`Ramp`], which map clicks and
+    /// drags to actions such as a reset or a fine drag. Share the same
+    /// bindings between every widget.
+    ///
+    /// The default bindings are [`DEFAULT_BINDINGS`].
+    ///
+    /// [`Ramp`]: struct.Ramp.html
+    /// [`GestureBindings`]: ../gesture/struct.GestureBindings.html
+    /// [`DEFAULT_BINDINGS`]: ../gesture/constant.DEFAULT_BINDINGS.html
+    pub fn gestures(mut self, gestures: &'a GestureBindings) -> Self {
+        self.gestures = Some(Cow::Borrowed(gestures));
+        self
+    }
+
+    /// Sets the modifier keys of a fine drag of the [`Ramp`].
+    ///
+    /// The default modifier key is `Ctrl`.
+    ///
+    /// This binds [`Action::FineDrag`] to a drag with exactly these
+    /// modifier keys in the [`GestureBindings`] of the [`Ramp`], so call it
+    /// after [`gestures`]. A fine drag no longer starts while holding down
+    /// more keys than these, such as `Ctrl` and `Shift` for `Ctrl`.
+    ///
+    /// [`Ramp`]: struct.Ramp.html
+    /// [`Action::FineDrag`]: ../gesture/enum.Action.html#variant.FineDrag
+    /// [`GestureBindings`]: ../gesture/struct.GestureBindings.html
+    /// [`gestures`]: #method.gestures
+    #[deprecated(note = "bind `gesture::Action::FineDrag` with `gestures`")]
+    pub fn modifier_keys(mut self, modifier_keys: keyboard::Modifiers) -> Self {
+        self.gestures = Some(gesture::rebind_fine_drag(
+            self.gestures.take(),
+            modifier_keys,
+        ));
         self
     }
 }
@@ -157,7 +355,7 @@ impl<'a, Message, Renderer: self::Renderer> Ramp<'a, Message, Renderer> {
 /// The local state of a [`Ramp`].
 ///
 /// [`Ramp`]: struct.Ramp.html
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Clone)]
 pub struct State {
     /// The [`NormalParam`] assigned to this widget
     ///
@@ -168,6 +366,7 @@ pub struct State {
     continuous_normal: f32,
     pressed_modifiers: keyboard::Modifiers,
     last_click: Option<mouse::Click>,
+    focus: Focus,
 }
 
 impl State {
@@ -189,6 +388,7 @@ impl State {
             continuous_normal: normal_param.value.as_f32(),
             pressed_modifiers: Default::default(),
             last_click: None,
+            focus: Focus::new(),
         }
     }
 
@@ -204,6 +404,34 @@ impl State {
     pub fn is_dragging(&self) -> bool {
         self.is_dragging
     }
+
+    /// Does the [`Ramp`] have keyboard focus?
+    ///
+    /// [`Ramp`]: struct.Ramp.html
+    pub fn is_focused(&self) -> bool {
+        self.focus.is_focused()
+    }
+
+    /// Gives keyboard focus to the [`Ramp`], taking it from any other
+    /// widget.
+    ///
+    /// [`Ramp`]: struct.Ramp.html
+    pub fn focus(&mut self) {
+        self.focus.focus();
+    }
+
+    /// Removes keyboard focus from the [`Ramp`].
+    ///
+    /// [`Ramp`]: struct.Ramp.html
+    pub fn unfocus(&mut self) {
+        self.focus.unfocus();
+    }
+}
+
+impl From<NormalParam> for State {
+    fn from(normal_param: NormalParam) -> Self {
+        State::new(normal_param)
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer>
@@ -240,6 +468,31 @@ where
         _renderer: &Renderer,
         _clipboard: Option<&dyn Clipboard>,
     ) -> event::Status {
+        if let Some(command) = self
+            .state
+            .focus
+            .on_event(&event, layout.bounds().contains(cursor_position))
+        {
+            let normal = command.apply(
+                self.state.normal_param.value.as_f32(),
+                self.state.normal_param.default.as_f32(),
+                self.key_step,
+                self.page_step,
+            );
+
+            self.state.set(normal.into());
+
+            // A key press is a complete edit gesture on its own.
+            edit::push_gesture(
+                messages,
+                self.on_grab.as_deref(),
+                (self.on_change)(self.state.normal_param.value),
+                self.on_release.as_deref(),
+            );
+
+            return event::Status::Captured;
+        }
+
         match event {
             Event::Mouse(mouse_event) => match mouse_event {
                 mouse::Event::CursorMoved { .. } => {
@@ -248,12 +501,17 @@ where
                             - self.state.prev_drag_y)
                             * self.scalar;
 
-                        if self
-                            .state
-                            .pressed_modifiers
-                            .matches(self.modifier_keys)
-                        {
-                            movement_y *= self.modifier_scalar;
+                        match gesture::drag_action(
+                            self.gestures.as_deref(),
+                            self.state.pressed_modifiers,
+                        ) {
+                            Some(gesture::Action::FineDrag) => {
+                                movement_y *= self.modifier_scalar;
+                            }
+                            Some(gesture::Action::UltraFineDrag) => {
+                                movement_y *= self.ultra_fine_scalar;
+                            }
+                            _ => {}
                         }
 
                         let mut normal =
@@ -277,37 +535,104 @@ where
                         return event::Status::Captured;
                     }
                 }
-                mouse::Event::ButtonPressed(mouse::Button::Left) => {
+                mouse::Event::ButtonPressed(button) => {
                     if layout.bounds().contains(cursor_position) {
-                        let click = mouse::Click::new(
-                            cursor_position,
-                            self.state.last_click,
-                        );
-
-                        match click.kind() {
-                            mouse::click::Kind::Single => {
-                                self.state.is_dragging = true;
-                                self.state.prev_drag_y = cursor_position.y;
-                            }
-                            _ => {
+                        let kind = if button == mouse::Button::Left {
+                            let click = mouse::Click::new(
+                                cursor_position,
+                                self.state.last_click,
+                            );
+                            self.state.last_click = Some(click);
+
+                            gesture::ClickKind::from(click.kind())
+                        } else {
+                            gesture::ClickKind::Single
+                        };
+
+                        match gesture::click_action(
+                            self.gestures.as_deref(),
+                            button,
+                            kind,
+                            self.state.pressed_modifiers,
+                            false,
+                            self.on_context_request.is_some(),
+                        ) {
+                            Some(gesture::Action::Reset) => {
                                 self.state.is_dragging = false;
 
                                 self.state.normal_param.value =
                                     self.state.normal_param.default;
 
-                                messages.push((self.on_change)(
-                                    self.state.normal_param.value,
-                                ));
+                                edit::push_gesture(
+                                    messages,
+                                    self.on_grab.as_deref(),
+                                    (self.on_change)(
+                                        self.state.normal_param.value,
+                                    ),
+                                    self.on_release.as_deref(),
+                                );
                             }
-                        }
+                            Some(gesture::Action::ContextRequest) => {
+                                if let Some(on_context_request) =
+                                    &self.on_context_request
+                                {
+                                    messages.push(on_context_request(
+                                        cursor_position,
+                                    ));
+                                }
+                            }
+                            _ if button == mouse::Button::Left => {
+                                self.state.is_dragging = true;
+
+                                if let Some(on_grab) = &self.on_grab {
+                                    messages.push(on_grab());
+                                }
 
-                        self.state.last_click = Some(click);
+                                self.state.prev_drag_y = cursor_position.y;
+                            }
+                            _ => return event::Status::Ignored,
+                        }
 
                         return event::Status::Captured;
                     }
                 }
+                mouse::Event::WheelScrolled { delta }
+                    if !self.state.is_dragging
+                        && layout.bounds().contains(cursor_position) =>
+                {
+                    let (_, movement) = wheel::movement(
+                        delta,
+                        self.wheel_scalar,
+                        self.wheel_pixel_scalar,
+                        gesture::drag_action(
+                            self.gestures.as_deref(),
+                            self.state.pressed_modifiers,
+                        ),
+                        self.wheel_modifier_scalar,
+                        self.wheel_ultra_fine_scalar,
+                    );
+
+                    self.state.set(Normal::from(
+                        self.state.normal_param.value.as_f32() + movement,
+                    ));
+
+                    edit::push_gesture(
+                        messages,
+                        self.on_grab.as_deref(),
+                        (self.on_change)(self.state.normal_param.value),
+                        self.on_release.as_deref(),
+                    );
+
+                    return event::Status::Captured;
+                }
                 mouse::Event::ButtonReleased(mouse::Button::Left) => {
-                    self.state.is_dragging = false;
+                    if self.state.is_dragging {
+                        self.state.is_dragging = false;
+
+                        if let Some(on_release) = &self.on_release {
+                            messages.push(on_release());
+                        }
+                    }
                     self.state.continuous_normal =
                         self.state.normal_param.value.as_f32();
 
@@ -345,8 +670,7 @@ where
         renderer.draw(
             layout.bounds(),
             cursor_position,
-            self.state.normal_param.value,
-            self.state.is_dragging,
+            self.state,
             &self.style,
             self.direction,
         )
@@ -376,18 +700,17 @@ pub trait Renderer: iced_native::Renderer {
     /// It receives:
     ///   * the bounds of the [`Ramp`]
     ///   * the current cursor position
-    ///   * the current normal of the [`Ramp`]
-    ///   * whether the ramp is currently being dragged
+    ///   * the [`State`] of the [`Ramp`]
     ///   * the style of the [`Ramp`]
     ///   * the direction of the ramp line of the [`Ramp`]
     ///
     /// [`Ramp`]: struct.Ramp.html
+    /// [`State`]: struct.State.html
     fn draw(
         &mut self,
         bounds: Rectangle,
         cursor_position: Point,
-        normal: Normal,
-        is_dragging: bool,
+        state: &State,
         style: &Self::Style,
         direction: RampDirection,
     ) -> Self::Output;
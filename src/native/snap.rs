@@ -0,0 +1,266 @@
+//! Snapping of widgets to the values of a quantized range
+//!
+//! A widget given a [`Snap`] with `.snap()` moves between the values of a
+//! quantized range, such as an [`IntRange`], a [`ChoiceRange`] or a
+//! [`PitchRange`] quantized to a scale, without the application snapping
+//! the value itself. A drag steps between the values, and each scroll of
+//! the mouse wheel or press of an arrow key moves by at least one value.
+//! A fine drag steps between the fine values of the range instead.
+//!
+//! [`Snap`]: trait.Snap.html
+//! [`IntRange`]: ../../core/range/struct.IntRange.html
+//! [`ChoiceRange`]: ../../core/range/struct.ChoiceRange.html
+//! [`PitchRange`]: ../../core/range/struct.PitchRange.html
+
+use crate::core::{Normal, ParamRange, Range};
+use crate::native::focus::Command;
+use crate::native::gesture::Action;
+
+/// The values a widget snaps to. This is implemented for every [`Range`]
+/// and for a [`ParamRange`] of a [`ParamRegistry`].
+///
+/// A range that is not quantized has no values to snap to, so a widget
+/// given one moves freely.
+///
+/// [`Range`]: ../../core/range/trait.Range.html
+/// [`ParamRange`]: ../../core/param_registry/struct.ParamRange.html
+/// [`ParamRegistry`]: ../../core/param_registry/struct.ParamRegistry.html
+pub trait Snap {
+    /// Returns `true` if the range has values to snap to
+    fn is_quantized(&self) -> bool;
+
+    /// Returns the [`Normal`] of the value closest to `normal`
+    ///
+    /// [`Normal`]: ../../core/struct.Normal.html
+    fn snap_normal(&self, normal: Normal) -> Normal;
+
+    /// Returns the [`Normal`] of the fine value closest to `normal`, used
+    /// during a fine drag
+    ///
+    /// [`Normal`]: ../../core/struct.Normal.html
+    fn snap_fine(&self, normal: Normal) -> Normal;
+
+    /// Returns the [`Normal`] of the value that is `steps` values away from
+    /// `normal`
+    ///
+    /// [`Normal`]: ../../core/struct.Normal.html
+    fn snap_step(&self, normal: Normal, steps: i32) -> Normal;
+}
+
+impl<R: Range> Snap for R {
+    fn is_quantized(&self) -> bool {
+        Range::is_quantized(self)
+    }
+
+    fn snap_normal(&self, normal: Normal) -> Normal {
+        if Range::is_quantized(self) {
+            self.snapped(normal)
+        } else {
+            normal
+        }
+    }
+
+    fn snap_fine(&self, normal: Normal) -> Normal {
+        if Range::is_quantized(self) {
+            self.snapped_fine(normal)
+        } else {
+            normal
+        }
+    }
+
+    fn snap_step(&self, normal: Normal, steps: i32) -> Normal {
+        self.step(normal, steps)
+    }
+}
+
+impl Snap for ParamRange {
+    fn is_quantized(&self) -> bool {
+        ParamRange::is_quantized(self)
+    }
+
+    fn snap_normal(&self, normal: Normal) -> Normal {
+        if ParamRange::is_quantized(self) {
+            self.snapped(normal)
+        } else {
+            normal
+        }
+    }
+
+    fn snap_fine(&self, normal: Normal) -> Normal {
+        if ParamRange::is_quantized(self) {
+            self.snapped_fine(normal)
+        } else {
+            normal
+        }
+    }
+
+    fn snap_step(&self, normal: Normal, steps: i32) -> Normal {
+        self.step(normal, steps)
+    }
+}
+
+/// Returns `normal` snapped to the values of `snap`, or `normal` itself if
+/// the widget does not snap
+pub(crate) fn snapped(snap: Option<&dyn Snap>, normal: Normal) -> Normal {
+    match snap {
+        Some(snap) => snap.snap_normal(normal),
+        None => normal,
+    }
+}
+
+/// Returns `normal` snapped to the values of `snap` during a drag. A fine
+/// or ultra-fine drag snaps to the fine values of `snap`.
+///
+/// * `snap` - the values the widget snaps to, or `None` if it does not snap
+/// * `normal` - the [`Normal`] the drag has moved to
+/// * `action` - the [`Action`] of the modifier keys held down
+///
+/// [`Normal`]: ../../core/struct.Normal.html
+/// [`Action`]: ../gesture/enum.Action.html
+pub(crate) fn dragged(
+    snap: Option<&dyn Snap>,
+    normal: Normal,
+    action: Option<Action>,
+) -> Normal {
+    match (snap, action) {
+        (Some(snap), Some(Action::FineDrag))
+        | (Some(snap), Some(Action::UltraFineDrag)) => snap.snap_fine(normal),
+        _ => snapped(snap, normal),
+    }
+}
+
+/// Returns the value of a widget moved by a relative movement, such as from
+/// the mouse wheel or an arrow key. The value moves at least to the next
+/// value of `snap`, otherwise a small movement would snap back to the same
+/// value.
+///
+/// The result is not constrained, so that endless widgets can wrap it.
+///
+/// * `snap` - the values the widget snaps to, or `None` if it does not snap
+/// * `value` - the current [`Normal`] value of the widget
+/// * `movement` - the [`Normal`] distance to move, which may be negative
+///
+/// [`Normal`]: ../../core/struct.Normal.html
+pub(crate) fn step(snap: Option<&dyn Snap>, value: f32, movement: f32) -> f32 {
+    match snap {
+        Some(snap) if snap.is_quantized() && movement != 0.0 => {
+            let next = snap
+                .snap_step(Normal::from(value), movement.signum() as i32)
+                .as_f32();
+
+            if movement > 0.0 {
+                (value + movement).max(next)
+            } else {
+                (value + movement).min(next)
+            }
+        }
+        _ => value + movement,
+    }
+}
+
+/// Returns the new value of a widget with a single value for a keyboard
+/// [`Command`]. A step or a page moves at least to the next value of
+/// `snap`.
+///
+/// [`Command`]: ../focus/enum.Command.html
+pub(crate) fn apply(
+    snap: Option<&dyn Snap>,
+    command: Command,
+    value: f32,
+    default: f32,
+    key_step: f32,
+    page_step: f32,
+) -> f32 {
+    let normal = command.apply(value, default, key_step, page_step);
+
+    match command {
+        Command::Step { .. } | Command::Page(_) => {
+            step(snap, value, normal - value)
+        }
+        _ => normal,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{
+        ChoiceRange, FloatRange, PitchQuantize, PitchRange, Scale,
+    };
+
+    #[test]
+    fn discrete_range_snaps_to_closest_value() {
+        let range = ChoiceRange::new(&["Low", "Band", "High"]);
+
+        assert_eq!(snapped(Some(&range), Normal::from(0.3)).as_f32(), 0.5);
+        assert_eq!(snapped(Some(&range), Normal::from(0.8)).as_f32(), 1.0);
+    }
+
+    #[test]
+    fn continuous_range_does_not_snap() {
+        let range = FloatRange::new(-1.0, 1.0);
+
+        assert_eq!(snapped(Some(&range), Normal::from(0.3)).as_f32(), 0.3);
+        assert_eq!(step(Some(&range), 0.5, 0.01), 0.51);
+    }
+
+    #[test]
+    fn small_movement_moves_one_value() {
+        let range = ChoiceRange::new(&["Low", "Band", "High"]);
+
+        assert_eq!(step(Some(&range), 0.0, 0.01), 0.5);
+        assert_eq!(step(Some(&range), 1.0, -0.01), 0.5);
+        assert_eq!(step(Some(&range), 0.0, 0.8), 0.8);
+        assert_eq!(step(Some(&range), 0.5, 0.0), 0.5);
+        assert_eq!(step(None, 0.5, 0.25), 0.75);
+    }
+
+    #[test]
+    fn quantized_pitch_range_snaps_and_steps_between_notes() {
+        // C major from C4 to C5: C D E F G A B C
+        let range = PitchRange::notes(60.0, 72.0)
+            .quantize(PitchQuantize::Scale(Scale::major(0)));
+        let e4 = range.map_to_normal(64.0);
+        let f4 = range.map_to_normal(65.0);
+
+        assert!(Snap::is_quantized(&range));
+        assert_eq!(
+            snapped(Some(&range), range.map_to_normal(65.4)),
+            range.map_to_normal(65.0)
+        );
+        assert_eq!(step(Some(&range), e4.as_f32(), 0.001), f4.as_f32());
+        assert_eq!(step(Some(&range), f4.as_f32(), -0.001), e4.as_f32());
+    }
+
+    #[test]
+    fn fine_drag_snaps_to_cents() {
+        let range =
+            PitchRange::notes(60.0, 72.0).quantize(PitchQuantize::Semitone);
+        let normal = range.map_to_normal(64.123);
+        let cents = range.map_to_normal(64.12);
+
+        assert_eq!(
+            dragged(Some(&range), normal, Some(Action::FineDrag)),
+            cents
+        );
+        assert_eq!(
+            dragged(Some(&range), normal, Some(Action::UltraFineDrag)),
+            cents
+        );
+        assert_eq!(
+            dragged(Some(&range), normal, None),
+            range.map_to_normal(64.0)
+        );
+    }
+
+    #[test]
+    fn key_step_moves_one_value_but_home_does_not() {
+        let range = ChoiceRange::new(&["Low", "Band", "High"]);
+        let snap = Some(&range as &dyn Snap);
+
+        let step = Command::Step { x: 0.0, y: 1.0 };
+        assert_eq!(apply(snap, step, 0.0, 0.5, 0.01, 0.1), 0.5);
+        assert_eq!(apply(snap, Command::Min, 1.0, 0.5, 0.01, 0.1), 0.0);
+        assert_eq!(apply(snap, Command::Default, 1.0, 0.5, 0.01, 0.1), 0.5);
+    }
+}
@@ -0,0 +1,251 @@
+//! Inline text entry of an exact parameter value
+//!
+//! A widget with text entry opens an editable field over itself, where the
+//! user can type a value such as `440` or `-6 dB`. The value is parsed
+//! through the range of the parameter and applied when `Enter` is
+//! pressed. `Escape` or a click outside of the widget closes the field
+//! without applying the value.
+
+use iced_native::{keyboard, mouse, Event};
+
+use crate::core::{Normal, ParamRange, Range};
+
+/// Converts between the value of a parameter and the text typed into a
+/// text entry.
+///
+/// This is implemented for every [`Range`], and for the [`ParamRange`] of
+/// a [`ParamRegistry`].
+///
+/// [`Range`]: ../../core/range/trait.Range.html
+/// [`ParamRange`]: ../../core/param_registry/struct.ParamRange.html
+/// [`ParamRegistry`]: ../../core/param_registry/struct.ParamRegistry.html
+pub trait EntryFormat {
+    /// Returns the text shown when the text entry opens
+    fn entry_text(&self, normal: Normal) -> String;
+
+    /// Parses the typed text, or returns `None` if the text is not a valid
+    /// value
+    fn parse_entry(&self, text: &str) -> Option<Normal>;
+}
+
+impl<R: Range> EntryFormat for R {
+    fn entry_text(&self, normal: Normal) -> String {
+        self.format(normal)
+    }
+
+    fn parse_entry(&self, text: &str) -> Option<Normal> {
+        self.parse(text.trim())
+    }
+}
+
+impl EntryFormat for ParamRange {
+    fn entry_text(&self, normal: Normal) -> String {
+        self.format(normal)
+    }
+
+    fn parse_entry(&self, text: &str) -> Option<Normal> {
+        self.parse(text.trim())
+    }
+}
+
+/// What a widget does after an event is sent to an open text entry
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) enum Action {
+    /// The text entry used the event
+    Edit,
+    /// The user entered a valid value
+    Apply(Normal),
+    /// The user closed the text entry without entering a value
+    Cancel,
+}
+
+/// The state of an open text entry
+#[derive(Debug, Clone)]
+pub struct State {
+    text: String,
+    is_invalid: bool,
+    is_pristine: bool,
+}
+
+impl State {
+    pub(crate) fn new(text: String) -> Self {
+        Self {
+            text,
+            is_invalid: false,
+            is_pristine: true,
+        }
+    }
+
+    /// Returns the text typed into the text entry
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns `true` if the text was entered but is not a valid value
+    pub fn is_invalid(&self) -> bool {
+        self.is_invalid
+    }
+
+    /// Returns `true` if nothing was typed since the text entry opened. The
+    /// text is replaced by the first typed character.
+    pub fn is_pristine(&self) -> bool {
+        self.is_pristine
+    }
+
+    pub(crate) fn on_event(
+        &mut self,
+        event: &Event,
+        format: &dyn EntryFormat,
+        is_mouse_over: bool,
+    ) -> Option<Action> {
+        match event {
+            Event::Keyboard(keyboard::Event::CharacterReceived(c))
+                if !c.is_control() =>
+            {
+                if self.is_pristine {
+                    self.text.clear();
+                    self.is_pristine = false;
+                }
+                self.text.push(*c);
+                self.is_invalid = false;
+
+                Some(Action::Edit)
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code, ..
+            }) => match key_code {
+                keyboard::KeyCode::Enter | keyboard::KeyCode::NumpadEnter => {
+                    match format.parse_entry(&self.text) {
+                        Some(normal) => Some(Action::Apply(normal)),
+                        None => {
+                            self.is_invalid = true;
+                            Some(Action::Edit)
+                        }
+                    }
+                }
+                keyboard::KeyCode::Escape => Some(Action::Cancel),
+                keyboard::KeyCode::Backspace => {
+                    if self.is_pristine {
+                        self.text.clear();
+                        self.is_pristine = false;
+                    } else {
+                        let _ = self.text.pop();
+                    }
+                    self.is_invalid = false;
+
+                    Some(Action::Edit)
+                }
+                _ => Some(Action::Edit),
+            },
+            Event::Keyboard(_) => Some(Action::Edit),
+            Event::Mouse(mouse::Event::ButtonPressed(_)) => {
+                if is_mouse_over {
+                    Some(Action::Edit)
+                } else {
+                    Some(Action::Cancel)
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::FloatRange;
+
+    fn key(key_code: keyboard::KeyCode) -> Event {
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key_code,
+            modifiers: keyboard::Modifiers::default(),
+        })
+    }
+
+    fn type_text(state: &mut State, range: &FloatRange, text: &str) {
+        for c in text.chars() {
+            let event = Event::Keyboard(keyboard::Event::CharacterReceived(c));
+            assert_eq!(state.on_event(&event, range, true), Some(Action::Edit));
+        }
+    }
+
+    #[test]
+    fn first_character_replaces_the_text() {
+        let range = FloatRange::new(0.0, 10.0);
+        let mut state = State::new(range.entry_text(Normal::min()));
+        assert!(state.is_pristine());
+
+        type_text(&mut state, &range, "5");
+
+        assert_eq!(state.text(), "5");
+        assert!(!state.is_pristine());
+        assert_eq!(
+            state.on_event(&key(keyboard::KeyCode::Enter), &range, true),
+            Some(Action::Apply(Normal::new(0.5)))
+        );
+    }
+
+    #[test]
+    fn backspace_clears_pristine_text_then_deletes() {
+        let range = FloatRange::new(0.0, 10.0);
+        let mut state = State::new(String::from("1.00"));
+
+        let backspace = key(keyboard::KeyCode::Backspace);
+        let _ = state.on_event(&backspace, &range, true);
+        assert_eq!(state.text(), "");
+
+        type_text(&mut state, &range, "25");
+        let _ = state.on_event(&backspace, &range, true);
+        assert_eq!(state.text(), "2");
+    }
+
+    #[test]
+    fn invalid_text_stays_open_until_edited() {
+        let range = FloatRange::new(0.0, 10.0);
+        let mut state = State::new(String::new());
+        type_text(&mut state, &range, "loud");
+
+        let enter = key(keyboard::KeyCode::NumpadEnter);
+        assert_eq!(state.on_event(&enter, &range, true), Some(Action::Edit));
+        assert!(state.is_invalid());
+
+        let _ =
+            state.on_event(&key(keyboard::KeyCode::Backspace), &range, true);
+        assert!(!state.is_invalid());
+    }
+
+    #[test]
+    fn escape_or_click_outside_cancels() {
+        let range = FloatRange::new(0.0, 10.0);
+        let mut state = State::new(String::new());
+        let click =
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left));
+
+        assert_eq!(
+            state.on_event(&key(keyboard::KeyCode::Escape), &range, true),
+            Some(Action::Cancel)
+        );
+        assert_eq!(state.on_event(&click, &range, true), Some(Action::Edit));
+        assert_eq!(state.on_event(&click, &range, false), Some(Action::Cancel));
+        assert_eq!(
+            state.on_event(
+                &Event::Mouse(mouse::Event::CursorLeft),
+                &range,
+                false
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn control_characters_are_not_typed() {
+        let range = FloatRange::new(0.0, 10.0);
+        let mut state = State::new(String::from("1"));
+
+        let event = Event::Keyboard(keyboard::Event::CharacterReceived('\r'));
+        assert_eq!(state.on_event(&event, &range, true), Some(Action::Edit));
+
+        assert_eq!(state.text(), "1");
+        assert!(state.is_pristine());
+    }
+}
@@ -6,6 +6,11 @@ use crate::core::Normal;
 
 /// A group of text marks.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(from = "Vec<(Normal, String)>", into = "Vec<(Normal, String)>")
+)]
 pub struct Group {
     /// The group of text marks.
     pub group: Vec<(Normal, String)>,
@@ -181,3 +186,24 @@ impl From<Vec<(Normal, String)>> for Group {
         Self::from_string(vec)
     }
 }
+
+impl From<Group> for Vec<(Normal, String)> {
+    fn from(group: Group) -> Self {
+        group.group
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serde_round_trips_and_recomputes_hash() {
+        let group = Group::min_max_and_center("L", "R", "C");
+        let json = serde_json::to_string(&group).unwrap();
+        let loaded: Group = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(loaded.group, group.group);
+        assert_eq!(loaded.hashed(), group.hashed());
+    }
+}
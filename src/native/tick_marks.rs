@@ -8,6 +8,11 @@ use crate::core::Normal;
 ///
 /// tick mark: struct.TickMark.html
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(from = "Vec<(Normal, Tier)>", into = "Vec<(Normal, Tier)>")
+)]
 pub struct Group {
     tier_1_positions: Vec<Normal>,
     tier_2_positions: Vec<Normal>,
@@ -38,10 +43,6 @@ impl Group {
         let mut tier_3_positions: Vec<Normal> = Vec::new();
 
         for tick_mark in tick_marks.iter() {
-            tick_mark.1.hash(&mut hasher);
-            // Rust can't hash an f32 value.
-            ((tick_mark.0.as_f32() * 10000000.0) as u64).hash(&mut hasher);
-
             match tick_mark.1 {
                 Tier::One => {
                     tier_1_positions.push(tick_mark.0);
@@ -55,6 +56,22 @@ impl Group {
             }
         }
 
+        // The positions are hashed by tier, so that the same tick marks in
+        // a different order (such as when deserialized) hash the same.
+        for (tier, positions) in [
+            (Tier::One, &tier_1_positions),
+            (Tier::Two, &tier_2_positions),
+            (Tier::Three, &tier_3_positions),
+        ]
+        .iter()
+        {
+            for position in positions.iter() {
+                tier.hash(&mut hasher);
+                // Rust can't hash an f32 value.
+                ((position.as_f32() * 10000000.0) as u64).hash(&mut hasher);
+            }
+        }
+
         Self {
             tier_1_positions,
             tier_2_positions,
@@ -247,12 +264,24 @@ impl From<&[(Normal, Tier)]> for Group {
     }
 }
 
+impl From<Group> for Vec<(Normal, Tier)> {
+    fn from(group: Group) -> Self {
+        let tier_1 = group.tier_1_positions.into_iter().map(|n| (n, Tier::One));
+        let tier_2 = group.tier_2_positions.into_iter().map(|n| (n, Tier::Two));
+        let tier_3 =
+            group.tier_3_positions.into_iter().map(|n| (n, Tier::Three));
+
+        tier_1.chain(tier_2).chain(tier_3).collect()
+    }
+}
+
 /// Tier of sizes for a tick mark.
 ///
 /// * One - large-sized tick mark
 /// * Two - medium-sized tick mark
 /// * Small - small-sized tick mark
 #[derive(Debug, Copy, Clone, PartialEq, std::hash::Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Tier {
     /// large-sized tick mark
     One,
@@ -267,3 +296,21 @@ impl Default for Tier {
         Tier::One
     }
 }
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serde_round_trips_and_recomputes_hash() {
+        let group = Group::subdivided(1, 1, 1, Some(Tier::Two));
+        let json = serde_json::to_string(&group).unwrap();
+        let loaded: Group = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(loaded.len(), group.len());
+        assert_eq!(loaded.tier_1(), group.tier_1());
+        assert_eq!(loaded.tier_2(), group.tier_2());
+        assert_eq!(loaded.tier_3(), group.tier_3());
+        assert_eq!(loaded.hashed(), group.hashed());
+    }
+}
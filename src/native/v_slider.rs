@@ -2,6 +2,7 @@
 //!
 //! [`NormalParam`]: ../core/normal_param/struct.NormalParam.html
 
+use std::borrow::Cow;
 use std::fmt::Debug;
 
 use iced_native::{
@@ -12,11 +13,21 @@ use iced_native::{
 use std::hash::Hash;
 
 use crate::core::{ModulationRange, Normal, NormalParam};
-use crate::native::{text_marks, tick_marks};
+use crate::native::focus::Focus;
+use crate::native::gesture::{self, GestureBindings};
+pub use crate::native::h_slider::DragMode;
+use crate::native::{edit, snap, text_entry, text_marks, tick_marks, wheel};
 
 static DEFAULT_WIDTH: u16 = 14;
 static DEFAULT_SCALAR: f32 = 0.9575;
 static DEFAULT_MODIFIER_SCALAR: f32 = 0.02;
+static DEFAULT_ULTRA_FINE_SCALAR: f32 = 0.002;
+static DEFAULT_WHEEL_SCALAR: f32 = 0.01;
+static DEFAULT_WHEEL_PIXEL_SCALAR: f32 = 0.001;
+static DEFAULT_WHEEL_MODIFIER_SCALAR: f32 = 0.1;
+static DEFAULT_WHEEL_ULTRA_FINE_SCALAR: f32 = 0.01;
+static DEFAULT_KEY_STEP: f32 = 0.01;
+static DEFAULT_PAGE_STEP: f32 = 0.1;
 
 /// A vertical slider GUI widget that controls a [`NormalParam`]
 ///
@@ -28,9 +39,20 @@ static DEFAULT_MODIFIER_SCALAR: f32 = 0.02;
 pub struct VSlider<'a, Message, Renderer: self::Renderer> {
     state: &'a mut State,
     on_change: Box<dyn Fn(Normal) -> Message>,
+    on_grab: Option<Box<dyn Fn() -> Message>>,
+    on_release: Option<Box<dyn Fn() -> Message>>,
+    on_context_request: Option<Box<dyn Fn(Point) -> Message>>,
     scalar: f32,
     modifier_scalar: f32,
-    modifier_keys: keyboard::Modifiers,
+    ultra_fine_scalar: f32,
+    wheel_scalar: f32,
+    wheel_pixel_scalar: f32,
+    wheel_modifier_scalar: f32,
+    wheel_ultra_fine_scalar: f32,
+    key_step: f32,
+    page_step: f32,
+    gestures: Option<Cow<'a, GestureBindings>>,
+    drag_mode: DragMode,
     width: Length,
     height: Length,
     style: Renderer::Style,
@@ -38,6 +60,8 @@ pub struct VSlider<'a, Message, Renderer: self::Renderer> {
     text_marks: Option<&'a text_marks::Group>,
     mod_range_1: Option<&'a ModulationRange>,
     mod_range_2: Option<&'a ModulationRange>,
+    text_entry: Option<&'a dyn text_entry::EntryFormat>,
+    snap: Option<&'a dyn snap::Snap>,
 }
 
 impl<'a, Message, Renderer: self::Renderer> VSlider<'a, Message, Renderer> {
@@ -56,12 +80,20 @@ impl<'a, Message, Renderer: self::Renderer> VSlider<'a, Message, Renderer> {
         VSlider {
             state,
             on_change: Box::new(on_change),
+            on_grab: None,
+            on_release: None,
+            on_context_request: None,
             scalar: DEFAULT_SCALAR,
             modifier_scalar: DEFAULT_MODIFIER_SCALAR,
-            modifier_keys: keyboard::Modifiers {
-                control: true,
-                ..Default::default()
-            },
+            ultra_fine_scalar: DEFAULT_ULTRA_FINE_SCALAR,
+            wheel_scalar: DEFAULT_WHEEL_SCALAR,
+            wheel_pixel_scalar: DEFAULT_WHEEL_PIXEL_SCALAR,
+            wheel_modifier_scalar: DEFAULT_WHEEL_MODIFIER_SCALAR,
+            wheel_ultra_fine_scalar: DEFAULT_WHEEL_ULTRA_FINE_SCALAR,
+            key_step: DEFAULT_KEY_STEP,
+            page_step: DEFAULT_PAGE_STEP,
+            gestures: None,
+            drag_mode: DragMode::default(),
             width: Length::from(Length::Units(DEFAULT_WIDTH)),
             height: Length::Fill,
             style: Renderer::Style::default(),
@@ -69,9 +101,59 @@ impl<'a, Message, Renderer: self::Renderer> VSlider<'a, Message, Renderer> {
             text_marks: None,
             mod_range_1: None,
             mod_range_2: None,
+            text_entry: None,
+            snap: None,
         }
     }
 
+    /// Sets the message that will be produced when the user grabs the
+    /// [`VSlider`] to begin an edit gesture.
+    ///
+    /// A host needs this to begin recording automation. It is followed by
+    /// the messages of `on_change`, and then by the message of
+    /// [`on_release`].
+    ///
+    /// [`VSlider`]: struct.VSlider.html
+    /// [`on_release`]: #method.on_release
+    pub fn on_grab<F>(mut self, on_grab: F) -> Self
+    where
+        F: 'static + Fn() -> Message,
+    {
+        self.on_grab = Some(Box::new(on_grab));
+        self
+    }
+
+    /// Sets the message that will be produced when the user releases the
+    /// [`VSlider`] to end an edit gesture.
+    ///
+    /// [`VSlider`]: struct.VSlider.html
+    pub fn on_release<F>(mut self, on_release: F) -> Self
+    where
+        F: 'static + Fn() -> Message,
+    {
+        self.on_release = Some(Box::new(on_release));
+        self
+    }
+
+    /// Sets the message that will be produced when the user asks for the
+    /// context of the [`VSlider`], such as to open a context menu. The message
+    /// receives the position of the cursor.
+    ///
+    /// No gesture asks for the context by default. Bind
+    /// [`Action::ContextRequest`] with [`gestures`], such as to a
+    /// right-click.
+    ///
+    /// [`VSlider`]: struct.VSlider.html
+    /// [`Action::ContextRequest`]: ../gesture/enum.Action.html#variant.ContextRequest
+    /// [`gestures`]: #method.gestures
+    pub fn on_context_request<F>(mut self, on_context_request: F) -> Self
+    where
+        F: 'static + Fn(Point) -> Message,
+    {
+        self.on_context_request = Some(Box::new(on_context_request));
+        self
+    }
+
     /// Sets the width of the [`VSlider`].
     /// The default width is `Length::Units(14)`.
     ///
@@ -98,16 +180,6 @@ impl<'a, Message, Renderer: self::Renderer> VSlider<'a, Message, Renderer> {
         self
     }
 
-    /// Sets the modifier keys of the [`VSlider`].
-    ///
-    /// The default modifier key is `Ctrl`.
-    ///
-    /// [`VSlider`]: struct.VSlider.html
-    pub fn modifier_keys(mut self, modifier_keys: keyboard::Modifiers) -> Self {
-        self.modifier_keys = modifier_keys;
-        self
-    }
-
     /// Sets the scalar to use when the user drags the slider per pixel.
     ///
     /// For example, a scalar of `0.5` will cause the slider to move half a
@@ -122,12 +194,12 @@ impl<'a, Message, Renderer: self::Renderer> VSlider<'a, Message, Renderer> {
     }
 
     /// Sets the scalar to use when the user drags the slider while holding down
-    /// the modifier key.
+    /// the modifier keys of a fine drag.
     ///
     /// For example, a scalar of `0.5` will cause the slider to move half a
     /// pixel for every pixel the mouse moves.
     ///
-    /// The default scalar is `0.02`, and the default modifier key is `Ctrl`.
+    /// The default scalar is `0.02`.
     ///
     /// [`VSlider`]: struct.VSlider.html
     pub fn modifier_scalar(mut self, scalar: f32) -> Self {
@@ -135,6 +207,147 @@ impl<'a, Message, Renderer: self::Renderer> VSlider<'a, Message, Renderer> {
         self
     }
 
+    /// Sets the scalar to use when the user drags the [`VSlider`] while holding
+    /// down the modifier keys of an ultra-fine drag. This is used in place
+    /// of `modifier_scalar`.
+    ///
+    /// An ultra-fine drag has no modifier keys by default. Bind them with
+    /// [`gestures`].
+    ///
+    /// The default scalar is `0.002`.
+    ///
+    /// [`VSlider`]: struct.VSlider.html
+    /// [`gestures`]: #method.gestures
+    pub fn ultra_fine_scalar(mut self, scalar: f32) -> Self {
+        self.ultra_fine_scalar = scalar;
+        self
+    }
+
+    /// Sets the distance to move the [`VSlider`] for each line the mouse
+    /// wheel scrolls, as a [`Normal`] distance.
+    ///
+    /// The default scalar is `0.01`.
+    ///
+    /// [`VSlider`]: struct.VSlider.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn wheel_scalar(mut self, scalar: f32) -> Self {
+        self.wheel_scalar = scalar;
+        self
+    }
+
+    /// Sets the distance to move the [`VSlider`] for each pixel the mouse
+    /// wheel or touchpad scrolls, as a [`Normal`] distance.
+    ///
+    /// The default scalar is `0.001`.
+    ///
+    /// [`VSlider`]: struct.VSlider.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn wheel_pixel_scalar(mut self, scalar: f32) -> Self {
+        self.wheel_pixel_scalar = scalar;
+        self
+    }
+
+    /// Sets the scalar to use when the user scrolls the mouse wheel while
+    /// holding down the modifier keys of a fine drag.
+    ///
+    /// For example, a scalar of `0.1` will cause the [`VSlider`] to move a
+    /// tenth of the distance it moves without them.
+    ///
+    /// The default scalar is `0.1`.
+    ///
+    /// [`VSlider`]: struct.VSlider.html
+    pub fn wheel_modifier_scalar(mut self, scalar: f32) -> Self {
+        self.wheel_modifier_scalar = scalar;
+        self
+    }
+
+    /// Sets the scalar to use when the user scrolls the mouse wheel over
+    /// the [`VSlider`] while holding down the modifier keys of an ultra-fine
+    /// drag. This is used in place of `wheel_modifier_scalar`.
+    ///
+    /// An ultra-fine drag has no modifier keys by default. Bind them with
+    /// [`gestures`].
+    ///
+    /// The default scalar is `0.01`.
+    ///
+    /// [`VSlider`]: struct.VSlider.html
+    /// [`gestures`]: #method.gestures
+    pub fn wheel_ultra_fine_scalar(mut self, scalar: f32) -> Self {
+        self.wheel_ultra_fine_scalar = scalar;
+        self
+    }
+
+    /// Sets the distance to move the [`VSlider`] when the user presses an
+    /// arrow key while it has keyboard focus, as a [`Normal`] distance.
+    ///
+    /// The default step is `0.01`.
+    ///
+    /// [`VSlider`]: struct.VSlider.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn key_step(mut self, step: f32) -> Self {
+        self.key_step = step;
+        self
+    }
+
+    /// Sets the distance to move the [`VSlider`] when the user presses
+    /// `Page Up` or `Page Down` while it has keyboard focus, as a
+    /// [`Normal`] distance.
+    ///
+    /// The default step is `0.1`.
+    ///
+    /// [`VSlider`]: struct.VSlider.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn page_step(mut self, step: f32) -> Self {
+        self.page_step = step;
+        self
+    }
+
+    /// Sets the [`GestureBindings`] of the [`VSlider`], which map clicks and
+    /// drags to actions such as a reset or a fine drag. Share the same
+    /// bindings between every widget.
+    ///
+    /// The default bindings are [`DEFAULT_BINDINGS`].
+    ///
+    /// [`VSlider`]: struct.VSlider.html
+    /// [`GestureBindings`]: ../gesture/struct.GestureBindings.html
+    /// [`DEFAULT_BINDINGS`]: ../gesture/constant.DEFAULT_BINDINGS.html
+    pub fn gestures(mut self, gestures: &'a GestureBindings) -> Self {
+        self.gestures = Some(Cow::Borrowed(gestures));
+        self
+    }
+
+    /// Sets the modifier keys of a fine drag of the [`VSlider`].
+    ///
+    /// The default modifier key is `Ctrl`.
+    ///
+    /// This binds [`Action::FineDrag`] to a drag with exactly these
+    /// modifier keys in the [`GestureBindings`] of the [`VSlider`], so call it
+    /// after [`gestures`]. A fine drag no longer starts while holding down
+    /// more keys than these, such as `Ctrl` and `Shift` for `Ctrl`.
+    ///
+    /// [`VSlider`]: struct.VSlider.html
+    /// [`Action::FineDrag`]: ../gesture/enum.Action.html#variant.FineDrag
+    /// [`GestureBindings`]: ../gesture/struct.GestureBindings.html
+    /// [`gestures`]: #method.gestures
+    #[deprecated(note = "bind `gesture::Action::FineDrag` with `gestures`")]
+    pub fn modifier_keys(mut self, modifier_keys: keyboard::Modifiers) -> Self {
+        self.gestures = Some(gesture::rebind_fine_drag(
+            self.gestures.take(),
+            modifier_keys,
+        ));
+        self
+    }
+
+    /// Sets the [`DragMode`] of the [`VSlider`]. The default mode is
+    /// `DragMode::Relative`.
+    ///
+    /// [`DragMode`]: ../h_slider/enum.DragMode.html
+    /// [`VSlider`]: struct.VSlider.html
+    pub fn drag_mode(mut self, drag_mode: DragMode) -> Self {
+        self.drag_mode = drag_mode;
+        self
+    }
+
     /// Sets the tick marks to display. Note your [`StyleSheet`] must
     /// also implement `tick_marks_style(&self) -> Option<tick_marks::Style>` for
     /// them to display (which the default style does).
@@ -176,6 +389,41 @@ impl<'a, Message, Renderer: self::Renderer> VSlider<'a, Message, Renderer> {
         self.mod_range_1 = Some(mod_range);
         self
     }
+
+    /// Enables the text entry of the [`VSlider`], where the user can type an
+    /// exact value. The typed text is parsed through `format`, which is
+    /// usually the range of the parameter.
+    ///
+    /// By default, a double-click opens the text entry instead of resetting
+    /// the [`VSlider`] to its default value. Change this with [`gestures`].
+    ///
+    /// [`VSlider`]: struct.VSlider.html
+    /// [`gestures`]: #method.gestures
+    pub fn text_entry(
+        mut self,
+        format: &'a dyn text_entry::EntryFormat,
+    ) -> Self {
+        self.text_entry = Some(format);
+        self
+    }
+
+    /// Snaps the [`VSlider`] to the values of a quantized range, such as an
+    /// [`IntRange`], a [`ChoiceRange`] or a quantized [`PitchRange`], so
+    /// that it steps between them. Each scroll of the mouse wheel and press
+    /// of an arrow key moves it by at least one value. A fine drag steps
+    /// between the fine values of the range instead, such as the whole
+    /// cents of a [`PitchRange`].
+    ///
+    /// A range that is not quantized does not snap.
+    ///
+    /// [`VSlider`]: struct.VSlider.html
+    /// [`IntRange`]: ../../core/range/struct.IntRange.html
+    /// [`ChoiceRange`]: ../../core/range/struct.ChoiceRange.html
+    /// [`PitchRange`]: ../../core/range/struct.PitchRange.html
+    pub fn snap(mut self, snap: &'a dyn snap::Snap) -> Self {
+        self.snap = Some(snap);
+        self
+    }
 }
 
 /// The local state of a [`VSlider`].
@@ -192,6 +440,8 @@ pub struct State {
     continuous_normal: f32,
     pressed_modifiers: keyboard::Modifiers,
     last_click: Option<mouse::Click>,
+    focus: Focus,
+    text_entry: Option<text_entry::State>,
     tick_marks_cache: crate::graphics::tick_marks::PrimitiveCache,
     text_marks_cache: crate::graphics::text_marks::PrimitiveCache,
 }
@@ -211,6 +461,8 @@ impl State {
             continuous_normal: normal_param.value.as_f32(),
             pressed_modifiers: Default::default(),
             last_click: None,
+            focus: Focus::new(),
+            text_entry: None,
             tick_marks_cache: Default::default(),
             text_marks_cache: Default::default(),
         }
@@ -228,6 +480,48 @@ impl State {
     pub fn is_dragging(&self) -> bool {
         self.is_dragging
     }
+
+    /// Does the [`VSlider`] have keyboard focus?
+    ///
+    /// [`VSlider`]: struct.VSlider.html
+    pub fn is_focused(&self) -> bool {
+        self.focus.is_focused()
+    }
+
+    /// Gives keyboard focus to the [`VSlider`], taking it from any other
+    /// widget.
+    ///
+    /// [`VSlider`]: struct.VSlider.html
+    pub fn focus(&mut self) {
+        self.focus.focus();
+    }
+
+    /// Removes keyboard focus from the [`VSlider`].
+    ///
+    /// [`VSlider`]: struct.VSlider.html
+    pub fn unfocus(&mut self) {
+        self.focus.unfocus();
+    }
+
+    /// Is the text entry of the [`VSlider`] open?
+    ///
+    /// [`VSlider`]: struct.VSlider.html
+    pub fn is_entering_text(&self) -> bool {
+        self.text_entry.is_some()
+    }
+
+    /// Returns the open text entry of the [`VSlider`], if any
+    ///
+    /// [`VSlider`]: struct.VSlider.html
+    pub fn text_entry(&self) -> Option<&text_entry::State> {
+        self.text_entry.as_ref()
+    }
+}
+
+impl From<NormalParam> for State {
+    fn from(normal_param: NormalParam) -> Self {
+        State::new(normal_param)
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer>
@@ -261,9 +555,68 @@ where
         layout: Layout<'_>,
         cursor_position: Point,
         messages: &mut Vec<Message>,
-        _renderer: &Renderer,
+        renderer: &Renderer,
         _clipboard: Option<&dyn Clipboard>,
     ) -> event::Status {
+        if let (Some(entry), Some(format)) =
+            (&mut self.state.text_entry, self.text_entry)
+        {
+            match entry.on_event(
+                &event,
+                format,
+                layout.bounds().contains(cursor_position),
+            ) {
+                Some(text_entry::Action::Edit) => {
+                    return event::Status::Captured;
+                }
+                Some(text_entry::Action::Apply(normal)) => {
+                    self.state.text_entry = None;
+                    self.state.set(snap::snapped(self.snap, normal));
+
+                    // An entered value is a complete edit gesture on its own.
+                    edit::push_gesture(
+                        messages,
+                        self.on_grab.as_deref(),
+                        (self.on_change)(self.state.normal_param.value),
+                        self.on_release.as_deref(),
+                    );
+
+                    return event::Status::Captured;
+                }
+                Some(text_entry::Action::Cancel) => {
+                    self.state.text_entry = None;
+                }
+                None => {}
+            }
+        }
+
+        if let Some(command) = self
+            .state
+            .focus
+            .on_event(&event, layout.bounds().contains(cursor_position))
+        {
+            let normal = snap::apply(
+                self.snap,
+                command,
+                self.state.normal_param.value.as_f32(),
+                self.state.normal_param.default.as_f32(),
+                self.key_step,
+                self.page_step,
+            );
+
+            self.state.set(snap::snapped(self.snap, normal.into()));
+
+            // A key press is a complete edit gesture on its own.
+            edit::push_gesture(
+                messages,
+                self.on_grab.as_deref(),
+                (self.on_change)(self.state.normal_param.value),
+                self.on_release.as_deref(),
+            );
+
+            return event::Status::Captured;
+        }
+
         match event {
             Event::Mouse(mouse_event) => match mouse_event {
                 mouse::Event::CursorMoved { .. } => {
@@ -275,14 +628,31 @@ where
                                 - self.state.prev_drag_y)
                                 / bounds_height;
 
-                            if self
-                                .state
-                                .pressed_modifiers
-                                .matches(self.modifier_keys)
-                            {
-                                movement_y *= self.modifier_scalar;
-                            } else {
-                                movement_y *= self.scalar;
+                            let action = gesture::drag_action(
+                                self.gestures.as_deref(),
+                                self.state.pressed_modifiers,
+                            );
+
+                            match action {
+                                Some(gesture::Action::FineDrag) => {
+                                    movement_y *= self.modifier_scalar;
+                                }
+                                Some(gesture::Action::UltraFineDrag) => {
+                                    movement_y *= self.ultra_fine_scalar;
+                                }
+                                _ if self.drag_mode == DragMode::Absolute => {
+                                    let normal = normal_at(
+                                        &layout.bounds(),
+                                        renderer.handle_height(&self.style),
+                                        cursor_position.y,
+                                    );
+
+                                    movement_y = self.state.continuous_normal
+                                        - normal.as_f32();
+                                }
+                                _ => {
+                                    movement_y *= self.scalar;
+                                }
                             }
 
                             let normal =
@@ -291,7 +661,8 @@ where
                             self.state.continuous_normal = normal;
                             self.state.prev_drag_y = cursor_position.y;
 
-                            self.state.normal_param.value = normal.into();
+                            self.state.normal_param.value =
+                                snap::dragged(self.snap, normal.into(), action);
 
                             messages.push((self.on_change)(
                                 self.state.normal_param.value,
@@ -301,37 +672,148 @@ where
                         }
                     }
                 }
-                mouse::Event::ButtonPressed(mouse::Button::Left) => {
+                mouse::Event::ButtonPressed(button) => {
                     if layout.bounds().contains(cursor_position) {
-                        let click = mouse::Click::new(
-                            cursor_position,
-                            self.state.last_click,
-                        );
-
-                        match click.kind() {
-                            mouse::click::Kind::Single => {
-                                self.state.is_dragging = true;
-                                self.state.prev_drag_y = cursor_position.y;
-                            }
-                            _ => {
+                        let kind = if button == mouse::Button::Left {
+                            let click = mouse::Click::new(
+                                cursor_position,
+                                self.state.last_click,
+                            );
+                            self.state.last_click = Some(click);
+
+                            gesture::ClickKind::from(click.kind())
+                        } else {
+                            gesture::ClickKind::Single
+                        };
+
+                        match gesture::click_action(
+                            self.gestures.as_deref(),
+                            button,
+                            kind,
+                            self.state.pressed_modifiers,
+                            self.text_entry.is_some(),
+                            self.on_context_request.is_some(),
+                        ) {
+                            Some(gesture::Action::Reset) => {
                                 self.state.is_dragging = false;
 
                                 self.state.normal_param.value =
                                     self.state.normal_param.default;
 
-                                messages.push((self.on_change)(
-                                    self.state.normal_param.value,
-                                ));
+                                edit::push_gesture(
+                                    messages,
+                                    self.on_grab.as_deref(),
+                                    (self.on_change)(
+                                        self.state.normal_param.value,
+                                    ),
+                                    self.on_release.as_deref(),
+                                );
                             }
-                        }
+                            Some(gesture::Action::TextEntry) => {
+                                if let Some(format) = self.text_entry {
+                                    self.state.text_entry =
+                                        Some(text_entry::State::new(
+                                            format.entry_text(
+                                                self.state.normal_param.value,
+                                            ),
+                                        ));
+                                }
+                            }
+                            Some(gesture::Action::ContextRequest) => {
+                                if let Some(on_context_request) =
+                                    &self.on_context_request
+                                {
+                                    messages.push(on_context_request(
+                                        cursor_position,
+                                    ));
+                                }
+                            }
+                            _ if button == mouse::Button::Left => {
+                                self.state.is_dragging = true;
 
-                        self.state.last_click = Some(click);
+                                if let Some(on_grab) = &self.on_grab {
+                                    messages.push(on_grab());
+                                }
+
+                                self.state.prev_drag_y = cursor_position.y;
+
+                                let bounds = layout.bounds();
+                                let handle_height =
+                                    renderer.handle_height(&self.style);
+
+                                let jump = match self.drag_mode {
+                                    DragMode::Relative => false,
+                                    DragMode::Absolute => true,
+                                    DragMode::JumpOffHandle => !is_over_handle(
+                                        &bounds,
+                                        handle_height,
+                                        self.state.normal_param.value,
+                                        cursor_position.y,
+                                    ),
+                                };
+
+                                if jump {
+                                    self.state.set(snap::snapped(
+                                        self.snap,
+                                        normal_at(
+                                            &bounds,
+                                            handle_height,
+                                            cursor_position.y,
+                                        ),
+                                    ));
+
+                                    messages.push((self.on_change)(
+                                        self.state.normal_param.value,
+                                    ));
+                                }
+                            }
+                            _ => return event::Status::Ignored,
+                        }
 
                         return event::Status::Captured;
                     }
                 }
+                mouse::Event::WheelScrolled { delta }
+                    if !self.state.is_dragging
+                        && layout.bounds().contains(cursor_position) =>
+                {
+                    let (_, movement) = wheel::movement(
+                        delta,
+                        self.wheel_scalar,
+                        self.wheel_pixel_scalar,
+                        gesture::drag_action(
+                            self.gestures.as_deref(),
+                            self.state.pressed_modifiers,
+                        ),
+                        self.wheel_modifier_scalar,
+                        self.wheel_ultra_fine_scalar,
+                    );
+
+                    let normal = snap::step(
+                        self.snap,
+                        self.state.normal_param.value.as_f32(),
+                        movement,
+                    );
+
+                    self.state.set(snap::snapped(self.snap, normal.into()));
+
+                    edit::push_gesture(
+                        messages,
+                        self.on_grab.as_deref(),
+                        (self.on_change)(self.state.normal_param.value),
+                        self.on_release.as_deref(),
+                    );
+
+                    return event::Status::Captured;
+                }
                 mouse::Event::ButtonReleased(mouse::Button::Left) => {
-                    self.state.is_dragging = false;
+                    if self.state.is_dragging {
+                        self.state.is_dragging = false;
+
+                        if let Some(on_release) = &self.on_release {
+                            messages.push(on_release());
+                        }
+                    }
                     self.state.continuous_normal =
                         self.state.normal_param.value.as_f32();
 
@@ -369,8 +851,7 @@ where
         renderer.draw(
             layout.bounds(),
             cursor_position,
-            self.state.normal_param.value,
-            self.state.is_dragging,
+            self.state,
             self.mod_range_1,
             self.mod_range_2,
             self.tick_marks,
@@ -390,6 +871,29 @@ where
     }
 }
 
+/// Returns the value that puts the center of the handle at the cursor
+fn normal_at(bounds: &Rectangle, handle_height: f32, cursor_y: f32) -> Normal {
+    let height = bounds.height - handle_height;
+
+    if height <= 0.0 {
+        return Normal::min();
+    }
+
+    (1.0 - ((cursor_y - bounds.y - (handle_height / 2.0)) / height)).into()
+}
+
+/// Returns `true` if the cursor is over the handle
+fn is_over_handle(
+    bounds: &Rectangle,
+    handle_height: f32,
+    normal: Normal,
+    cursor_y: f32,
+) -> bool {
+    let handle_y = bounds.y + normal.scale_inv(bounds.height - handle_height);
+
+    cursor_y >= handle_y && cursor_y <= handle_y + handle_height
+}
+
 /// The renderer of a [`VSlider`].
 ///
 /// Your renderer will need to implement this trait before being
@@ -400,25 +904,31 @@ pub trait Renderer: iced_native::Renderer {
     /// The style supported by this renderer.
     type Style: Default;
 
+    /// Returns the height of the handle of a [`VSlider`] in pixels, from
+    /// the active style. A click within the handle does not jump the
+    /// handle in `DragMode::JumpOffHandle`.
+    ///
+    /// [`VSlider`]: struct.VSlider.html
+    fn handle_height(&self, style: &Self::Style) -> f32;
+
     /// Draws a [`VSlider`].
     ///
     /// It receives:
     ///   * the bounds of the [`VSlider`]
     ///   * the current cursor position
-    ///   * the current normal of the [`VSlider`]
+    ///   * the [`State`] of the [`VSlider`]
     ///   * the height of the handle in pixels
-    ///   * whether the slider is currently being dragged
     ///   * any tick marks to display
     ///   * any text marks to display
     ///   * the style of the [`VSlider`]
     ///
     /// [`VSlider`]: struct.VSlider.html
+    /// [`State`]: struct.State.html
     fn draw(
         &mut self,
         bounds: Rectangle,
         cursor_position: Point,
-        normal: Normal,
-        is_dragging: bool,
+        state: &State,
         mod_range_1: Option<&ModulationRange>,
         mod_range_2: Option<&ModulationRange>,
         tick_marks: Option<&tick_marks::Group>,
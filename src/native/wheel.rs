@@ -0,0 +1,81 @@
+//! Mouse wheel control shared by every widget
+//!
+//! A scroll of the mouse wheel over a widget that is not being dragged
+//! moves its value. A scroll is a complete edit gesture on its own.
+
+use iced_native::mouse;
+
+use crate::native::gesture::Action;
+
+/// Returns the movement of a value along the x and y axes for a scroll of
+/// the mouse wheel
+///
+/// * `delta` - the distance scrolled
+/// * `scalar` - the movement of the value per line scrolled
+/// * `pixel_scalar` - the movement of the value per pixel scrolled, such
+///   as with a touchpad
+/// * `action` - the [`Action`] of the modifier keys held down, as for a
+///   drag
+/// * `fine_scalar` - the multiplier of the movement for a fine scroll
+/// * `ultra_fine_scalar` - the multiplier of the movement for an
+///   ultra-fine scroll
+///
+/// [`Action`]: ../gesture/enum.Action.html
+pub(crate) fn movement(
+    delta: mouse::ScrollDelta,
+    scalar: f32,
+    pixel_scalar: f32,
+    action: Option<Action>,
+    fine_scalar: f32,
+    ultra_fine_scalar: f32,
+) -> (f32, f32) {
+    let (x, y) = match delta {
+        mouse::ScrollDelta::Lines { x, y } => (x * scalar, y * scalar),
+        mouse::ScrollDelta::Pixels { x, y } => {
+            (x * pixel_scalar, y * pixel_scalar)
+        }
+    };
+
+    let modifier_scalar = match action {
+        Some(Action::FineDrag) => fine_scalar,
+        Some(Action::UltraFineDrag) => ultra_fine_scalar,
+        _ => 1.0,
+    };
+
+    (x * modifier_scalar, y * modifier_scalar)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lines_and_pixels_use_their_own_scalar() {
+        let lines = mouse::ScrollDelta::Lines { x: 1.0, y: -2.0 };
+        let pixels = mouse::ScrollDelta::Pixels { x: 4.0, y: 8.0 };
+
+        assert_eq!(movement(lines, 0.5, 0.25, None, 0.1, 0.01), (0.5, -1.0));
+        assert_eq!(movement(pixels, 0.5, 0.25, None, 0.1, 0.01), (1.0, 2.0));
+    }
+
+    #[test]
+    fn fine_and_ultra_fine_scroll_scale_the_movement() {
+        let lines = mouse::ScrollDelta::Lines { x: 0.0, y: 2.0 };
+
+        assert_eq!(
+            movement(lines, 0.5, 0.01, Some(Action::FineDrag), 0.25, 0.125),
+            (0.0, 0.25)
+        );
+        assert_eq!(
+            movement(
+                lines,
+                0.5,
+                0.01,
+                Some(Action::UltraFineDrag),
+                0.25,
+                0.125
+            ),
+            (0.0, 0.125)
+        );
+    }
+}
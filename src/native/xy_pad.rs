@@ -3,6 +3,7 @@
 //!
 //! [`NormalParam`]: ../core/normal_param/struct.NormalParam.html
 
+use std::borrow::Cow;
 use std::fmt::Debug;
 
 use iced_native::{
@@ -13,8 +14,19 @@ use iced_native::{
 use std::hash::Hash;
 
 use crate::core::{Normal, NormalParam};
+use crate::native::edit;
+use crate::native::focus::{self, Focus};
+use crate::native::gesture::{self, GestureBindings};
+use crate::native::wheel;
 
 static DEFAULT_MODIFIER_SCALAR: f32 = 0.02;
+static DEFAULT_ULTRA_FINE_SCALAR: f32 = 0.002;
+static DEFAULT_WHEEL_SCALAR: f32 = 0.01;
+static DEFAULT_WHEEL_PIXEL_SCALAR: f32 = 0.001;
+static DEFAULT_WHEEL_MODIFIER_SCALAR: f32 = 0.1;
+static DEFAULT_WHEEL_ULTRA_FINE_SCALAR: f32 = 0.01;
+static DEFAULT_KEY_STEP: f32 = 0.01;
+static DEFAULT_PAGE_STEP: f32 = 0.1;
 
 /// A 2D XY pad GUI widget that controls two [`NormalParam`] parameters at
 /// once. One in the `x` coordinate and one in the `y` coordinate.
@@ -28,8 +40,18 @@ static DEFAULT_MODIFIER_SCALAR: f32 = 0.02;
 pub struct XYPad<'a, Message, Renderer: self::Renderer> {
     state: &'a mut State,
     on_change: Box<dyn Fn(Normal, Normal) -> Message>,
+    on_grab: Option<Box<dyn Fn() -> Message>>,
+    on_release: Option<Box<dyn Fn() -> Message>>,
+    on_context_request: Option<Box<dyn Fn(Point) -> Message>>,
     modifier_scalar: f32,
-    modifier_keys: keyboard::Modifiers,
+    ultra_fine_scalar: f32,
+    wheel_scalar: f32,
+    wheel_pixel_scalar: f32,
+    wheel_modifier_scalar: f32,
+    wheel_ultra_fine_scalar: f32,
+    key_step: f32,
+    page_step: f32,
+    gestures: Option<Cow<'a, GestureBindings>>,
     size: Length,
     style: Renderer::Style,
 }
@@ -50,16 +72,71 @@ impl<'a, Message, Renderer: self::Renderer> XYPad<'a, Message, Renderer> {
         XYPad {
             state,
             on_change: Box::new(on_change),
+            on_grab: None,
+            on_release: None,
+            on_context_request: None,
             modifier_scalar: DEFAULT_MODIFIER_SCALAR,
-            modifier_keys: keyboard::Modifiers {
-                control: true,
-                ..Default::default()
-            },
+            ultra_fine_scalar: DEFAULT_ULTRA_FINE_SCALAR,
+            wheel_scalar: DEFAULT_WHEEL_SCALAR,
+            wheel_pixel_scalar: DEFAULT_WHEEL_PIXEL_SCALAR,
+            wheel_modifier_scalar: DEFAULT_WHEEL_MODIFIER_SCALAR,
+            wheel_ultra_fine_scalar: DEFAULT_WHEEL_ULTRA_FINE_SCALAR,
+            key_step: DEFAULT_KEY_STEP,
+            page_step: DEFAULT_PAGE_STEP,
+            gestures: None,
             size: Length::Fill,
             style: Renderer::Style::default(),
         }
     }
 
+    /// Sets the message that will be produced when the user grabs the
+    /// [`XYPad`] to begin an edit gesture.
+    ///
+    /// A host needs this to begin recording automation. It is followed by
+    /// the messages of `on_change`, and then by the message of
+    /// [`on_release`].
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    /// [`on_release`]: #method.on_release
+    pub fn on_grab<F>(mut self, on_grab: F) -> Self
+    where
+        F: 'static + Fn() -> Message,
+    {
+        self.on_grab = Some(Box::new(on_grab));
+        self
+    }
+
+    /// Sets the message that will be produced when the user releases the
+    /// [`XYPad`] to end an edit gesture.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    pub fn on_release<F>(mut self, on_release: F) -> Self
+    where
+        F: 'static + Fn() -> Message,
+    {
+        self.on_release = Some(Box::new(on_release));
+        self
+    }
+
+    /// Sets the message that will be produced when the user asks for the
+    /// context of the [`XYPad`], such as to open a context menu. The message
+    /// receives the position of the cursor.
+    ///
+    /// No gesture asks for the context by default. Bind
+    /// [`Action::ContextRequest`] with [`gestures`], such as to a
+    /// right-click.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    /// [`Action::ContextRequest`]: ../gesture/enum.Action.html#variant.ContextRequest
+    /// [`gestures`]: #method.gestures
+    pub fn on_context_request<F>(mut self, on_context_request: F) -> Self
+    where
+        F: 'static + Fn(Point) -> Message,
+    {
+        self.on_context_request = Some(Box::new(on_context_request));
+        self
+    }
+
     /// Sets the size of the [`XYPad`].
     ///
     /// [`XYPad`]: struct.XYPad.html
@@ -76,27 +153,148 @@ impl<'a, Message, Renderer: self::Renderer> XYPad<'a, Message, Renderer> {
         self
     }
 
-    /// Sets the modifier keys of the [`XYPad`].
+    /// Sets the scalar to use when the user drags the slider while holding down
+    /// the modifier keys of a fine drag.
     ///
-    /// The default modifier key is `Ctrl`.
+    /// For example, a scalar of `0.5` will cause the slider to move half a
+    /// pixel for every pixel the mouse moves.
+    ///
+    /// The default scalar is `0.02`.
     ///
     /// [`XYPad`]: struct.XYPad.html
-    pub fn modifier_keys(mut self, modifier_keys: keyboard::Modifiers) -> Self {
-        self.modifier_keys = modifier_keys;
+    pub fn modifier_scalar(mut self, scalar: f32) -> Self {
+        self.modifier_scalar = scalar;
         self
     }
 
-    /// Sets the scalar to use when the user drags the slider while holding down
-    /// the modifier key.
+    /// Sets the scalar to use when the user drags the [`XYPad`] while holding
+    /// down the modifier keys of an ultra-fine drag. This is used in place
+    /// of `modifier_scalar`.
     ///
-    /// For example, a scalar of `0.5` will cause the slider to move half a
-    /// pixel for every pixel the mouse moves.
+    /// An ultra-fine drag has no modifier keys by default. Bind them with
+    /// [`gestures`].
     ///
-    /// The default scalar is `0.02`, and the default modifier key is `Ctrl`.
+    /// The default scalar is `0.002`.
     ///
     /// [`XYPad`]: struct.XYPad.html
-    pub fn modifier_scalar(mut self, scalar: f32) -> Self {
-        self.modifier_scalar = scalar;
+    /// [`gestures`]: #method.gestures
+    pub fn ultra_fine_scalar(mut self, scalar: f32) -> Self {
+        self.ultra_fine_scalar = scalar;
+        self
+    }
+
+    /// Sets the distance to move each axis of the [`XYPad`] for each line the mouse
+    /// wheel scrolls, as a [`Normal`] distance.
+    ///
+    /// The default scalar is `0.01`.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn wheel_scalar(mut self, scalar: f32) -> Self {
+        self.wheel_scalar = scalar;
+        self
+    }
+
+    /// Sets the distance to move each axis of the [`XYPad`] for each pixel the mouse
+    /// wheel or touchpad scrolls, as a [`Normal`] distance.
+    ///
+    /// The default scalar is `0.001`.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn wheel_pixel_scalar(mut self, scalar: f32) -> Self {
+        self.wheel_pixel_scalar = scalar;
+        self
+    }
+
+    /// Sets the scalar to use when the user scrolls the mouse wheel while
+    /// holding down the modifier keys of a fine drag.
+    ///
+    /// For example, a scalar of `0.1` will cause the [`XYPad`] to move a
+    /// tenth of the distance it moves without them.
+    ///
+    /// The default scalar is `0.1`.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    pub fn wheel_modifier_scalar(mut self, scalar: f32) -> Self {
+        self.wheel_modifier_scalar = scalar;
+        self
+    }
+
+    /// Sets the scalar to use when the user scrolls the mouse wheel over
+    /// the [`XYPad`] while holding down the modifier keys of an ultra-fine
+    /// drag. This is used in place of `wheel_modifier_scalar`.
+    ///
+    /// An ultra-fine drag has no modifier keys by default. Bind them with
+    /// [`gestures`].
+    ///
+    /// The default scalar is `0.01`.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    /// [`gestures`]: #method.gestures
+    pub fn wheel_ultra_fine_scalar(mut self, scalar: f32) -> Self {
+        self.wheel_ultra_fine_scalar = scalar;
+        self
+    }
+
+    /// Sets the distance to move each axis of the [`XYPad`] when the user presses an
+    /// arrow key while it has keyboard focus, as a [`Normal`] distance.
+    ///
+    /// The default step is `0.01`.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn key_step(mut self, step: f32) -> Self {
+        self.key_step = step;
+        self
+    }
+
+    /// Sets the distance to move the y axis of the [`XYPad`] when the user presses
+    /// `Page Up` or `Page Down` while it has keyboard focus, as a
+    /// [`Normal`] distance.
+    ///
+    /// The default step is `0.1`.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn page_step(mut self, step: f32) -> Self {
+        self.page_step = step;
+        self
+    }
+
+    /// Sets the [`GestureBindings`] of the [`XYPad`], which map clicks and
+    /// drags to actions such as a reset or a fine drag. Share the same
+    /// bindings between every widget.
+    ///
+    /// The default bindings are [`DEFAULT_BINDINGS`].
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    /// [`GestureBindings`]: ../gesture/struct.GestureBindings.html
+    /// [`DEFAULT_BINDINGS`]: ../gesture/constant.DEFAULT_BINDINGS.html
+    pub fn gestures(mut self, gestures: &'a GestureBindings) -> Self {
+        self.gestures = Some(Cow::Borrowed(gestures));
+        self
+    }
+
+    /// Sets the modifier keys of a fine drag of the [`XYPad`].
+    ///
+    /// The default modifier key is `Ctrl`.
+    ///
+    /// This binds [`Action::FineDrag`] to a drag with exactly these
+    /// modifier keys in the [`GestureBindings`] of the [`XYPad`], so call it
+    /// after [`gestures`]. A fine drag no longer starts while holding down
+    /// more keys than these, such as `Ctrl` and `Shift` for `Ctrl`.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    /// [`Action::FineDrag`]: ../gesture/enum.Action.html#variant.FineDrag
+    /// [`GestureBindings`]: ../gesture/struct.GestureBindings.html
+    /// [`gestures`]: #method.gestures
+    #[deprecated(note = "bind `gesture::Action::FineDrag` with `gestures`")]
+    pub fn modifier_keys(mut self, modifier_keys: keyboard::Modifiers) -> Self {
+        self.gestures = Some(gesture::rebind_fine_drag(
+            self.gestures.take(),
+            modifier_keys,
+        ));
         self
     }
 }
@@ -104,7 +302,7 @@ impl<'a, Message, Renderer: self::Renderer> XYPad<'a, Message, Renderer> {
 /// The local state of a [`XYPad`].
 ///
 /// [`XYPad`]: struct.XYPad.html
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Clone)]
 pub struct State {
     /// The [`NormalParam`] assigned to this widget's x axis
     ///
@@ -121,6 +319,7 @@ pub struct State {
     continuous_normal_y: f32,
     pressed_modifiers: keyboard::Modifiers,
     last_click: Option<mouse::Click>,
+    focus: Focus,
 }
 
 impl State {
@@ -146,6 +345,7 @@ impl State {
             continuous_normal_y: normal_param_y.value.as_f32(),
             pressed_modifiers: Default::default(),
             last_click: None,
+            focus: Focus::new(),
         }
     }
 
@@ -167,6 +367,28 @@ impl State {
     pub fn is_dragging(&self) -> bool {
         self.is_dragging
     }
+
+    /// Does the [`XYPad`] have keyboard focus?
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    pub fn is_focused(&self) -> bool {
+        self.focus.is_focused()
+    }
+
+    /// Gives keyboard focus to the [`XYPad`], taking it from any other
+    /// widget.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    pub fn focus(&mut self) {
+        self.focus.focus();
+    }
+
+    /// Removes keyboard focus from the [`XYPad`].
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    pub fn unfocus(&mut self) {
+        self.focus.unfocus();
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer>
@@ -209,6 +431,54 @@ where
         _renderer: &Renderer,
         _clipboard: Option<&dyn Clipboard>,
     ) -> event::Status {
+        if let Some(command) = self
+            .state
+            .focus
+            .on_event(&event, layout.bounds().contains(cursor_position))
+        {
+            let value_x = self.state.normal_param_x.value.as_f32();
+            let value_y = self.state.normal_param_y.value.as_f32();
+
+            let (normal_x, normal_y) = match command {
+                focus::Command::Step { x, y } => {
+                    (value_x + x * self.key_step, value_y + y * self.key_step)
+                }
+                focus::Command::Page(pages) => {
+                    (value_x, value_y + pages * self.page_step)
+                }
+                _ => (
+                    command.apply(
+                        value_x,
+                        self.state.normal_param_x.default.as_f32(),
+                        self.key_step,
+                        self.page_step,
+                    ),
+                    command.apply(
+                        value_y,
+                        self.state.normal_param_y.default.as_f32(),
+                        self.key_step,
+                        self.page_step,
+                    ),
+                ),
+            };
+
+            self.state.set_x(normal_x.into());
+            self.state.set_y(normal_y.into());
+
+            // A key press is a complete edit gesture on its own.
+            edit::push_gesture(
+                messages,
+                self.on_grab.as_deref(),
+                (self.on_change)(
+                    self.state.normal_param_x.value,
+                    self.state.normal_param_y.value,
+                ),
+                self.on_release.as_deref(),
+            );
+
+            return event::Status::Captured;
+        }
+
         match event {
             Event::Mouse(mouse_event) => match mouse_event {
                 mouse::Event::CursorMoved { .. } => {
@@ -229,13 +499,19 @@ where
                                 - self.state.prev_drag_y)
                                 / bounds_size;
 
-                            if self
-                                .state
-                                .pressed_modifiers
-                                .matches(self.modifier_keys)
-                            {
-                                movement_x *= self.modifier_scalar;
-                                movement_y *= self.modifier_scalar;
+                            match gesture::drag_action(
+                                self.gestures.as_deref(),
+                                self.state.pressed_modifiers,
+                            ) {
+                                Some(gesture::Action::FineDrag) => {
+                                    movement_x *= self.modifier_scalar;
+                                    movement_y *= self.modifier_scalar;
+                                }
+                                Some(gesture::Action::UltraFineDrag) => {
+                                    movement_x *= self.ultra_fine_scalar;
+                                    movement_y *= self.ultra_fine_scalar;
+                                }
+                                _ => {}
                             }
 
                             let normal_x =
@@ -261,16 +537,62 @@ where
                         }
                     }
                 }
-                mouse::Event::ButtonPressed(mouse::Button::Left) => {
+                mouse::Event::ButtonPressed(button) => {
                     if layout.bounds().contains(cursor_position) {
-                        let click = mouse::Click::new(
-                            cursor_position,
-                            self.state.last_click,
-                        );
+                        let kind = if button == mouse::Button::Left {
+                            let click = mouse::Click::new(
+                                cursor_position,
+                                self.state.last_click,
+                            );
+                            self.state.last_click = Some(click);
+
+                            gesture::ClickKind::from(click.kind())
+                        } else {
+                            gesture::ClickKind::Single
+                        };
+
+                        match gesture::click_action(
+                            self.gestures.as_deref(),
+                            button,
+                            kind,
+                            self.state.pressed_modifiers,
+                            false,
+                            self.on_context_request.is_some(),
+                        ) {
+                            Some(gesture::Action::Reset) => {
+                                self.state.is_dragging = false;
+
+                                self.state.normal_param_x.value =
+                                    self.state.normal_param_x.default;
+                                self.state.normal_param_y.value =
+                                    self.state.normal_param_y.default;
 
-                        match click.kind() {
-                            mouse::click::Kind::Single => {
+                                edit::push_gesture(
+                                    messages,
+                                    self.on_grab.as_deref(),
+                                    (self.on_change)(
+                                        self.state.normal_param_x.value,
+                                        self.state.normal_param_y.value,
+                                    ),
+                                    self.on_release.as_deref(),
+                                );
+                            }
+                            Some(gesture::Action::ContextRequest) => {
+                                if let Some(on_context_request) =
+                                    &self.on_context_request
+                                {
+                                    messages.push(on_context_request(
+                                        cursor_position,
+                                    ));
+                                }
+                            }
+                            _ if button == mouse::Button::Left => {
                                 self.state.is_dragging = true;
+
+                                if let Some(on_grab) = &self.on_grab {
+                                    messages.push(on_grab());
+                                }
+
                                 self.state.prev_drag_x = cursor_position.x;
                                 self.state.prev_drag_y = cursor_position.y;
 
@@ -305,28 +627,55 @@ where
                                     self.state.normal_param_y.value,
                                 ));
                             }
-                            _ => {
-                                self.state.is_dragging = false;
-
-                                self.state.normal_param_x.value =
-                                    self.state.normal_param_x.default;
-                                self.state.normal_param_y.value =
-                                    self.state.normal_param_y.default;
-
-                                messages.push((self.on_change)(
-                                    self.state.normal_param_x.value,
-                                    self.state.normal_param_y.value,
-                                ));
-                            }
+                            _ => return event::Status::Ignored,
                         }
 
-                        self.state.last_click = Some(click);
-
                         return event::Status::Captured;
                     }
                 }
+                mouse::Event::WheelScrolled { delta }
+                    if !self.state.is_dragging
+                        && layout.bounds().contains(cursor_position) =>
+                {
+                    let (movement_x, movement_y) = wheel::movement(
+                        delta,
+                        self.wheel_scalar,
+                        self.wheel_pixel_scalar,
+                        gesture::drag_action(
+                            self.gestures.as_deref(),
+                            self.state.pressed_modifiers,
+                        ),
+                        self.wheel_modifier_scalar,
+                        self.wheel_ultra_fine_scalar,
+                    );
+
+                    self.state.set_x(Normal::from(
+                        self.state.normal_param_x.value.as_f32() + movement_x,
+                    ));
+                    self.state.set_y(Normal::from(
+                        self.state.normal_param_y.value.as_f32() + movement_y,
+                    ));
+
+                    edit::push_gesture(
+                        messages,
+                        self.on_grab.as_deref(),
+                        (self.on_change)(
+                            self.state.normal_param_x.value,
+                            self.state.normal_param_y.value,
+                        ),
+                        self.on_release.as_deref(),
+                    );
+
+                    return event::Status::Captured;
+                }
                 mouse::Event::ButtonReleased(mouse::Button::Left) => {
-                    self.state.is_dragging = false;
+                    if self.state.is_dragging {
+                        self.state.is_dragging = false;
+
+                        if let Some(on_release) = &self.on_release {
+                            messages.push(on_release());
+                        }
+                    }
                     self.state.continuous_normal_x =
                         self.state.normal_param_x.value.as_f32();
                     self.state.continuous_normal_y =
@@ -363,14 +712,7 @@ where
         cursor_position: Point,
         _viewport: &Rectangle,
     ) -> Renderer::Output {
-        renderer.draw(
-            layout.bounds(),
-            cursor_position,
-            self.state.normal_param_x.value,
-            self.state.normal_param_y.value,
-            self.state.is_dragging,
-            &self.style,
-        )
+        renderer.draw(layout.bounds(), cursor_position, self.state, &self.style)
     }
 
     fn hash_layout(&self, state: &mut Hasher) {
@@ -396,19 +738,16 @@ pub trait Renderer: iced_native::Renderer {
     /// It receives:
     ///   * the bounds of the [`XYPad`]
     ///   * the current cursor position
-    ///   * the current normal of the x coordinate of the [`XYPad`]
-    ///   * the current normal of the y coordinate of the [`XYPad`]
-    ///   * whether the xy_pad is currently being dragged
+    ///   * the [`State`] of the [`XYPad`]
     ///   * the style of the [`XYPad`]
     ///
     /// [`XYPad`]: struct.XYPad.html
+    /// [`State`]: struct.State.html
     fn draw(
         &mut self,
         bounds: Rectangle,
         cursor_position: Point,
-        normal_x: Normal,
-        normal_y: Normal,
-        is_dragging: bool,
+        state: &State,
         style: &Self::Style,
     ) -> Self::Output;
 }
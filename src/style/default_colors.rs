@@ -65,6 +65,10 @@ pub const XY_PAD_CENTER_LINE: Color = Color {
     a: 0.5,
 };
 
+pub const FOCUS_RING: Color = Color::from_rgb(0.22, 0.52, 0.88);
+
+pub const TEXT_ENTRY_INVALID: Color = Color::from_rgb(0.86, 0.2, 0.2);
+
 /*
 pub const DB_METER_BACK: Color = Color::from_rgb(0.45, 0.45, 0.45);
 pub const DB_METER_BORDER: Color = Color::from_rgb(0.2, 0.2, 0.2);
@@ -0,0 +1,30 @@
+//! The style of the ring drawn around a widget that has keyboard focus
+
+use iced_native::Color;
+
+use crate::style::default_colors;
+
+/// The style of the ring drawn around a widget that has keyboard focus
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Style {
+    /// The color of the ring
+    pub color: Color,
+    /// The width (thickness) of the ring
+    pub width: f32,
+    /// The gap between the edge of the widget and the ring
+    pub offset: f32,
+    /// The radius of the corners of the ring. This is ignored by round
+    /// widgets such as the `Knob`, which draw a round ring.
+    pub border_radius: f32,
+}
+
+impl Default for Style {
+    fn default() -> Self {
+        Self {
+            color: default_colors::FOCUS_RING,
+            width: 2.0,
+            offset: 1.0,
+            border_radius: 3.0,
+        }
+    }
+}
@@ -5,7 +5,9 @@
 use iced_native::{image, Color, Rectangle};
 
 use crate::core::Offset;
-use crate::style::{default_colors, text_marks, tick_marks};
+use crate::style::{
+    default_colors, focus_ring, text_entry, text_marks, tick_marks,
+};
 
 /// The appearance of an [`HSlider`].
 ///
@@ -286,6 +288,32 @@ pub trait StyleSheet {
     fn text_marks_style(&self) -> Option<TextMarksStyle> {
         None
     }
+
+    /// The style of the focus ring drawn around an [`HSlider`] that has
+    /// keyboard focus
+    ///
+    /// For no focus ring, override this to return `None`.
+    ///
+    /// [`HSlider`]: ../../native/h_slider/struct.HSlider.html
+    fn focus_ring_style(&self) -> Option<focus_ring::Style> {
+        Some(std::default::Default::default())
+    }
+
+    /// The style of the text entry drawn over an [`HSlider`] while the user
+    /// types a value
+    ///
+    /// [`HSlider`]: ../../native/h_slider/struct.HSlider.html
+    fn text_entry_style(&self) -> text_entry::Style {
+        std::default::Default::default()
+    }
+
+    /// The style of the text entry drawn over an [`HSlider`] when the typed
+    /// text is not a valid value
+    ///
+    /// [`HSlider`]: ../../native/h_slider/struct.HSlider.html
+    fn invalid_text_entry_style(&self) -> text_entry::Style {
+        text_entry::Style::invalid()
+    }
 }
 
 struct Default;
@@ -7,7 +7,9 @@ use iced_native::Color;
 
 pub use iced_graphics::canvas::LineCap;
 
-use crate::style::{default_colors, text_marks, tick_marks};
+use crate::style::{
+    default_colors, focus_ring, text_entry, text_marks, tick_marks,
+};
 use crate::KnobAngleRange;
 
 /// The appearance of a [`Knob`],
@@ -330,6 +332,32 @@ pub trait StyleSheet {
     fn text_marks_style(&self) -> Option<TextMarksStyle> {
         None
     }
+
+    /// The style of the focus ring drawn around a [`Knob`] that has
+    /// keyboard focus
+    ///
+    /// For no focus ring, override this to return `None`.
+    ///
+    /// [`Knob`]: ../../native/knob/struct.Knob.html
+    fn focus_ring_style(&self) -> Option<focus_ring::Style> {
+        Some(std::default::Default::default())
+    }
+
+    /// The style of the text entry drawn over a [`Knob`] while the user
+    /// types a value
+    ///
+    /// [`Knob`]: ../../native/knob/struct.Knob.html
+    fn text_entry_style(&self) -> text_entry::Style {
+        std::default::Default::default()
+    }
+
+    /// The style of the text entry drawn over a [`Knob`] when the typed
+    /// text is not a valid value
+    ///
+    /// [`Knob`]: ../../native/knob/struct.Knob.html
+    fn invalid_text_entry_style(&self) -> text_entry::Style {
+        text_entry::Style::invalid()
+    }
 }
 
 struct Default;
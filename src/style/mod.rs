@@ -9,6 +9,8 @@ pub mod ramp;
 pub mod v_slider;
 pub mod xy_pad;
 
+pub mod focus_ring;
+pub mod text_entry;
 pub mod text_marks;
 pub mod tick_marks;
 
@@ -4,7 +4,7 @@
 
 use iced_native::Color;
 
-use crate::style::default_colors;
+use crate::style::{default_colors, focus_ring};
 
 /// The appearance of an [`ModRangeInput`]
 ///
@@ -71,6 +71,16 @@ pub trait StyleSheet {
     ///
     /// [`ModRangeInput`]: ../../native/mod_range_input/struct.ModRangeInput.html
     fn dragging(&self) -> Style;
+
+    /// The style of the focus ring drawn around a [`ModRangeInput`] that has
+    /// keyboard focus
+    ///
+    /// For no focus ring, override this to return `None`.
+    ///
+    /// [`ModRangeInput`]: ../../native/mod_range_input/struct.ModRangeInput.html
+    fn focus_ring_style(&self) -> Option<focus_ring::Style> {
+        Some(std::default::Default::default())
+    }
 }
 
 struct Default;
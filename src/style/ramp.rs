@@ -4,7 +4,7 @@
 
 use iced_native::Color;
 
-use crate::style::default_colors;
+use crate::style::{default_colors, focus_ring};
 
 /// The appearance of a [`Ramp`],
 ///
@@ -45,6 +45,16 @@ pub trait StyleSheet {
     ///
     /// [`Ramp`]: ../../native/ramp/struct.Ramp.html
     fn dragging(&self) -> Style;
+
+    /// The style of the focus ring drawn around a [`Ramp`] that has
+    /// keyboard focus
+    ///
+    /// For no focus ring, override this to return `None`.
+    ///
+    /// [`Ramp`]: ../../native/ramp/struct.Ramp.html
+    fn focus_ring_style(&self) -> Option<focus_ring::Style> {
+        Some(std::default::Default::default())
+    }
 }
 
 struct Default;
@@ -0,0 +1,59 @@
+//! The style of the text entry drawn over a widget while the user types a
+//! value
+
+use iced_graphics::{Color, Font};
+
+use crate::style::default_colors;
+
+/// The style of the text entry drawn over a widget while the user types a
+/// value
+#[derive(Debug, Copy, Clone)]
+pub struct Style {
+    /// The color of the background
+    pub back_color: Color,
+    /// The color of the border
+    pub border_color: Color,
+    /// The width of the border
+    pub border_width: f32,
+    /// The radius of the corners
+    pub border_radius: f32,
+    /// The color of the text
+    pub text_color: Color,
+    /// The size of the text
+    pub text_size: u16,
+    /// The font of the text
+    pub font: Font,
+    /// The minimum width of the text entry. The text entry is as wide as
+    /// the widget if the widget is wider.
+    pub width: u16,
+    /// The height of the text entry
+    pub height: u16,
+}
+
+impl Style {
+    /// Returns the default style of a text entry with text that is not a
+    /// valid value
+    pub fn invalid() -> Self {
+        Self {
+            border_color: default_colors::TEXT_ENTRY_INVALID,
+            text_color: default_colors::TEXT_ENTRY_INVALID,
+            ..Self::default()
+        }
+    }
+}
+
+impl Default for Style {
+    fn default() -> Self {
+        Self {
+            back_color: default_colors::LIGHT_BACK,
+            border_color: default_colors::BORDER,
+            border_width: 1.0,
+            border_radius: 2.0,
+            text_color: default_colors::TEXT_MARK,
+            text_size: 12,
+            font: Default::default(),
+            width: 50,
+            height: 18,
+        }
+    }
+}
@@ -5,7 +5,9 @@
 use iced_native::{image, Color, Rectangle};
 
 use crate::core::Offset;
-use crate::style::{default_colors, text_marks, tick_marks};
+use crate::style::{
+    default_colors, focus_ring, text_entry, text_marks, tick_marks,
+};
 
 /// The appearance of a [`VSlider`].
 ///
@@ -287,6 +289,32 @@ pub trait StyleSheet {
     fn text_marks_style(&self) -> Option<TextMarksStyle> {
         None
     }
+
+    /// The style of the focus ring drawn around a [`VSlider`] that has
+    /// keyboard focus
+    ///
+    /// For no focus ring, override this to return `None`.
+    ///
+    /// [`VSlider`]: ../../native/v_slider/struct.VSlider.html
+    fn focus_ring_style(&self) -> Option<focus_ring::Style> {
+        Some(std::default::Default::default())
+    }
+
+    /// The style of the text entry drawn over a [`VSlider`] while the user
+    /// types a value
+    ///
+    /// [`VSlider`]: ../../native/v_slider/struct.VSlider.html
+    fn text_entry_style(&self) -> text_entry::Style {
+        std::default::Default::default()
+    }
+
+    /// The style of the text entry drawn over a [`VSlider`] when the typed
+    /// text is not a valid value
+    ///
+    /// [`VSlider`]: ../../native/v_slider/struct.VSlider.html
+    fn invalid_text_entry_style(&self) -> text_entry::Style {
+        text_entry::Style::invalid()
+    }
 }
 
 struct Default;
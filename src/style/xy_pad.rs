@@ -4,7 +4,7 @@
 
 use iced_native::Color;
 
-use crate::style::default_colors;
+use crate::style::{default_colors, focus_ring};
 
 /// The appearance of an [`XYPad`].
 ///
@@ -98,6 +98,16 @@ pub trait StyleSheet {
     ///
     /// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
     fn dragging(&self) -> Style;
+
+    /// The style of the focus ring drawn around an [`XYPad`] that has
+    /// keyboard focus
+    ///
+    /// For no focus ring, override this to return `None`.
+    ///
+    /// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
+    fn focus_ring_style(&self) -> Option<focus_ring::Style> {
+        Some(std::default::Default::default())
+    }
 }
 
 struct Default;